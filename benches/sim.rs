@@ -0,0 +1,51 @@
+//! Simulation benchmarks
+//!
+//! Run with `make bench` (`cargo bench --features testkit --bench sim`).
+//! Both benches use fixed seeds and inputs, so numbers are comparable
+//! between commits.
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use roto_pong::consts::SIM_DT;
+use roto_pong::sim::testkit::HEAVY_WAVE;
+use roto_pong::sim::{GameState, TickInput, generate_wave, tick};
+
+/// Ticks per `tick` iteration (one second of play)
+const TICKS: u32 = 120;
+
+fn bench_tick(c: &mut Criterion) {
+    let heavy = GameState::heavy(42);
+    let input = TickInput {
+        target_theta: Some(1.0),
+        ..Default::default()
+    };
+    c.bench_function("tick_heavy_1s", |b| {
+        b.iter_batched(
+            || heavy.clone(),
+            |mut state| {
+                for _ in 0..TICKS {
+                    tick(&mut state, &input, SIM_DT);
+                }
+                state
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_generate_wave(c: &mut Criterion) {
+    let mut fresh = GameState::new(42);
+    fresh.wave_index = HEAVY_WAVE;
+    c.bench_function("generate_wave_late", |b| {
+        b.iter_batched(
+            || fresh.clone(),
+            |mut state| {
+                generate_wave(&mut state);
+                state
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_tick, bench_generate_wave);
+criterion_main!(benches);
@@ -0,0 +1,248 @@
+//! Achievements
+//!
+//! Data-driven table of unlock conditions checked after every simulation
+//! tick. Unlocked ids are persisted to LocalStorage.
+
+use serde::{Deserialize, Serialize};
+
+use crate::sim::{GameEvent, GameState};
+use crate::stats::Stats;
+
+/// Everything an achievement predicate can look at
+pub struct AchievementContext<'a> {
+    pub state: &'a GameState,
+    pub stats: &'a Stats,
+    /// A wave was cleared this tick
+    pub wave_cleared: bool,
+    /// The wave cleared this tick was played start to finish on one life
+    pub one_life_wave: bool,
+}
+
+/// A single achievement definition
+pub struct AchievementDef {
+    /// Stable id (persisted, never rename)
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub check: fn(&AchievementContext) -> bool,
+}
+
+/// All achievements, in display order
+pub const ACHIEVEMENTS: &[AchievementDef] = &[
+    AchievementDef {
+        id: "first_break",
+        name: "First Crack",
+        description: "Destroy your first block",
+        check: |c| c.stats.blocks_destroyed >= 1,
+    },
+    AchievementDef {
+        id: "wave_5",
+        name: "Warming Up",
+        description: "Clear wave 5",
+        check: |c| c.wave_cleared && c.state.wave_index >= 5,
+    },
+    AchievementDef {
+        id: "wave_10",
+        name: "Event Horizon",
+        description: "Clear wave 10",
+        check: |c| c.wave_cleared && c.state.wave_index >= 10,
+    },
+    AchievementDef {
+        id: "combo_50",
+        name: "Chain Reaction",
+        description: "Reach a 50 combo",
+        check: |c| c.state.combo >= 50,
+    },
+    AchievementDef {
+        id: "combo_500",
+        name: "Unstoppable",
+        description: "Reach a 500 combo",
+        check: |c| c.state.combo >= 500,
+    },
+    AchievementDef {
+        id: "last_life_wave",
+        name: "Hanging On",
+        description: "Clear a full wave on your last life",
+        check: |c| c.one_life_wave,
+    },
+    AchievementDef {
+        id: "score_100k",
+        name: "Six Figures",
+        description: "Score 100,000 points in one run",
+        check: |c| c.state.score >= 100_000,
+    },
+    AchievementDef {
+        id: "blocks_1000",
+        name: "Demolition Crew",
+        description: "Destroy 1,000 blocks",
+        check: |c| c.stats.blocks_destroyed >= 1000,
+    },
+    AchievementDef {
+        id: "playtime_1h",
+        name: "Orbital Veteran",
+        description: "Play for an hour in total",
+        check: |c| c.stats.playtime_secs() >= 3600.0,
+    },
+];
+
+/// Look up an achievement definition by id
+pub fn find(id: &str) -> Option<&'static AchievementDef> {
+    ACHIEVEMENTS.iter().find(|a| a.id == id)
+}
+
+/// Unlocked achievements plus per-run tracking
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Achievements {
+    /// Unlocked achievement ids (in unlock order)
+    pub unlocked: Vec<String>,
+    /// Current wave started on the last life and hasn't lost one since
+    #[serde(skip)]
+    one_life_wave: bool,
+}
+
+impl Achievements {
+    /// LocalStorage key (used only in wasm32)
+    #[allow(dead_code)]
+    const STORAGE_KEY: &'static str = "roto_pong_achievements";
+
+    /// Create with nothing unlocked
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether an achievement has been unlocked
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.iter().any(|u| u == id)
+    }
+
+    /// Check all achievements after a tick, unlocking any newly met ones.
+    ///
+    /// Pushes `GameEvent::Achievement(id)` onto `state.events` for each
+    /// unlock and returns the newly unlocked definitions.
+    pub fn check(&mut self, state: &mut GameState, stats: &Stats) -> Vec<&'static AchievementDef> {
+        let wave_cleared = state.events.contains(&GameEvent::WaveClear);
+        if state.lives != 1 {
+            self.one_life_wave = false;
+        }
+
+        let ctx = AchievementContext {
+            state,
+            stats,
+            wave_cleared,
+            one_life_wave: wave_cleared && self.one_life_wave,
+        };
+        let newly: Vec<&'static AchievementDef> = ACHIEVEMENTS
+            .iter()
+            .filter(|a| !self.is_unlocked(a.id) && (a.check)(&ctx))
+            .collect();
+
+        // Next wave starts on the last life?
+        if wave_cleared {
+            self.one_life_wave = state.lives == 1;
+        }
+
+        for def in &newly {
+            self.unlocked.push(def.id.to_string());
+            state.events.push(GameEvent::Achievement(def.id));
+        }
+        newly
+    }
+
+    /// Load achievements from LocalStorage (WASM only)
+    #[cfg(target_arch = "wasm32")]
+    pub fn load() -> Self {
+        let storage = web_sys::window()
+            .and_then(|w| w.local_storage().ok())
+            .flatten();
+
+        if let Some(storage) = storage {
+            if let Ok(Some(json)) = storage.get_item(Self::STORAGE_KEY) {
+                if let Ok(achievements) = serde_json::from_str::<Achievements>(&json) {
+                    log::info!("Loaded {} achievements", achievements.unlocked.len());
+                    return achievements;
+                }
+            }
+        }
+
+        Self::new()
+    }
+
+    /// Save achievements to LocalStorage (WASM only)
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self) {
+        let storage = web_sys::window()
+            .and_then(|w| w.local_storage().ok())
+            .flatten();
+
+        if let Some(storage) = storage {
+            if let Ok(json) = serde_json::to_string(self) {
+                let _ = storage.set_item(Self::STORAGE_KEY, &json);
+            }
+        }
+    }
+
+    /// Native stubs
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load() -> Self {
+        Self::new()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self) {
+        // No-op for native
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::BlockKind;
+    use glam::Vec2;
+
+    #[test]
+    fn test_unlocks_once() {
+        let mut state = GameState::new(1);
+        let mut stats = Stats::new();
+        let mut achievements = Achievements::new();
+
+        state.events = vec![GameEvent::BlockBreak(BlockKind::Glass, Vec2::ZERO)];
+        stats.record_tick(&state);
+        let newly = achievements.check(&mut state, &stats);
+        assert_eq!(newly.len(), 1);
+        assert_eq!(newly[0].id, "first_break");
+        assert!(
+            state
+                .events
+                .contains(&GameEvent::Achievement("first_break"))
+        );
+
+        // Already unlocked: doesn't fire again
+        assert!(achievements.check(&mut state, &stats).is_empty());
+    }
+
+    #[test]
+    fn test_one_life_wave() {
+        let mut state = GameState::new(1);
+        let stats = Stats::new();
+        let mut achievements = Achievements::new();
+
+        // Start a wave on two lives, drop to one mid-wave, then clear it
+        state.lives = 2;
+        state.wave_index = 1;
+        state.events = vec![GameEvent::WaveClear];
+        achievements.check(&mut state, &stats);
+        state.lives = 1;
+        state.events = vec![GameEvent::BallLost];
+        achievements.check(&mut state, &stats);
+        state.wave_index = 2;
+        state.events = vec![GameEvent::WaveClear];
+        achievements.check(&mut state, &stats);
+        assert!(!achievements.is_unlocked("last_life_wave"));
+
+        // Full wave on one life
+        state.wave_index = 3;
+        state.events = vec![GameEvent::WaveClear];
+        achievements.check(&mut state, &stats);
+        assert!(achievements.is_unlocked("last_life_wave"));
+    }
+}
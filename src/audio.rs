@@ -1,11 +1,54 @@
 //! Audio system using Web Audio API
 //!
-//! Procedurally generated sound effects - no external files needed!
+//! Procedurally generated sound effects and background music - no external
+//! files needed!
 
-use web_sys::{AudioContext, GainNode, OscillatorNode, OscillatorType};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use web_sys::{AudioContext, AudioContextState, GainNode, OscillatorNode, OscillatorType};
+
+/// Shortest gap between two starts of the same throttled effect (seconds)
+const MIN_REPEAT_INTERVAL: f64 = 0.035;
+/// Repeats that would have to wait longer than this are dropped (seconds)
+const MAX_REPEAT_DELAY: f64 = 0.1;
+
+/// Seconds per music step (eighth notes at 120 BPM)
+const MUSIC_STEP: f64 = 0.25;
+/// Steps per chord in the music loop
+const MUSIC_STEPS_PER_CHORD: usize = 8;
+/// How far ahead music notes are scheduled (seconds). Covers the gap
+/// between frames, plus a hitch or two.
+const MUSIC_LOOKAHEAD: f64 = 0.2;
+/// Music level while a wave-clear fanfare plays (fraction of normal)
+const MUSIC_DUCK: f32 = 0.35;
+/// How long a wave-clear fanfare holds the music down (seconds)
+const MUSIC_DUCK_TIME: f64 = 1.0;
+
+/// Fastest the loop gets at full intensity (fraction of the step removed)
+const MUSIC_MAX_SPEEDUP: f64 = 0.2;
+/// Intensity range over which the pad layer fades in, and its full level
+const PAD_FADE: (f32, f32) = (0.2, 0.5);
+const PAD_LEVEL: f32 = 0.12;
+/// Intensity range over which the drive layer fades in, and its full level
+const DRIVE_FADE: (f32, f32) = (0.55, 0.85);
+const DRIVE_LEVEL: f32 = 0.05;
+/// Smallest intensity change worth re-ramping the layers for
+const INTENSITY_EPSILON: f32 = 0.02;
+
+/// Background loop: bass root and arpeggio tones (Hz) of each chord
+/// (Am - F - C - G)
+const MUSIC_CHORDS: [(f32, [f32; 4]); 4] = [
+    (110.0, [220.0, 261.63, 329.63, 440.0]),
+    (87.31, [174.61, 220.0, 261.63, 349.23]),
+    (130.81, [261.63, 329.63, 392.0, 523.25]),
+    (98.0, [196.0, 246.94, 293.66, 392.0]),
+];
+/// Which arpeggio tone each step of a chord plays
+const MUSIC_ARPEGGIO: [usize; MUSIC_STEPS_PER_CHORD] = [0, 1, 2, 3, 2, 1, 2, 3];
 
 /// Sound effect types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SoundEffect {
     /// Ball hits paddle
     PaddleHit,
@@ -27,10 +70,18 @@ pub enum SoundEffect {
     BlockBreakElectric,
     /// Block breaks - portal
     BlockBreakPortal,
+    /// Ball kicked off a bumper
+    Bumper,
     /// Pickup collected
     PickupCollect,
     /// Ball lost to black hole
     BlackHoleConsume,
+    /// Combo lost
+    ComboBreak,
+    /// Combo reached a milestone (higher combos play higher)
+    ComboMilestone(u32),
+    /// Down to the last life
+    LowLives,
     /// Wave cleared
     WaveClear,
     /// Ball launched
@@ -41,12 +92,141 @@ pub enum SoundEffect {
     HighScore,
 }
 
+impl SoundEffect {
+    /// Whether rapid repeats get spaced out. Important one-off cues always
+    /// play right away.
+    fn throttled(self) -> bool {
+        !matches!(
+            self,
+            SoundEffect::WaveClear
+                | SoundEffect::GameOver
+                | SoundEffect::HighScore
+                | SoundEffect::LowLives
+                | SoundEffect::ComboBreak
+                | SoundEffect::ComboMilestone(_)
+        )
+    }
+}
+
+/// 0 below `start`, 1 above `end`, linear in between
+fn fade_in(x: f32, (start, end): (f32, f32)) -> f32 {
+    ((x - start) / (end - start)).clamp(0.0, 1.0)
+}
+
+/// Sustained music layers that fade in as the intensity rises. Built once
+/// and left running; after that only their parameters are ramped, so
+/// continuous music doesn't create nodes as it plays.
+struct MusicStems {
+    /// Root and fifth an octave over the bass, following the chord
+    pad: [OscillatorNode; 2],
+    pad_level: GainNode,
+    /// Square an octave over the bass, plucked every step
+    drive: OscillatorNode,
+    drive_pulse: GainNode,
+    drive_level: GainNode,
+}
+
+impl MusicStems {
+    /// Layers feeding `bus`, silent until `set_intensity` raises them
+    fn new(ctx: &AudioContext, bus: &GainNode) -> Option<Self> {
+        let pad_level = ctx.create_gain().ok()?;
+        pad_level.gain().set_value(0.0);
+        pad_level.connect_with_audio_node(bus).ok()?;
+        let pad = [ctx.create_oscillator().ok()?, ctx.create_oscillator().ok()?];
+        for osc in &pad {
+            osc.set_type(OscillatorType::Triangle);
+            osc.connect_with_audio_node(&pad_level).ok()?;
+        }
+
+        let drive_level = ctx.create_gain().ok()?;
+        drive_level.gain().set_value(0.0);
+        drive_level.connect_with_audio_node(bus).ok()?;
+        let drive_pulse = ctx.create_gain().ok()?;
+        drive_pulse.gain().set_value(0.0);
+        drive_pulse.connect_with_audio_node(&drive_level).ok()?;
+        let drive = ctx.create_oscillator().ok()?;
+        drive.set_type(OscillatorType::Square);
+        drive.connect_with_audio_node(&drive_pulse).ok()?;
+
+        let stems = Self {
+            pad,
+            pad_level,
+            drive,
+            drive_pulse,
+            drive_level,
+        };
+        stems.set_chord(MUSIC_CHORDS[0].0, ctx.current_time());
+        for osc in stems.pad.iter().chain([&stems.drive]) {
+            osc.start().ok()?;
+        }
+        Some(stems)
+    }
+
+    /// Follow the chord with bass root `bass` from time `t`
+    fn set_chord(&self, bass: f32, t: f64) {
+        self.pad[0]
+            .frequency()
+            .set_target_at_time(bass * 2.0, t, 0.08)
+            .ok();
+        self.pad[1]
+            .frequency()
+            .set_target_at_time(bass * 3.0, t, 0.08)
+            .ok();
+        self.drive.frequency().set_value_at_time(bass * 2.0, t).ok();
+    }
+
+    /// Pluck the drive layer for the step starting at `t`
+    fn pulse(&self, t: f64, length: f64) {
+        let gain = self.drive_pulse.gain();
+        gain.set_value_at_time(1.0, t).ok();
+        gain.exponential_ramp_to_value_at_time(0.05, t + length * 0.8)
+            .ok();
+    }
+
+    /// Glide the layer levels to those for `intensity`
+    fn set_intensity(&self, ctx: &AudioContext, intensity: f32) {
+        let t = ctx.current_time();
+        for (level, fade, full) in [
+            (&self.pad_level, PAD_FADE, PAD_LEVEL),
+            (&self.drive_level, DRIVE_FADE, DRIVE_LEVEL),
+        ] {
+            level.gain().cancel_scheduled_values(t).ok();
+            level
+                .gain()
+                .set_target_at_time(full * fade_in(intensity, fade), t, 0.5)
+                .ok();
+        }
+    }
+}
+
 /// Audio manager for the game
 pub struct AudioManager {
     ctx: Option<AudioContext>,
     master_volume: f32,
     sfx_volume: f32,
+    music_volume: f32,
     muted: bool,
+    /// Bus every music note goes through; its gain is the music level
+    music_bus: Option<GainNode>,
+    /// Music wanted. Notes are only scheduled while the context runs, so
+    /// nothing plays before a user gesture has resumed it.
+    music_on: bool,
+    /// Context time of the next music step to schedule
+    next_music_time: Cell<f64>,
+    /// Next step of the music loop
+    music_step: Cell<usize>,
+    /// Layers that fade in with the intensity
+    stems: Option<MusicStems>,
+    /// How heated the music is (0.0 - 1.0)
+    intensity: f32,
+    /// Stereo pan for the sound being built (-1 left .. 1 right)
+    pan: Cell<f32>,
+    /// Space out rapid repeats of the same effect
+    smoothing: bool,
+    /// Start delay for the sound being built (seconds)
+    delay: Cell<f64>,
+    /// Scheduled start of each throttled effect's latest play
+    last_start: RefCell<HashMap<SoundEffect, f64>>,
 }
 
 impl Default for AudioManager {
@@ -62,12 +242,36 @@ impl AudioManager {
         if ctx.is_none() {
             log::warn!("Failed to create AudioContext - audio disabled");
         }
-        Self {
+        let music_bus = ctx.as_ref().and_then(|ctx| {
+            let bus = ctx.create_gain().ok()?;
+            bus.connect_with_audio_node(&ctx.destination()).ok()?;
+            Some(bus)
+        });
+        let stems = ctx
+            .as_ref()
+            .zip(music_bus.as_ref())
+            .and_then(|(ctx, bus)| MusicStems::new(ctx, bus));
+        let manager = Self {
             ctx,
             master_volume: 0.8,
             sfx_volume: 1.0,
+            music_volume: 0.7,
             muted: false,
+            music_bus,
+            music_on: false,
+            next_music_time: Cell::new(0.0),
+            music_step: Cell::new(0),
+            stems,
+            intensity: 0.0,
+            pan: Cell::new(0.0),
+            smoothing: true,
+            delay: Cell::new(0.0),
+            last_start: RefCell::new(HashMap::new()),
+        };
+        if let Some(bus) = &manager.music_bus {
+            bus.gain().set_value(manager.music_level());
         }
+        manager
     }
 
     /// Resume audio context (required after user gesture)
@@ -80,6 +284,7 @@ impl AudioManager {
     /// Set master volume (0.0 - 1.0)
     pub fn set_master_volume(&mut self, vol: f32) {
         self.master_volume = vol.clamp(0.0, 1.0);
+        self.apply_music_level();
     }
 
     /// Set SFX volume (0.0 - 1.0)
@@ -87,9 +292,22 @@ impl AudioManager {
         self.sfx_volume = vol.clamp(0.0, 1.0);
     }
 
+    /// Set music volume (0.0 - 1.0)
+    pub fn set_music_volume(&mut self, vol: f32) {
+        self.music_volume = vol.clamp(0.0, 1.0);
+        self.apply_music_level();
+    }
+
     /// Mute/unmute all audio
     pub fn set_muted(&mut self, muted: bool) {
         self.muted = muted;
+        self.apply_music_level();
+    }
+
+    /// Space out rapid repeats of the same effect (on) or play every sound
+    /// the moment it's requested (off)
+    pub fn set_smoothing(&mut self, smoothing: bool) {
+        self.smoothing = smoothing;
     }
 
     /// Get effective volume
@@ -115,6 +333,23 @@ impl AudioManager {
             let _ = ctx.resume();
         }
 
+        // Repeats of the same effect start at least MIN_REPEAT_INTERVAL apart,
+        // so a burst of hits in one frame doesn't flam into a buzz
+        let mut delay = 0.0;
+        if self.smoothing && effect.throttled() {
+            let now = ctx.current_time();
+            let mut last_start = self.last_start.borrow_mut();
+            let start = last_start
+                .get(&effect)
+                .map_or(now, |&prev| (prev + MIN_REPEAT_INTERVAL).max(now));
+            if start - now > MAX_REPEAT_DELAY {
+                return;
+            }
+            last_start.insert(effect, start);
+            delay = start - now;
+        }
+        self.delay.set(delay);
+
         match effect {
             SoundEffect::PaddleHit => self.play_paddle_hit(ctx, vol),
             SoundEffect::WallHit => self.play_wall_hit(ctx, vol),
@@ -126,17 +361,214 @@ impl AudioManager {
             SoundEffect::BlockBreakCrystal => self.play_crystal_break(ctx, vol),
             SoundEffect::BlockBreakElectric => self.play_electric_break(ctx, vol),
             SoundEffect::BlockBreakPortal => self.play_portal_break(ctx, vol),
+            SoundEffect::Bumper => self.play_bumper(ctx, vol),
             SoundEffect::PickupCollect => self.play_pickup(ctx, vol),
             SoundEffect::BlackHoleConsume => self.play_black_hole(ctx, vol),
-            SoundEffect::WaveClear => self.play_wave_clear(ctx, vol),
+            SoundEffect::ComboBreak => self.play_combo_break(ctx, vol),
+            SoundEffect::ComboMilestone(combo) => self.play_combo_milestone(ctx, vol, combo),
+            SoundEffect::LowLives => self.play_heartbeat(ctx, vol),
+            SoundEffect::WaveClear => {
+                self.duck_music(ctx);
+                self.play_wave_clear(ctx, vol);
+            }
             SoundEffect::Launch => self.play_launch(ctx, vol),
             SoundEffect::GameOver => self.play_game_over(ctx, vol),
             SoundEffect::HighScore => self.play_high_score(ctx, vol),
         }
+        self.delay.set(0.0);
+    }
+
+    /// Start the background music loop. It begins with the next
+    /// `update_music` once the context is running (browsers keep it
+    /// suspended until a user gesture).
+    pub fn start_music(&mut self) {
+        if !self.music_on {
+            self.music_on = true;
+            self.music_step.set(0);
+            self.next_music_time.set(0.0);
+            self.apply_intensity();
+        }
+    }
+
+    /// Stop the background music (notes already scheduled still finish)
+    pub fn stop_music(&mut self) {
+        if self.music_on {
+            self.music_on = false;
+            self.apply_intensity();
+        }
+    }
+
+    /// Set how heated the music is (0.0 calm - 1.0 frantic). Higher
+    /// intensity plays the loop faster and fades in extra layers. Cheap to
+    /// call every frame: small changes are ignored.
+    pub fn set_intensity(&mut self, intensity: f32) {
+        let intensity = intensity.clamp(0.0, 1.0);
+        if (intensity - self.intensity).abs() < INTENSITY_EPSILON {
+            return;
+        }
+        self.intensity = intensity;
+        self.apply_intensity();
+    }
+
+    /// Ramp the layers to the current intensity (silent while stopped)
+    fn apply_intensity(&self) {
+        if let (Some(ctx), Some(stems)) = (&self.ctx, &self.stems) {
+            let intensity = if self.music_on { self.intensity } else { 0.0 };
+            stems.set_intensity(ctx, intensity);
+        }
+    }
+
+    /// Schedule the music notes due in the next moment. Call every frame.
+    pub fn update_music(&self) {
+        if !self.music_on {
+            return;
+        }
+        let (Some(ctx), Some(bus)) = (&self.ctx, &self.music_bus) else {
+            return;
+        };
+        if ctx.state() != AudioContextState::Running {
+            return;
+        }
+        let now = ctx.current_time();
+        // First note, or the tab was in the background: pick up from now
+        // rather than cramming in every missed step
+        if self.next_music_time.get() < now {
+            self.next_music_time.set(now + 0.05);
+        }
+        let step_length = MUSIC_STEP * (1.0 - MUSIC_MAX_SPEEDUP * self.intensity as f64);
+        while self.next_music_time.get() < now + MUSIC_LOOKAHEAD {
+            let t = self.next_music_time.get();
+            let step = self.music_step.get();
+            self.play_music_step(ctx, bus, step, t, step_length);
+            self.music_step
+                .set((step + 1) % (MUSIC_CHORDS.len() * MUSIC_STEPS_PER_CHORD));
+            self.next_music_time.set(t + step_length);
+        }
+    }
+
+    /// Music bus gain for the current volumes
+    fn music_level(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.master_volume * self.music_volume
+        }
+    }
+
+    /// Glide the music bus to the current music level
+    fn apply_music_level(&self) {
+        if let (Some(ctx), Some(bus)) = (&self.ctx, &self.music_bus) {
+            let t = ctx.current_time();
+            bus.gain().cancel_scheduled_values(t).ok();
+            bus.gain()
+                .set_target_at_time(self.music_level(), t, 0.05)
+                .ok();
+        }
+    }
+
+    /// Dip the music under a fanfare, then bring it back
+    fn duck_music(&self, ctx: &AudioContext) {
+        let Some(bus) = &self.music_bus else { return };
+        let level = self.music_level();
+        let t = ctx.current_time();
+        bus.gain().cancel_scheduled_values(t).ok();
+        bus.gain()
+            .set_target_at_time(level * MUSIC_DUCK, t, 0.05)
+            .ok();
+        bus.gain()
+            .set_target_at_time(level, t + MUSIC_DUCK_TIME, 0.3)
+            .ok();
+    }
+
+    /// One step of the loop: the arpeggio note, plus a bass note on the
+    /// first and fifth steps of each chord. The sustained layers follow
+    /// along without new nodes.
+    fn play_music_step(
+        &self,
+        ctx: &AudioContext,
+        bus: &GainNode,
+        step: usize,
+        t: f64,
+        step_length: f64,
+    ) {
+        let (bass, tones) = MUSIC_CHORDS[step / MUSIC_STEPS_PER_CHORD];
+        let beat = step % MUSIC_STEPS_PER_CHORD;
+        if let Some(stems) = &self.stems {
+            if beat == 0 {
+                stems.set_chord(bass, t);
+            }
+            stems.pulse(t, step_length);
+        }
+        if beat.is_multiple_of(4) {
+            self.play_music_note(
+                ctx,
+                bus,
+                bass,
+                OscillatorType::Triangle,
+                0.3,
+                t,
+                step_length * 3.5,
+            );
+        }
+        let tone = tones[MUSIC_ARPEGGIO[beat]];
+        self.play_music_note(
+            ctx,
+            bus,
+            tone,
+            OscillatorType::Sine,
+            0.1,
+            t,
+            step_length * 0.9,
+        );
+    }
+
+    /// A single soft-edged music note into the music bus
+    #[allow(clippy::too_many_arguments)]
+    fn play_music_note(
+        &self,
+        ctx: &AudioContext,
+        bus: &GainNode,
+        freq: f32,
+        osc_type: OscillatorType,
+        level: f32,
+        t: f64,
+        length: f64,
+    ) {
+        let (Ok(osc), Ok(gain)) = (ctx.create_oscillator(), ctx.create_gain()) else {
+            return;
+        };
+        osc.set_type(osc_type);
+        osc.frequency().set_value(freq);
+        if osc.connect_with_audio_node(&gain).is_err() || gain.connect_with_audio_node(bus).is_err()
+        {
+            return;
+        }
+        gain.gain().set_value_at_time(0.001, t).ok();
+        gain.gain()
+            .exponential_ramp_to_value_at_time(level, t + 0.02)
+            .ok();
+        gain.gain()
+            .exponential_ramp_to_value_at_time(0.001, t + length)
+            .ok();
+        osc.start_with_when(t).ok();
+        osc.stop_with_when(t + length + 0.02).ok();
+    }
+
+    /// Play a sound effect at stereo `pan` (-1 left to 1 right). `play`
+    /// stays centered, for UI sounds with no place.
+    pub fn play_at(&self, effect: SoundEffect, pan: f32) {
+        self.pan.set(pan);
+        self.play(effect);
+        self.pan.set(0.0);
     }
 
     // === Sound generators ===
 
+    /// Start time for the sound being built (now, plus any spacing delay)
+    fn now(&self, ctx: &AudioContext) -> f64 {
+        ctx.current_time() + self.delay.get()
+    }
+
     /// Create an oscillator with gain envelope
     fn create_osc(
         &self,
@@ -150,7 +582,15 @@ impl AudioManager {
         osc.set_type(osc_type);
         osc.frequency().set_value(freq);
         osc.connect_with_audio_node(&gain).ok()?;
-        gain.connect_with_audio_node(&ctx.destination()).ok()?;
+        let pan = self.pan.get();
+        if pan != 0.0 {
+            let panner = ctx.create_stereo_panner().ok()?;
+            panner.pan().set_value(pan);
+            gain.connect_with_audio_node(&panner).ok()?;
+            panner.connect_with_audio_node(&ctx.destination()).ok()?;
+        } else {
+            gain.connect_with_audio_node(&ctx.destination()).ok()?;
+        }
 
         Some((osc, gain))
     }
@@ -160,7 +600,7 @@ impl AudioManager {
         let Some((osc, gain)) = self.create_osc(ctx, 150.0, OscillatorType::Sine) else {
             return;
         };
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         gain.gain().set_value_at_time(vol * 0.6, t).ok();
         gain.gain()
@@ -171,7 +611,7 @@ impl AudioManager {
             .exponential_ramp_to_value_at_time(60.0, t + 0.1)
             .ok();
 
-        osc.start().ok();
+        osc.start_with_when(t).ok();
         osc.stop_with_when(t + 0.15).ok();
     }
 
@@ -180,14 +620,14 @@ impl AudioManager {
         let Some((osc, gain)) = self.create_osc(ctx, 400.0, OscillatorType::Sine) else {
             return;
         };
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         gain.gain().set_value_at_time(vol * 0.3, t).ok();
         gain.gain()
             .exponential_ramp_to_value_at_time(0.01, t + 0.08)
             .ok();
 
-        osc.start().ok();
+        osc.start_with_when(t).ok();
         osc.stop_with_when(t + 0.1).ok();
     }
 
@@ -196,20 +636,20 @@ impl AudioManager {
         let Some((osc, gain)) = self.create_osc(ctx, 300.0, OscillatorType::Triangle) else {
             return;
         };
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         gain.gain().set_value_at_time(vol * 0.25, t).ok();
         gain.gain()
             .exponential_ramp_to_value_at_time(0.01, t + 0.05)
             .ok();
 
-        osc.start().ok();
+        osc.start_with_when(t).ok();
         osc.stop_with_when(t + 0.08).ok();
     }
 
     /// Glass break - crackling zap shatter
     fn play_glass_break(&self, ctx: &AudioContext, vol: f32) {
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         // Crackling frequency jumps
         if let Some((osc, gain)) = self.create_osc(ctx, 100.0, OscillatorType::Sawtooth) {
@@ -228,7 +668,7 @@ impl AudioManager {
             osc.frequency().set_value_at_time(80.0, t + 0.1).ok();
             osc.frequency().set_value_at_time(2000.0, t + 0.12).ok();
             osc.frequency().set_value_at_time(50.0, t + 0.15).ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.2).ok();
         }
 
@@ -242,7 +682,7 @@ impl AudioManager {
             osc.frequency().set_value_at_time(8000.0, t + 0.02).ok();
             osc.frequency().set_value_at_time(5000.0, t + 0.04).ok();
             osc.frequency().set_value_at_time(7000.0, t + 0.06).ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.12).ok();
         }
 
@@ -252,14 +692,14 @@ impl AudioManager {
             gain.gain()
                 .exponential_ramp_to_value_at_time(0.01, t + 0.1)
                 .ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.12).ok();
         }
     }
 
     /// Armored break - deep metallic clang
     fn play_armored_break(&self, ctx: &AudioContext, vol: f32) {
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         // Deep bass impact
         if let Some((osc, gain)) = self.create_osc(ctx, 80.0, OscillatorType::Sine) {
@@ -271,7 +711,7 @@ impl AudioManager {
             osc.frequency()
                 .exponential_ramp_to_value_at_time(40.0, t + 0.2)
                 .ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.3).ok();
         }
 
@@ -284,7 +724,7 @@ impl AudioManager {
             osc.frequency().set_value_at_time(400.0, t).ok();
             osc.frequency().set_value_at_time(300.0, t + 0.05).ok();
             osc.frequency().set_value_at_time(200.0, t + 0.1).ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.25).ok();
         }
 
@@ -294,7 +734,7 @@ impl AudioManager {
             gain.gain()
                 .exponential_ramp_to_value_at_time(0.01, t + 0.15)
                 .ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.2).ok();
         }
     }
@@ -304,7 +744,7 @@ impl AudioManager {
         let Some((osc, gain)) = self.create_osc(ctx, 100.0, OscillatorType::Sawtooth) else {
             return;
         };
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         gain.gain().set_value_at_time(vol * 0.5, t).ok();
         gain.gain()
@@ -315,7 +755,7 @@ impl AudioManager {
             .exponential_ramp_to_value_at_time(30.0, t + 0.4)
             .ok();
 
-        osc.start().ok();
+        osc.start_with_when(t).ok();
         osc.stop_with_when(t + 0.5).ok();
 
         // Add high frequency crack
@@ -325,7 +765,7 @@ impl AudioManager {
                 .gain()
                 .exponential_ramp_to_value_at_time(0.01, t + 0.1)
                 .ok();
-            osc2.start().ok();
+            osc2.start_with_when(t).ok();
             osc2.stop_with_when(t + 0.15).ok();
         }
     }
@@ -335,7 +775,7 @@ impl AudioManager {
         let Some((osc, gain)) = self.create_osc(ctx, 400.0, OscillatorType::Sine) else {
             return;
         };
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         gain.gain().set_value_at_time(vol * 0.35, t).ok();
         gain.gain()
@@ -349,17 +789,42 @@ impl AudioManager {
         osc.frequency().set_value_at_time(450.0, t + 0.15).ok();
         osc.frequency().set_value_at_time(300.0, t + 0.2).ok();
 
-        osc.start().ok();
+        osc.start_with_when(t).ok();
         osc.stop_with_when(t + 0.35).ok();
     }
 
+    /// Bumper kick - springy boing that bends up
+    fn play_bumper(&self, ctx: &AudioContext, vol: f32) {
+        let Some((osc, gain)) = self.create_osc(ctx, 220.0, OscillatorType::Triangle) else {
+            return;
+        };
+        let t = self.now(ctx);
+
+        gain.gain().set_value_at_time(vol * 0.4, t).ok();
+        gain.gain()
+            .exponential_ramp_to_value_at_time(0.01, t + 0.25)
+            .ok();
+
+        // Quick upward bend, then a short spring wobble
+        osc.frequency().set_value_at_time(220.0, t).ok();
+        osc.frequency()
+            .exponential_ramp_to_value_at_time(660.0, t + 0.06)
+            .ok();
+        osc.frequency().set_value_at_time(560.0, t + 0.1).ok();
+        osc.frequency().set_value_at_time(620.0, t + 0.14).ok();
+        osc.frequency().set_value_at_time(580.0, t + 0.18).ok();
+
+        osc.start_with_when(t).ok();
+        osc.stop_with_when(t + 0.28).ok();
+    }
+
     /// Crystal break - sparkly chime
     fn play_crystal_break(&self, ctx: &AudioContext, vol: f32) {
         // Multiple harmonics for shimmer
         for (i, freq) in [1200.0, 1800.0, 2400.0].iter().enumerate() {
             let delay = i as f64 * 0.02;
             if let Some((osc, gain)) = self.create_osc(ctx, *freq, OscillatorType::Sine) {
-                let t = ctx.current_time() + delay;
+                let t = self.now(ctx) + delay;
                 gain.gain().set_value_at_time(vol * 0.2, t).ok();
                 gain.gain()
                     .exponential_ramp_to_value_at_time(0.01, t + 0.3)
@@ -372,7 +837,7 @@ impl AudioManager {
 
     /// Electric break - deep humming zap
     fn play_electric_break(&self, ctx: &AudioContext, vol: f32) {
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         // Low frequency electrical hum (60Hz mains hum style)
         if let Some((osc, gain)) = self.create_osc(ctx, 60.0, OscillatorType::Sawtooth) {
@@ -386,7 +851,7 @@ impl AudioManager {
             osc.frequency().set_value_at_time(55.0, t + 0.1).ok();
             osc.frequency().set_value_at_time(70.0, t + 0.15).ok();
             osc.frequency().set_value_at_time(50.0, t + 0.2).ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.35).ok();
         }
 
@@ -404,7 +869,7 @@ impl AudioManager {
             osc.frequency().set_value_at_time(100.0, t + 0.1).ok();
             osc.frequency().set_value_at_time(300.0, t + 0.12).ok();
             osc.frequency().set_value_at_time(80.0, t + 0.15).ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.25).ok();
         }
 
@@ -414,7 +879,7 @@ impl AudioManager {
             gain.gain()
                 .exponential_ramp_to_value_at_time(0.01, t + 0.25)
                 .ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.3).ok();
         }
 
@@ -424,7 +889,7 @@ impl AudioManager {
             gain.gain()
                 .exponential_ramp_to_value_at_time(0.01, t + 0.15)
                 .ok();
-            osc.start().ok();
+            osc.start_with_when(t).ok();
             osc.stop_with_when(t + 0.2).ok();
         }
     }
@@ -434,7 +899,7 @@ impl AudioManager {
         let Some((osc, gain)) = self.create_osc(ctx, 600.0, OscillatorType::Sine) else {
             return;
         };
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         gain.gain().set_value_at_time(0.01, t).ok();
         gain.gain()
@@ -448,7 +913,7 @@ impl AudioManager {
             .exponential_ramp_to_value_at_time(200.0, t + 0.4)
             .ok();
 
-        osc.start().ok();
+        osc.start_with_when(t).ok();
         osc.stop_with_when(t + 0.5).ok();
     }
 
@@ -457,7 +922,7 @@ impl AudioManager {
         for (i, freq) in [600.0, 800.0, 1000.0].iter().enumerate() {
             let delay = i as f64 * 0.08;
             if let Some((osc, gain)) = self.create_osc(ctx, *freq, OscillatorType::Sine) {
-                let t = ctx.current_time() + delay;
+                let t = self.now(ctx) + delay;
                 gain.gain().set_value_at_time(vol * 0.25, t).ok();
                 gain.gain()
                     .exponential_ramp_to_value_at_time(0.01, t + 0.15)
@@ -473,7 +938,7 @@ impl AudioManager {
         let Some((osc, gain)) = self.create_osc(ctx, 300.0, OscillatorType::Sine) else {
             return;
         };
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         gain.gain().set_value_at_time(vol * 0.4, t).ok();
         gain.gain()
@@ -484,16 +949,70 @@ impl AudioManager {
             .exponential_ramp_to_value_at_time(20.0, t + 0.8)
             .ok();
 
-        osc.start().ok();
+        osc.start_with_when(t).ok();
         osc.stop_with_when(t + 1.0).ok();
     }
 
+    /// Combo break - deflating slide down
+    fn play_combo_break(&self, ctx: &AudioContext, vol: f32) {
+        for (i, freq) in [700.0, 520.0, 380.0].iter().enumerate() {
+            let delay = i as f64 * 0.07;
+            if let Some((osc, gain)) = self.create_osc(ctx, *freq, OscillatorType::Sawtooth) {
+                let t = self.now(ctx) + delay;
+                gain.gain().set_value_at_time(vol * 0.15, t).ok();
+                gain.gain()
+                    .exponential_ramp_to_value_at_time(0.01, t + 0.18)
+                    .ok();
+                osc.frequency().set_value_at_time(*freq, t).ok();
+                osc.frequency()
+                    .exponential_ramp_to_value_at_time(*freq * 0.8, t + 0.18)
+                    .ok();
+                osc.start_with_when(t).ok();
+                osc.stop_with_when(t + 0.2).ok();
+            }
+        }
+    }
+
+    /// Combo milestone - rising arpeggio, pitched up the bigger the combo
+    fn play_combo_milestone(&self, ctx: &AudioContext, vol: f32, combo: u32) {
+        let root = 440.0 * (1.0 + combo as f32 / 20.0);
+        for (i, step) in [1.0, 1.25, 1.5, 2.0].iter().enumerate() {
+            let freq = root * step;
+            let delay = i as f64 * 0.06;
+            if let Some((osc, gain)) = self.create_osc(ctx, freq, OscillatorType::Square) {
+                let t = self.now(ctx) + delay;
+                gain.gain().set_value_at_time(vol * 0.12, t).ok();
+                gain.gain()
+                    .exponential_ramp_to_value_at_time(0.01, t + 0.15)
+                    .ok();
+                osc.start_with_when(t).ok();
+                osc.stop_with_when(t + 0.18).ok();
+            }
+        }
+    }
+
+    /// Last life - two soft, low heartbeat thumps
+    fn play_heartbeat(&self, ctx: &AudioContext, vol: f32) {
+        for (i, freq) in [62.0, 55.0].iter().enumerate() {
+            let delay = i as f64 * 0.22;
+            if let Some((osc, gain)) = self.create_osc(ctx, *freq, OscillatorType::Sine) {
+                let t = self.now(ctx) + delay;
+                gain.gain().set_value_at_time(vol * 0.35, t).ok();
+                gain.gain()
+                    .exponential_ramp_to_value_at_time(0.01, t + 0.16)
+                    .ok();
+                osc.start_with_when(t).ok();
+                osc.stop_with_when(t + 0.18).ok();
+            }
+        }
+    }
+
     /// Wave clear - triumphant fanfare
     fn play_wave_clear(&self, ctx: &AudioContext, vol: f32) {
         for (i, freq) in [400.0, 500.0, 600.0, 800.0].iter().enumerate() {
             let delay = i as f64 * 0.1;
             if let Some((osc, gain)) = self.create_osc(ctx, *freq, OscillatorType::Triangle) {
-                let t = ctx.current_time() + delay;
+                let t = self.now(ctx) + delay;
                 gain.gain().set_value_at_time(vol * 0.3, t).ok();
                 gain.gain()
                     .exponential_ramp_to_value_at_time(0.01, t + 0.4)
@@ -509,7 +1028,7 @@ impl AudioManager {
         let Some((osc, gain)) = self.create_osc(ctx, 200.0, OscillatorType::Triangle) else {
             return;
         };
-        let t = ctx.current_time();
+        let t = self.now(ctx);
 
         gain.gain().set_value_at_time(vol * 0.3, t).ok();
         gain.gain()
@@ -520,7 +1039,7 @@ impl AudioManager {
             .exponential_ramp_to_value_at_time(600.0, t + 0.15)
             .ok();
 
-        osc.start().ok();
+        osc.start_with_when(t).ok();
         osc.stop_with_when(t + 0.25).ok();
     }
 
@@ -529,7 +1048,7 @@ impl AudioManager {
         for (i, freq) in [400.0, 350.0, 300.0, 200.0].iter().enumerate() {
             let delay = i as f64 * 0.2;
             if let Some((osc, gain)) = self.create_osc(ctx, *freq, OscillatorType::Sine) {
-                let t = ctx.current_time() + delay;
+                let t = self.now(ctx) + delay;
                 gain.gain().set_value_at_time(vol * 0.3, t).ok();
                 gain.gain()
                     .exponential_ramp_to_value_at_time(0.01, t + 0.3)
@@ -545,7 +1064,7 @@ impl AudioManager {
         for (i, freq) in [500.0, 600.0, 700.0, 800.0, 1000.0].iter().enumerate() {
             let delay = i as f64 * 0.08;
             if let Some((osc, gain)) = self.create_osc(ctx, *freq, OscillatorType::Triangle) {
-                let t = ctx.current_time() + delay;
+                let t = self.now(ctx) + delay;
                 gain.gain().set_value_at_time(vol * 0.25, t).ok();
                 gain.gain()
                     .exponential_ramp_to_value_at_time(0.01, t + 0.25)
@@ -0,0 +1,124 @@
+//! Embeddable simulation API
+//!
+//! A thin wrapper around `GameState` and `tick` for running the exact game
+//! physics outside the full frontend (another web app, a server, a bot).
+//! Enabled with the `embed` feature; on wasm32 the handle is exported
+//! through `wasm_bindgen`, natively it is a plain Rust type.
+//!
+//! # Input JSON (`SimHandle::tick`)
+//!
+//! One object per fixed 120 Hz tick. Every field is optional:
+//!
+//! ```json
+//! {
+//!   "target_theta": 1.57,  // paddle target angle (radians), null = hold
+//!   "paddle_spin": 0.0,    // -1/0/+1 rotation when target_theta is null
+//!   "launch": false,       // launch attached balls
+//!   "pause": false,        // toggle pause
+//!   "skip_wave": false,    // debug: clear the current wave
+//!   "idle_mode": false     // let the demo AI drive the paddle
+//! }
+//! ```
+//!
+//! An empty string is the same as `{}`.
+//!
+//! # Output JSON (`SimHandle::snapshot_json`)
+//!
+//! The serialized `GameState`, the same format used for saved runs. Useful
+//! top-level fields:
+//!
+//! - `seed`, `wave_index`, `lives`, `score`, `combo`, `time_ticks`
+//! - `phase`: `"Serve" | "Playing" | "Breather" | "Paused" | "GameOver"`
+//! - `arena_radius`
+//! - `paddle`: `{ "theta", "arc_width", ... }`
+//! - `balls`: `[{ "id", "pos": [x, y], "vel": [x, y], "radius", "state", ... }]`
+//! - `blocks`: `[{ "id", "kind", "hp", "arc": { "radius", "thickness",
+//!   "theta_start", "theta_end" }, ... }]`
+//! - `pickups`, `effects`
+//!
+//! Cosmetic data (particles, screen shake, per-tick events) is not included.
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::consts::SIM_DT;
+use crate::sim::{GameState, TickInput, tick};
+
+/// A running simulation
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub struct SimHandle {
+    state: GameState,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl SimHandle {
+    /// Start a new run from a seed
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new(seed: u64) -> SimHandle {
+        SimHandle {
+            state: GameState::new(seed),
+        }
+    }
+
+    /// Advance one fixed timestep with the given input JSON
+    pub fn tick(&mut self, input_json: &str) -> Result<(), String> {
+        let input = if input_json.trim().is_empty() {
+            TickInput::default()
+        } else {
+            serde_json::from_str(input_json).map_err(|e| format!("invalid tick input: {}", e))?
+        };
+        tick(&mut self.state, &input, SIM_DT);
+        Ok(())
+    }
+
+    /// Current game state as JSON
+    pub fn snapshot_json(&self) -> String {
+        serde_json::to_string(&self.state).unwrap_or_default()
+    }
+}
+
+impl SimHandle {
+    /// The wrapped state (for Rust embedders)
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_matches_direct_tick() {
+        let mut handle = SimHandle::new(42);
+        let mut direct = GameState::new(42);
+
+        let inputs = [
+            r#"{"launch": true}"#,
+            "",
+            r#"{"target_theta": 1.2}"#,
+            r#"{"target_theta": -0.4, "launch": false}"#,
+        ];
+        for i in 0..240 {
+            let json = inputs[i % inputs.len()];
+            handle.tick(json).unwrap();
+            let input: TickInput = if json.is_empty() {
+                TickInput::default()
+            } else {
+                serde_json::from_str(json).unwrap()
+            };
+            tick(&mut direct, &input, SIM_DT);
+        }
+        assert_eq!(
+            handle.snapshot_json(),
+            serde_json::to_string(&direct).unwrap()
+        );
+
+        let snapshot: serde_json::Value = serde_json::from_str(&handle.snapshot_json()).unwrap();
+        assert_eq!(snapshot["seed"], 42);
+        assert!(snapshot["phase"].is_string());
+        assert!(snapshot["balls"].is_array());
+
+        assert!(handle.tick(r#"{"launch": "yes"}"#).is_err());
+    }
+}
@@ -1,10 +1,13 @@
 //! High score leaderboard system
 //!
-//! Persisted to LocalStorage, tracks top 10 scores.
+//! Persisted to platform storage, tracks the top 10 scores per difficulty.
 
 use serde::{Deserialize, Serialize};
 
-/// Maximum number of high scores to keep
+use crate::platform::Storage;
+use crate::tuning::Difficulty;
+
+/// Maximum number of high scores to keep per difficulty
 pub const MAX_HIGH_SCORES: usize = 10;
 
 /// A single high score entry
@@ -14,8 +17,17 @@ pub struct HighScoreEntry {
     pub score: u64,
     /// Wave reached
     pub wave: u32,
+    /// Run time in seconds when the game ended (0 for entries saved before timing)
+    #[serde(default)]
+    pub time_secs: u32,
     /// Unix timestamp (ms) when achieved
     pub timestamp: f64,
+    /// Difficulty the run was played on (Normal for older entries)
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Game version the run was played on (empty for older entries)
+    #[serde(default)]
+    pub game_version: String,
 }
 
 /// High score leaderboard
@@ -25,8 +37,7 @@ pub struct HighScores {
 }
 
 impl HighScores {
-    /// LocalStorage key (used only in wasm32)
-    #[allow(dead_code)]
+    /// Storage key of the leaderboard
     const STORAGE_KEY: &'static str = "roto_pong_highscores";
 
     /// Create empty leaderboard
@@ -36,113 +47,130 @@ impl HighScores {
         }
     }
 
-    /// Check if a score qualifies for the leaderboard
-    pub fn qualifies(&self, score: u64) -> bool {
-        if score == 0 {
-            return false;
-        }
-        if self.entries.len() < MAX_HIGH_SCORES {
-            return true;
-        }
-        // Check if score beats the lowest entry
-        self.entries.last().map(|e| score > e.score).unwrap_or(true)
+    /// Check if a score qualifies for the `difficulty` leaderboard
+    pub fn qualifies(&self, score: u64, difficulty: Difficulty) -> bool {
+        score > 0 && self.potential_rank(score, difficulty).is_some()
     }
 
-    /// Get the rank a score would achieve (1-indexed, None if doesn't qualify)
-    pub fn potential_rank(&self, score: u64) -> Option<usize> {
-        if !self.qualifies(score) {
+    /// Get the rank a score would achieve on the `difficulty` leaderboard
+    /// (1-indexed, None if doesn't qualify)
+    pub fn potential_rank(&self, score: u64, difficulty: Difficulty) -> Option<usize> {
+        if score == 0 {
             return None;
         }
-        let rank = self.entries.iter().position(|e| score > e.score);
-        Some(rank.unwrap_or(self.entries.len()) + 1)
+        let rank = self
+            .filtered(Some(difficulty))
+            .take_while(|e| score <= e.score)
+            .count()
+            + 1;
+        (rank <= MAX_HIGH_SCORES).then_some(rank)
     }
 
-    /// Add a new score to the leaderboard (if it qualifies)
-    /// Returns the rank achieved (1-indexed) or None if didn't qualify
-    pub fn add_score(&mut self, score: u64, wave: u32, timestamp: f64) -> Option<usize> {
-        if !self.qualifies(score) {
-            return None;
-        }
+    /// Add a new score to its difficulty's leaderboard (if it qualifies).
+    /// Returns the rank achieved there (1-indexed) or None if didn't qualify
+    pub fn add_score(
+        &mut self,
+        score: u64,
+        wave: u32,
+        time_secs: u32,
+        timestamp: f64,
+        difficulty: Difficulty,
+        game_version: &str,
+    ) -> Option<usize> {
+        let rank = self.potential_rank(score, difficulty)?;
 
         let entry = HighScoreEntry {
             score,
             wave,
+            time_secs,
             timestamp,
+            difficulty,
+            game_version: game_version.to_string(),
         };
 
         // Find insertion point (sorted descending by score)
-        let pos = self.entries.iter().position(|e| score > e.score);
-        let rank = match pos {
-            Some(i) => {
-                self.entries.insert(i, entry);
-                i + 1
-            }
-            None => {
-                self.entries.push(entry);
-                self.entries.len()
-            }
-        };
+        let pos = self
+            .entries
+            .iter()
+            .position(|e| score > e.score)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(pos, entry);
 
-        // Trim to max size
-        self.entries.truncate(MAX_HIGH_SCORES);
+        // Trim this difficulty's board to max size
+        let mut kept = 0;
+        self.entries.retain(|e| {
+            if e.difficulty != difficulty {
+                return true;
+            }
+            kept += 1;
+            kept <= MAX_HIGH_SCORES
+        });
 
         Some(rank)
     }
 
+    /// Remove the entry recorded at `timestamp` (e.g. a run that was revived)
+    pub fn remove_entry(&mut self, timestamp: f64) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.timestamp != timestamp);
+        self.entries.len() != before
+    }
+
+    /// Entries set on `difficulty` (all entries for `None`), best first
+    pub fn filtered(
+        &self,
+        difficulty: Option<Difficulty>,
+    ) -> impl Iterator<Item = &HighScoreEntry> {
+        self.entries
+            .iter()
+            .filter(move |e| difficulty.is_none_or(|d| e.difficulty == d))
+    }
+
     /// Check if the leaderboard is empty
     pub fn is_empty(&self) -> bool {
         self.entries.is_empty()
     }
 
-    /// Get the top score (if any)
-    pub fn top_score(&self) -> Option<u64> {
-        self.entries.first().map(|e| e.score)
+    /// Get the top score on `difficulty` (if any)
+    pub fn top_score(&self, difficulty: Difficulty) -> Option<u64> {
+        self.filtered(Some(difficulty)).next().map(|e| e.score)
     }
 
-    /// Load high scores from LocalStorage (WASM only)
-    #[cfg(target_arch = "wasm32")]
-    pub fn load() -> Self {
-        let storage = web_sys::window()
-            .and_then(|w| w.local_storage().ok())
-            .flatten();
-
-        if let Some(storage) = storage {
-            if let Ok(Some(json)) = storage.get_item(Self::STORAGE_KEY) {
+    /// Load high scores from `storage`, or an empty board if there are none
+    pub fn load(storage: &dyn Storage) -> Self {
+        match storage.get(Self::STORAGE_KEY) {
+            Ok(Some(json)) => {
                 if let Ok(scores) = serde_json::from_str::<HighScores>(&json) {
                     log::info!("Loaded {} high scores", scores.entries.len());
                     return scores;
                 }
             }
+            Ok(None) => {}
+            Err(e) => log::warn!("High scores couldn't be read: {}", e),
         }
 
         log::info!("No high scores found, starting fresh");
         Self::new()
     }
 
-    /// Save high scores to LocalStorage (WASM only)
-    #[cfg(target_arch = "wasm32")]
-    pub fn save(&self) {
-        let storage = web_sys::window()
-            .and_then(|w| w.local_storage().ok())
-            .flatten();
-
-        if let Some(storage) = storage {
-            if let Ok(json) = serde_json::to_string(self) {
-                let _ = storage.set_item(Self::STORAGE_KEY, &json);
-                log::info!("High scores saved ({} entries)", self.entries.len());
+    /// Save high scores to `storage`
+    pub fn save(&self, storage: &dyn Storage) {
+        if let Ok(json) = serde_json::to_string(self) {
+            match storage.set(Self::STORAGE_KEY, &json) {
+                Ok(()) => log::info!("High scores saved ({} entries)", self.entries.len()),
+                Err(e) => log::warn!("High scores not saved: {}", e),
             }
         }
     }
+}
 
-    /// Native stubs
-    #[cfg(not(target_arch = "wasm32"))]
-    pub fn load() -> Self {
-        Self::new()
-    }
-
-    #[cfg(not(target_arch = "wasm32"))]
-    pub fn save(&self) {
-        // No-op for native
+/// Format a run time as "mm:ss" (or "h:mm:ss" past an hour)
+pub fn format_run_time(secs: u32) -> String {
+    let (hours, mins, secs) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, mins, secs)
+    } else {
+        format!("{:02}:{:02}", mins, secs)
     }
 }
 
@@ -195,3 +223,93 @@ pub fn format_date(timestamp: f64) -> String {
 pub fn format_date(_timestamp: f64) -> String {
     "N/A".to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::MemoryStorage;
+
+    #[test]
+    fn test_remove_entry() {
+        let mut scores = HighScores::new();
+        scores.add_score(500, 3, 60, 1.0, Difficulty::Normal, "0.1.0");
+        scores.add_score(900, 5, 120, 2.0, Difficulty::Hard, "0.1.0");
+        assert!(scores.remove_entry(2.0));
+        assert!(!scores.remove_entry(2.0));
+        assert_eq!(scores.top_score(Difficulty::Normal), Some(500));
+        assert_eq!(scores.top_score(Difficulty::Hard), None);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let storage = MemoryStorage::new();
+        assert!(HighScores::load(&storage).is_empty());
+
+        let mut scores = HighScores::new();
+        scores.add_score(700, 4, 90, 1.0, Difficulty::Casual, "0.1.0");
+        scores.save(&storage);
+        let loaded = HighScores::load(&storage);
+        assert_eq!(loaded.top_score(Difficulty::Casual), Some(700));
+        assert_eq!(loaded.entries[0].difficulty, Difficulty::Casual);
+    }
+
+    #[test]
+    fn test_filter_by_difficulty() {
+        let mut scores = HighScores::new();
+        scores.add_score(9999, 20, 600, 1.0, Difficulty::Casual, "0.1.0");
+        scores.add_score(3000, 9, 300, 2.0, Difficulty::Hard, "0.1.0");
+        scores.add_score(1500, 6, 200, 3.0, Difficulty::Hard, "0.1.0");
+
+        let hard: Vec<u64> = scores
+            .filtered(Some(Difficulty::Hard))
+            .map(|e| e.score)
+            .collect();
+        assert_eq!(hard, [3000, 1500]);
+        assert_eq!(scores.filtered(None).count(), 3);
+        assert_eq!(scores.filtered(Some(Difficulty::Normal)).count(), 0);
+
+        // Entries saved before difficulty and version were recorded
+        let old: HighScores = serde_json::from_str(
+            r#"{"entries":[{"score":700,"wave":4,"time_secs":90,"timestamp":5.0}]}"#,
+        )
+        .unwrap();
+        assert_eq!(old.entries[0].difficulty, Difficulty::Normal);
+        assert_eq!(old.entries[0].game_version, "");
+    }
+
+    #[test]
+    fn test_boards_are_per_difficulty() {
+        let mut scores = HighScores::new();
+        for i in 0..MAX_HIGH_SCORES as u64 {
+            scores.add_score(10_000 + i, 10, 300, i as f64, Difficulty::Hard, "0.1.0");
+        }
+
+        // A full Hard board doesn't crowd out a low Casual score...
+        assert!(!scores.qualifies(500, Difficulty::Hard));
+        assert!(scores.qualifies(500, Difficulty::Casual));
+        assert_eq!(
+            scores.add_score(500, 2, 60, 50.0, Difficulty::Casual, "0.1.0"),
+            Some(1)
+        );
+
+        // ...and ranks count only runs on the same difficulty
+        assert_eq!(scores.potential_rank(10_006, Difficulty::Hard), Some(5));
+        assert_eq!(
+            scores.add_score(20_000, 30, 900, 60.0, Difficulty::Hard, "0.1.0"),
+            Some(1)
+        );
+        assert_eq!(
+            scores.filtered(Some(Difficulty::Hard)).count(),
+            MAX_HIGH_SCORES
+        );
+        assert_eq!(scores.filtered(Some(Difficulty::Casual)).count(), 1);
+        assert!(!scores.qualifies(0, Difficulty::Normal));
+    }
+
+    #[test]
+    fn test_format_run_time() {
+        assert_eq!(format_run_time(0), "00:00");
+        assert_eq!(format_run_time(75), "01:15");
+        assert_eq!(format_run_time(3725), "1:02:05");
+    }
+}
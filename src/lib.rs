@@ -6,13 +6,18 @@
 //! - `platform`: Browser/native platform abstraction
 //! - `persistence`: Save/load with integrity verification
 //! - `tuning`: Data-driven game balance
+//! - `embed`: Standalone simulation handle (`embed` feature)
 
+pub mod achievements;
+#[cfg(feature = "embed")]
+pub mod embed;
 pub mod highscores;
 pub mod persistence;
 pub mod platform;
 pub mod renderer;
 pub mod settings;
 pub mod sim;
+pub mod stats;
 pub mod tuning;
 pub mod ui;
 
@@ -21,6 +26,7 @@ pub mod audio;
 
 pub use highscores::HighScores;
 pub use settings::{QualityPreset, Settings};
+pub use stats::Stats;
 
 use glam::Vec2;
 
@@ -43,6 +49,10 @@ pub mod consts {
 
     /// Ball defaults
     pub const BALL_RADIUS: f32 = 8.0;
+    /// Most balls in play at once, including dying ones. Sized to the
+    /// renderer's ball buffer (`MAX_BALLS` in `sdf_shader.wgsl`), so every
+    /// ball the sim spawns is drawn.
+    pub const MAX_BALLS: usize = 8;
     pub const BALL_START_SPEED: f32 = 200.0;
     /// Minimum ball speed (gravity can't slow it below this)
     pub const BALL_MIN_SPEED: f32 = 150.0;
@@ -53,6 +63,8 @@ pub mod consts {
     pub const BLACK_HOLE_GRAVITY: f32 = 120.0;
     /// Speed boost when ball hits paddle (multiplicative)
     pub const PADDLE_BOOST: f32 = 1.15;
+    /// Paddle hits this close to the arc edge (fraction of half-width) are near-death saves
+    pub const NEAR_DEATH_EDGE: f32 = 0.85;
 
     /// Block defaults
     pub const BLOCK_THICKNESS: f32 = 24.0;
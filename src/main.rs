@@ -13,11 +13,15 @@ mod wasm_game {
     use wasm_bindgen::prelude::*;
     use web_sys::{HtmlCanvasElement, HtmlInputElement, MouseEvent, TouchEvent};
 
+    use roto_pong::achievements::{ACHIEVEMENTS, AchievementDef, Achievements};
     use roto_pong::consts::*;
-    use roto_pong::highscores::{HighScores, format_date};
+    use roto_pong::highscores::{HighScores, format_date, format_run_time};
+    use roto_pong::persistence::{SaveError, SaveFormat, SaveSlot};
+    use roto_pong::platform::{Clock, Storage, default_clock, default_storage};
     use roto_pong::renderer::SdfRenderState;
-    use roto_pong::settings::Settings;
-    use roto_pong::sim::{GameState, TickInput, tick};
+    use roto_pong::settings::{ColorChoice, Difficulty, KeyAction, PanMode, Settings, ZoomMode};
+    use roto_pong::sim::{GameState, Recording, ReplayPlayer, ReplayRecorder, TickInput, tick};
+    use roto_pong::stats::Stats;
 
     // JS bindings for pointer lock and mobile detection
     #[wasm_bindgen(inline_js = "
@@ -70,6 +74,28 @@ mod wasm_game {
         export function is_fullscreen() {
             return !!(document.fullscreenElement || document.webkitFullscreenElement);
         }
+        
+        export function download_text(filename, text) {
+            const url = URL.createObjectURL(new Blob([text], { type: 'text/plain' }));
+            const link = document.createElement('a');
+            link.href = url;
+            link.download = filename;
+            link.click();
+            URL.revokeObjectURL(url);
+        }
+        
+        export function pick_text_file(on_load) {
+            const input = document.createElement('input');
+            input.type = 'file';
+            input.accept = '.replay,.txt';
+            input.onchange = () => {
+                const file = input.files[0];
+                if (file) {
+                    file.text().then(on_load);
+                }
+            };
+            input.click();
+        }
     ")]
     extern "C" {
         fn is_mobile_device() -> bool;
@@ -79,16 +105,90 @@ mod wasm_game {
         fn request_fullscreen();
         fn exit_fullscreen();
         fn is_fullscreen() -> bool;
+        fn download_text(filename: &str, text: &str);
+        fn pick_text_file(on_load: &js_sys::Function);
     }
 
+    /// Slow-motion time scale during bullet time
+    ///
+    /// Applied to the frame accumulator, not to the tick `dt`: the sim still
+    /// advances in identical fixed steps (so replays and determinism are
+    /// unaffected), it just runs fewer of them per real second.
+    const BULLET_TIME_SCALE: f32 = 0.3;
+    /// Bullet time duration (real seconds)
+    const BULLET_TIME_DURATION: f32 = 0.5;
+
+    /// Hit-stop after an explosive block breaks (real seconds)
+    ///
+    /// Like bullet time, hit-stop only holds back the frame accumulator, so
+    /// the sim ticks themselves (and replays) are unchanged.
+    const HIT_STOP_EXPLOSION: f32 = 0.05;
+    /// Hit-stop after the shield saves a ball (real seconds)
+    const HIT_STOP_SHIELD_SAVE: f32 = 0.06;
+    /// Hit-stop on wave clear (real seconds)
+    const HIT_STOP_WAVE_CLEAR: f32 = 0.1;
+
+    /// Ticks advanced by Shift + the step key while paused (debug)
+    const DEBUG_STEP_BURST: u32 = 10;
+
+    /// Most lives the HUD draws as ball icons
+    const MAX_LIFE_ICONS: u8 = 5;
+
+    /// Seconds the combo HUD shows the broken combo in red
+    const COMBO_BREAK_FLASH: f32 = 0.8;
+    /// Seconds the "COMBO xN!" banner stays up
+    const COMBO_BANNER_TIME: f32 = 1.2;
+    /// Seconds a "+N" score floater drifts up before it's removed (matches
+    /// the `score-float` CSS animation)
+    const SCORE_FLOATER_TIME: f32 = 1.0;
+
+    /// Storage key of the in-progress run (`.tmp` and `.bak` hold the
+    /// write in progress and the previous save)
+    const SAVE_KEY: &str = "roto_pong_save";
+    /// Compact binary saves in release builds; debug builds keep JSON so
+    /// the save can be read in the storage inspector
+    const SAVE_FORMAT: SaveFormat = if cfg!(debug_assertions) {
+        SaveFormat::Json
+    } else {
+        SaveFormat::Binary
+    };
+
+    /// Relative touch drag sensitivity (radians per CSS pixel)
+    const TOUCH_DRAG_SENSITIVITY: f32 = 0.02;
+
     /// Game instance holding all state
     struct Game {
         state: GameState,
         render_state: Option<SdfRenderState>,
         settings: Settings,
+        // Where the saved run and high scores live
+        storage: Box<dyn Storage>,
         highscores: HighScores,
+        stats: Stats,
+        achievements: Achievements,
+        /// Seconds left to show the achievement toast
+        achievement_toast_timer: f32,
+        /// Seconds left on the combo milestone banner
+        combo_banner_timer: f32,
+        /// "+N" floaters on screen and the seconds each has left
+        score_floaters: Vec<(web_sys::Element, f32)>,
+        /// Real seconds of bullet time remaining
+        bullet_time_timer: f32,
+        /// Real seconds of hit-stop remaining (no ticks run)
+        hit_stop_timer: f32,
+        /// Seconds left to flash the last broken combo
+        combo_break_timer: f32,
+        /// Combo value shown while the combo break flash runs
+        broken_combo: u32,
+        /// Action waiting for a key press in the rebinding UI
+        rebinding: Option<KeyAction>,
         accumulator: f32,
-        last_time: f64,
+        // Events of every tick run this frame, for audio
+        frame_events: Vec<roto_pong::sim::GameEvent>,
+        // Monotonic frame clock (performance.now, not the wall clock)
+        clock: Box<dyn Clock>,
+        // Clock time of the previous frame
+        last_frame_ms: Option<f64>,
         input: TickInput,
         canvas_center: (f32, f32),
         // FPS tracking
@@ -101,28 +201,62 @@ mod wasm_game {
         pointer_locked: bool,
         // Track if score was submitted this game over
         score_submitted: bool,
+        // Timestamp of the leaderboard entry submitted this game over
+        submitted_timestamp: Option<f64>,
         // Audio
         audio: roto_pong::audio::AudioManager,
         // Mobile device detection
         is_mobile: bool,
+        // Relative touch steering: (touch identifier, last x)
+        steer_touch: Option<(i32, f32)>,
         // Arrow key states for keyboard paddle control
         key_left: bool,
         key_right: bool,
+        // Replay of the run in progress (fresh runs only)
+        recorder: Option<ReplayRecorder>,
+        // Replay of the last finished run, ready to save
+        last_replay: Option<Recording>,
+        // Playback of a replay being watched
+        replay: Option<ReplayPlayer>,
+        // The current run is a watched replay: nothing is saved or scored
+        watching_replay: bool,
+        // Difficulty the high score list shows (None = all)
+        highscore_filter: Option<Difficulty>,
     }
 
     impl Game {
         fn new(seed: u64) -> Self {
             use roto_pong::sim::GamePhase;
-            let settings = Settings::load();
+            let settings = Settings::load().unwrap_or_else(|e| {
+                log::warn!("Settings not loaded, using defaults: {}", e);
+                Settings::default()
+            });
             let mut audio = roto_pong::audio::AudioManager::new();
             audio.set_master_volume(settings.master_volume);
             audio.set_sfx_volume(settings.sfx_volume);
+            audio.set_music_volume(settings.music_volume);
+            audio.set_smoothing(settings.smooth_audio);
+            audio.set_muted(settings.muted);
+            let storage = default_storage();
             Self {
                 state: GameState::new(seed),
                 render_state: None,
-                highscores: HighScores::load(),
+                highscores: HighScores::load(storage.as_ref()),
+                storage,
+                stats: Stats::load(),
+                achievements: Achievements::load(),
+                achievement_toast_timer: 0.0,
+                combo_banner_timer: 0.0,
+                score_floaters: Vec::new(),
+                bullet_time_timer: 0.0,
+                hit_stop_timer: 0.0,
+                combo_break_timer: 0.0,
+                broken_combo: 0,
+                rebinding: None,
                 accumulator: 0.0,
-                last_time: 0.0,
+                frame_events: Vec::new(),
+                clock: default_clock(),
+                last_frame_ms: None,
                 input: TickInput::default(),
                 canvas_center: (0.0, 0.0),
                 frame_times: [0.0; 60],
@@ -131,11 +265,18 @@ mod wasm_game {
                 last_phase: GamePhase::Serve,
                 pointer_locked: false,
                 score_submitted: false,
+                submitted_timestamp: None,
                 settings,
                 audio,
                 is_mobile: is_mobile_device(),
+                steer_touch: None,
                 key_left: false,
                 key_right: false,
+                recorder: None,
+                last_replay: None,
+                replay: None,
+                watching_replay: false,
+                highscore_filter: None,
             }
         }
 
@@ -153,31 +294,138 @@ mod wasm_game {
         /// Run simulation ticks
         fn update(&mut self, dt: f32, time: f64) {
             let dt = dt.min(0.1);
-            self.accumulator += dt;
 
-            // Apply arrow key paddle movement
-            if self.key_left || self.key_right {
-                let direction = if self.key_left { 1.0 } else { -1.0 };
-                let delta = direction * self.settings.keyboard_sensitivity * dt;
-                let current = self.state.paddle.theta;
-                self.input.target_theta = Some(current + delta);
+            // Bullet time slows the accumulator, not the sim step; hit-stop
+            // holds it still while frames keep rendering
+            let time_scale = if self.hit_stop_timer > 0.0 {
+                self.hit_stop_timer -= dt;
+                0.0
+            } else if self.bullet_time_timer > 0.0 {
+                self.bullet_time_timer -= dt;
+                BULLET_TIME_SCALE
+            } else {
+                1.0
+            };
+            self.accumulator += dt * time_scale;
+
+            // Held rotate keys spin the paddle (opposite keys cancel out)
+            self.input.paddle_spin = self.key_left as i8 as f32 - self.key_right as i8 as f32;
+            if self.input.paddle_spin != 0.0 {
+                self.input.target_theta = None;
             }
 
+            // Hit-stop starts right on the impact tick, so it also ends the loop
             let mut substeps = 0;
-            while self.accumulator >= SIM_DT && substeps < MAX_SUBSTEPS {
+            while self.accumulator >= SIM_DT
+                && substeps < MAX_SUBSTEPS
+                && self.hit_stop_timer <= 0.0
+            {
                 let input = self.input.clone();
-                tick(&mut self.state, &input, SIM_DT);
+                if self.watching_replay {
+                    // Recorded input drives the sim until the recording ends
+                    if !self.step_replay() {
+                        self.accumulator = 0.0;
+                        break;
+                    }
+                } else {
+                    tick(&mut self.state, &input, SIM_DT);
+                    if let Some(recorder) = &mut self.recorder {
+                        recorder.record(&input, &self.state);
+                    }
+                    self.stats.record_tick(&self.state);
+                }
+                if self.settings.effective_bullet_time()
+                    && self
+                        .state
+                        .events
+                        .contains(&roto_pong::sim::GameEvent::NearDeathSave)
+                {
+                    self.bullet_time_timer = BULLET_TIME_DURATION;
+                }
+                if let Some(previous) = self.state.events.iter().find_map(|e| match e {
+                    roto_pong::sim::GameEvent::ComboBreak { previous } => Some(*previous),
+                    _ => None,
+                }) {
+                    self.broken_combo = previous;
+                    self.combo_break_timer = COMBO_BREAK_FLASH;
+                }
+                if let Some(milestone) = self.state.events.iter().rev().find_map(|e| match e {
+                    roto_pong::sim::GameEvent::ComboMilestone(m) => Some(*m),
+                    _ => None,
+                }) {
+                    self.show_combo_banner(milestone);
+                }
+                if self.settings.hit_stop {
+                    self.hit_stop_timer = self.hit_stop_timer.max(self.hit_stop_for_events());
+                }
+                if !self.watching_replay {
+                    let unlocked = self.achievements.check(&mut self.state, &self.stats);
+                    if !unlocked.is_empty() {
+                        self.achievements.save();
+                        for def in unlocked {
+                            log::info!("Achievement unlocked: {}", def.name);
+                            self.show_achievement_toast(def);
+                        }
+                    }
+                }
+                self.frame_events.extend(self.state.events.iter().copied());
                 self.accumulator -= SIM_DT;
                 substeps += 1;
 
                 // Clear one-shot inputs after processing
                 self.input.launch = false;
+                self.input.catch = false;
                 self.input.pause = false;
                 self.input.skip_wave = false;
             }
 
-            // Play audio for game events
+            // Play audio for the events of every tick run this frame (not
+            // just the last one)
             self.play_audio_events();
+            self.spawn_score_floaters();
+            self.frame_events.clear();
+
+            // Background music runs except over the game over screen
+            if self.state.phase == roto_pong::sim::GamePhase::GameOver {
+                self.audio.stop_music();
+            } else {
+                self.audio.start_music();
+            }
+            self.audio.set_intensity(self.state.music_intensity());
+            self.audio.update_music();
+
+            // Hide achievement toast when its time is up
+            if self.achievement_toast_timer > 0.0 {
+                self.achievement_toast_timer -= dt;
+                if self.achievement_toast_timer <= 0.0 {
+                    let document = web_sys::window().unwrap().document().unwrap();
+                    if let Some(el) = document.get_element_by_id("achievement-toast") {
+                        let _ = el.set_attribute("class", "hidden");
+                    }
+                }
+            }
+
+            // Remove score floaters that have finished drifting
+            self.score_floaters.retain_mut(|(el, time_left)| {
+                *time_left -= dt;
+                if *time_left <= 0.0 {
+                    el.remove();
+                }
+                *time_left > 0.0
+            });
+
+            // Hide the combo banner when its time is up
+            if self.combo_banner_timer > 0.0 {
+                self.combo_banner_timer -= dt;
+                if self.combo_banner_timer <= 0.0 {
+                    let document = web_sys::window().unwrap().document().unwrap();
+                    if let Some(el) = document.get_element_by_id("combo-banner") {
+                        let _ = el.set_attribute("class", "hidden");
+                    }
+                }
+            }
+
+            self.combo_break_timer = (self.combo_break_timer - dt).max(0.0);
 
             // Track frame times for FPS
             self.frame_times[self.frame_index] = time;
@@ -201,13 +449,30 @@ mod wasm_game {
                 if current_phase == GamePhase::Breather || current_phase == GamePhase::Paused {
                     self.save_game();
                 }
+                // Persist lifetime stats at natural checkpoints
+                if matches!(
+                    current_phase,
+                    GamePhase::Breather | GamePhase::Paused | GamePhase::GameOver
+                ) {
+                    self.stats.save();
+                }
                 // Release pointer lock when paused so menu can be used
                 if current_phase == GamePhase::Paused {
                     exit_pointer_lock();
                 }
                 // Submit score when entering GameOver
                 if current_phase == GamePhase::GameOver {
+                    use roto_pong::audio::SoundEffect;
+                    if let Some(recorder) = self.recorder.take() {
+                        self.last_replay = Some(recorder.finish(&self.state));
+                    }
                     let rank = self.submit_score();
+                    // A new record gets the fanfare instead of the game-over dirge
+                    self.audio.play(if rank.is_some() {
+                        SoundEffect::HighScore
+                    } else {
+                        SoundEffect::GameOver
+                    });
                     self.show_game_over_highscore(rank);
                     // Release pointer lock so menu can be used
                     exit_pointer_lock();
@@ -216,17 +481,143 @@ mod wasm_game {
             }
         }
 
-        /// Play audio for game events
+        /// Show the "achievement unlocked" toast
+        fn show_achievement_toast(&mut self, def: &AchievementDef) {
+            let document = web_sys::window().unwrap().document().unwrap();
+            if let Some(el) = document.get_element_by_id("achievement-toast-name") {
+                el.set_text_content(Some(def.name));
+            }
+            if let Some(el) = document.get_element_by_id("achievement-toast") {
+                let _ = el.set_attribute("class", "");
+            }
+            self.achievement_toast_timer = 3.0;
+        }
+
+        /// Flash "COMBO xN!" over the arena
+        fn show_combo_banner(&mut self, milestone: u32) {
+            let document = web_sys::window().unwrap().document().unwrap();
+            if let Some(el) = document.get_element_by_id("combo-banner") {
+                el.set_text_content(Some(&format!("COMBO x{}!", milestone)));
+                let _ = el.set_attribute("class", "");
+            }
+            self.combo_banner_timer = COMBO_BANNER_TIME;
+        }
+
+        /// Show a "+N" floater where each aggregated score of this frame's
+        /// events was earned (e.g. a whole explosion chain)
+        fn spawn_score_floaters(&mut self) {
+            use roto_pong::sim::GameEvent;
+
+            let Some(render_state) = &self.render_state else {
+                return;
+            };
+            let document = web_sys::window().unwrap().document().unwrap();
+            let Some(layer) = document.get_element_by_id("score-floaters") else {
+                return;
+            };
+            for event in &self.frame_events {
+                let GameEvent::Score { amount, pos } = *event else {
+                    continue;
+                };
+                let Ok(el) = document.create_element("div") else {
+                    continue;
+                };
+                let (x, y) = render_state.world_to_screen(pos);
+                el.set_class_name("score-floater");
+                el.set_text_content(Some(&format!("+{}", amount)));
+                let _ = el.set_attribute(
+                    "style",
+                    &format!("left: {}%; top: {}%", x * 100.0, y * 100.0),
+                );
+                if layer.append_child(&el).is_ok() {
+                    self.score_floaters.push((el, SCORE_FLOATER_TIME));
+                }
+            }
+        }
+
+        /// Turn the manual mute on or off and remember it across reloads
+        fn set_muted(&mut self, muted: bool) {
+            self.settings.muted = muted;
+            self.audio.set_muted(muted);
+            self.settings.save();
+            sync_settings_ui(&self.settings);
+            log::info!("Sound: {}", if muted { "OFF" } else { "ON" });
+        }
+
+        /// Debug: advance exactly one tick while paused, using the held input,
+        /// and log the state hash. Stats and achievements aren't touched.
+        fn step_once(&mut self) {
+            use roto_pong::sim::GamePhase;
+
+            if self.state.phase != GamePhase::Paused {
+                return;
+            }
+            // Stepping resumes the sim behind tick()'s back, so a replay
+            // of this run couldn't reproduce it
+            self.recorder = None;
+            self.state.phase = if self.state.any_attached() {
+                GamePhase::Serve
+            } else {
+                GamePhase::Playing
+            };
+            let input = TickInput {
+                pause: false,
+                skip_wave: false,
+                ..self.input.clone()
+            };
+            tick(&mut self.state, &input, SIM_DT);
+            self.frame_events.extend(self.state.events.iter().copied());
+            self.input.launch = false;
+            self.input.catch = false;
+            log::info!(
+                "Step: tick {} hash {:016x}",
+                self.state.time_ticks,
+                self.state.state_hash()
+            );
+            // Breather and game over carry on as usual
+            if matches!(self.state.phase, GamePhase::Playing | GamePhase::Serve) {
+                self.state.phase = GamePhase::Paused;
+            }
+        }
+
+        /// Debug: step up to `n` ticks, stopping if the run leaves pause
+        fn step_ticks(&mut self, n: u32) {
+            for _ in 0..n {
+                if self.state.phase != roto_pong::sim::GamePhase::Paused {
+                    break;
+                }
+                self.step_once();
+            }
+        }
+
+        /// Hit-stop for the biggest impact in this tick's events (0 if none)
+        fn hit_stop_for_events(&self) -> f32 {
+            use roto_pong::sim::{BlockKind, GameEvent};
+
+            self.state
+                .events
+                .iter()
+                .map(|event| match event {
+                    GameEvent::BlockBreak(BlockKind::Explosive, _) => HIT_STOP_EXPLOSION,
+                    GameEvent::ShieldSave => HIT_STOP_SHIELD_SAVE,
+                    GameEvent::WaveClear => HIT_STOP_WAVE_CLEAR,
+                    _ => 0.0,
+                })
+                .fold(0.0, f32::max)
+        }
+
+        /// Play audio for the events collected this frame
         fn play_audio_events(&mut self) {
             use roto_pong::audio::SoundEffect;
             use roto_pong::sim::{BlockKind, GameEvent};
 
-            for event in &self.state.events {
+            for event in &self.frame_events {
                 let sfx = match event {
-                    GameEvent::PaddleHit => SoundEffect::PaddleHit,
-                    GameEvent::WallHit => SoundEffect::WallHit,
-                    GameEvent::BlockHit => SoundEffect::BlockHit,
-                    GameEvent::BlockBreak(kind) => match kind {
+                    GameEvent::PaddleHit(_) => SoundEffect::PaddleHit,
+                    GameEvent::NearDeathSave => continue, // PaddleHit already plays
+                    GameEvent::WallHit(_) => SoundEffect::WallHit,
+                    GameEvent::BlockHit(_) => SoundEffect::BlockHit,
+                    GameEvent::BlockBreak(kind, _) => match kind {
                         BlockKind::Glass => SoundEffect::BlockBreakGlass,
                         BlockKind::Armored => SoundEffect::BlockBreakArmored,
                         BlockKind::Explosive => SoundEffect::BlockBreakExplosive,
@@ -237,14 +628,38 @@ mod wasm_game {
                         BlockKind::Invincible => continue, // Shouldn't happen
                         BlockKind::Magnet => SoundEffect::BlockBreakArmored, // Metallic
                         BlockKind::Ghost => SoundEffect::BlockBreakGlass, // Ethereal shatter
+                        BlockKind::Bumper => continue,     // Never breaks
                     },
-                    GameEvent::PickupCollect => SoundEffect::PickupCollect,
+                    GameEvent::BumperHit(_) => SoundEffect::Bumper,
+                    GameEvent::PickupCollect(_) => SoundEffect::PickupCollect,
                     GameEvent::BallLost => SoundEffect::BlackHoleConsume,
+                    GameEvent::ShieldSave => continue, // No sound of its own yet
+                    // Shown as a floater; the break sounds already play
+                    GameEvent::Score { .. } => continue,
+                    GameEvent::ComboBreak { .. } => SoundEffect::ComboBreak,
+                    GameEvent::ComboMilestone(milestone) => SoundEffect::ComboMilestone(*milestone),
                     GameEvent::WaveClear => SoundEffect::WaveClear,
+                    GameEvent::RingClear { .. } => SoundEffect::PickupCollect,
+                    GameEvent::PuristBonus { .. } => SoundEffect::PickupCollect,
                     GameEvent::Launch => SoundEffect::Launch,
-                    GameEvent::GameOver => SoundEffect::GameOver,
+                    // Played at the game-over transition, once the score's rank is known
+                    GameEvent::GameOver => continue,
+                    GameEvent::Achievement(_) => SoundEffect::PickupCollect,
                 };
-                self.audio.play(sfx);
+                // Heartbeat once when a lost ball leaves the last life
+                if *event == GameEvent::BallLost
+                    && self.state.lives == 1
+                    && self.settings.low_lives_warning
+                {
+                    self.audio.play(SoundEffect::LowLives);
+                }
+                let pan = event
+                    .pos()
+                    .and_then(|pos| self.settings.pan_mode.pan(pos.x, self.state.arena_radius));
+                match pan {
+                    Some(pan) => self.audio.play_at(sfx, pan),
+                    None => self.audio.play(sfx),
+                }
             }
         }
 
@@ -264,9 +679,12 @@ mod wasm_game {
             // Show rank info
             if let Some(rank_el) = document.get_element_by_id("highscore-rank") {
                 if let Some(r) = rank {
-                    rank_el.set_text_content(Some(&format!("Rank #{} on leaderboard!", r)));
-                } else if self.highscores.top_score().is_some() {
-                    let top = self.highscores.top_score().unwrap();
+                    rank_el.set_text_content(Some(&format!(
+                        "Rank #{} on the {} leaderboard!",
+                        r,
+                        self.state.tuning.difficulty.as_str()
+                    )));
+                } else if let Some(top) = self.highscores.top_score(self.state.tuning.difficulty) {
                     if self.state.score > 0 {
                         rank_el.set_text_content(Some(&format!("Best: {}", top)));
                     } else {
@@ -280,8 +698,14 @@ mod wasm_game {
 
         /// Render the current frame
         fn render(&mut self, time: f64) {
+            // A replay's paddle follows the recording, not the pointer
+            let target_theta = if self.watching_replay {
+                None
+            } else {
+                self.input.target_theta
+            };
             if let Some(ref mut render_state) = self.render_state {
-                match render_state.render(&self.state, &self.settings, time) {
+                match render_state.render(&self.state, &self.settings, target_theta, time) {
                     Ok(_) => {}
                     Err(wgpu::SurfaceError::Lost) => {
                         render_state.resize(render_state.size.0, render_state.size.1);
@@ -310,13 +734,26 @@ mod wasm_game {
                 el.set_text_content(Some(&self.state.score.to_string()));
             }
 
-            // Update lives
+            // Update lives (icons fall back to a number past a handful)
+            let lives = self.state.lives;
             if let Some(el) = document
                 .query_selector("#hud-lives .hud-value")
                 .ok()
                 .flatten()
             {
-                el.set_text_content(Some(&self.state.lives.to_string()));
+                let text = if self.settings.lives_icons && lives <= MAX_LIFE_ICONS {
+                    "●".repeat(lives as usize)
+                } else {
+                    lives.to_string()
+                };
+                el.set_text_content(Some(&text));
+            }
+            // Last life: pulse the counter
+            if let Some(el) = document.get_element_by_id("hud-lives") {
+                let low = self.settings.low_lives_warning
+                    && lives == 1
+                    && self.state.phase != GamePhase::GameOver;
+                let _ = el.set_attribute("class", if low { "hud-item low" } else { "hud-item" });
             }
 
             // Update wave
@@ -328,6 +765,62 @@ mod wasm_game {
                 el.set_text_content(Some(&(self.state.wave_index + 1).to_string()));
             }
 
+            // Chaos modifier banner: the upcoming wave's during the breather,
+            // the current wave's until the ball is launched
+            if let Some(el) = document.get_element_by_id("wave-modifier") {
+                let modifiers = match self.state.phase {
+                    GamePhase::Breather => roto_pong::sim::wave_modifiers_for(
+                        self.state.wave_index,
+                        self.state.seed,
+                        &self.state.tuning,
+                    ),
+                    GamePhase::Serve => self.state.wave_modifiers,
+                    _ => roto_pong::sim::WaveModifiers::NONE,
+                };
+                if modifiers.is_empty() {
+                    let _ = el.set_attribute("class", "hidden");
+                } else {
+                    let text = format!("⚠ {}", modifiers.names().collect::<Vec<_>>().join(" + "));
+                    el.set_text_content(Some(&text));
+                    let _ = el.set_attribute("class", "");
+                }
+            }
+
+            if let Some(el) = document.get_element_by_id("replay-badge") {
+                let class = if self.watching_replay { "" } else { "hidden" };
+                let _ = el.set_attribute("class", class);
+            }
+
+            // Update wave progress (blocks left, bar fills as they're destroyed)
+            if let Some(el) = document
+                .query_selector("#hud-progress .hud-value")
+                .ok()
+                .flatten()
+            {
+                el.set_text_content(Some(&self.state.clearable_blocks().to_string()));
+            }
+            if let Some(bar) = document.get_element_by_id("hud-progress-bar") {
+                let pct = self.state.wave_progress() * 100.0;
+                let _ = bar.set_attribute("style", &format!("width: {}%", pct));
+            }
+
+            // Update run timer (respect settings)
+            if let Some(el) = document.get_element_by_id("hud-time") {
+                if self.settings.show_timer {
+                    let _ = el.set_attribute("class", "hud-item");
+                    if let Some(val) = document
+                        .query_selector("#hud-time .hud-value")
+                        .ok()
+                        .flatten()
+                    {
+                        let secs = self.state.elapsed_secs() as u32;
+                        val.set_text_content(Some(&format_run_time(secs)));
+                    }
+                } else {
+                    let _ = el.set_attribute("class", "hud-item hidden");
+                }
+            }
+
             // Update FPS (respect settings)
             if let Some(el) = document.get_element_by_id("hud-fps") {
                 if self.settings.show_fps {
@@ -344,9 +837,31 @@ mod wasm_game {
                 }
             }
 
-            // Update combo (only show when 2+ for actual combo)
+            // Mute indicator
+            if let Some(el) = document.get_element_by_id("hud-mute") {
+                let class = if self.settings.muted {
+                    "hud-item"
+                } else {
+                    "hud-item hidden"
+                };
+                let _ = el.set_attribute("class", class);
+            }
+
+            // Update combo (only show when 2+ for actual combo, or the
+            // broken combo in red right after it was lost)
             if let Some(el) = document.get_element_by_id("hud-combo") {
-                if self.state.combo > 1 {
+                if self.combo_break_timer > 0.0 && self.state.combo <= 1 {
+                    if el.get_attribute("class").as_deref() != Some("hud-item broken") {
+                        let _ = el.set_attribute("class", "hud-item broken");
+                        if let Some(val) = document
+                            .query_selector("#hud-combo .hud-value")
+                            .ok()
+                            .flatten()
+                        {
+                            val.set_text_content(Some(&self.broken_combo.to_string()));
+                        }
+                    }
+                } else if self.state.combo > 1 {
                     let _ = el.set_attribute("class", "hud-item");
 
                     // Update combo value
@@ -370,7 +885,7 @@ mod wasm_game {
                         .ok()
                         .flatten()
                     {
-                        let multiplier = (1.0 + (self.state.combo - 1) as f32 * 0.1).min(3.0);
+                        let multiplier = self.state.tuning.combo_multiplier(self.state.combo);
                         mult.set_text_content(Some(&format!("x{:.1}", multiplier)));
                     }
                 } else {
@@ -378,45 +893,77 @@ mod wasm_game {
                 }
             }
 
-            // Update power-up indicators
-            // Slow (5 sec = 600 ticks)
+            // Update power-up indicators. Timer bars are relative to the
+            // run's tuned pickup durations.
+            // Slow
             if let Some(el) = document.get_element_by_id("powerup-slow") {
-                if self.state.effects.slow_ticks > 0 {
+                let ticks = self.state.effects.slow_ticks;
+                if ticks > 0 {
                     let _ = el.set_attribute("class", "powerup-icon active");
                     if let Some(bar) = document.get_element_by_id("powerup-slow-bar") {
-                        let pct = (self.state.effects.slow_ticks as f32 / 600.0 * 100.0).min(100.0);
+                        let full = self.state.tuning.pickups.slow_ticks.max(1);
+                        let pct = (ticks as f32 / full as f32 * 100.0).min(100.0);
                         let _ = bar.set_attribute("style", &format!("width: {}%", pct));
                     }
                 } else {
                     let _ = el.set_attribute("class", "powerup-icon");
                 }
             }
-            // Piercing (4 sec = 480 ticks)
+            // Piercing
             if let Some(el) = document.get_element_by_id("powerup-piercing") {
-                if self.state.effects.piercing_ticks > 0 {
+                let ticks = self.state.effects.piercing_ticks;
+                if ticks > 0 {
                     let _ = el.set_attribute("class", "powerup-icon active");
                     if let Some(bar) = document.get_element_by_id("powerup-piercing-bar") {
-                        let pct =
-                            (self.state.effects.piercing_ticks as f32 / 480.0 * 100.0).min(100.0);
+                        let full = self.state.tuning.pickups.piercing_ticks.max(1);
+                        let pct = (ticks as f32 / full as f32 * 100.0).min(100.0);
                         let _ = bar.set_attribute("style", &format!("width: {}%", pct));
                     }
                 } else {
                     let _ = el.set_attribute("class", "powerup-icon");
                 }
             }
-            // Widen (6 sec = 720 ticks)
+            // Widen (bar shows the current stack)
             if let Some(el) = document.get_element_by_id("powerup-widen") {
-                if self.state.effects.widen_ticks > 0 {
+                let ticks = self.state.effects.widen_ticks;
+                if ticks > 0 {
                     let _ = el.set_attribute("class", "powerup-icon active");
                     if let Some(bar) = document.get_element_by_id("powerup-widen-bar") {
-                        let pct =
-                            (self.state.effects.widen_ticks as f32 / 720.0 * 100.0).min(100.0);
+                        let full = self.state.tuning.pickups.widen_ticks.max(1);
+                        let pct = (ticks as f32 / full as f32 * 100.0).min(100.0);
                         let _ = bar.set_attribute("style", &format!("width: {}%", pct));
                     }
                 } else {
                     let _ = el.set_attribute("class", "powerup-icon");
                 }
             }
+            // Ball catch (once per life; flashes while armed)
+            if let Some(el) = document.get_element_by_id("powerup-catch") {
+                let class = if self.state.catch_armed {
+                    "powerup-icon active flash"
+                } else if self.state.catch_available {
+                    "powerup-icon active"
+                } else {
+                    "powerup-icon"
+                };
+                let _ = el.set_attribute("class", class);
+            }
+            // Sticky paddle (until a ball sticks - no timer)
+            if let Some(el) = document.get_element_by_id("powerup-sticky") {
+                if self.state.effects.sticky_active {
+                    let _ = el.set_attribute("class", "powerup-icon active");
+                } else {
+                    let _ = el.set_attribute("class", "powerup-icon");
+                }
+            }
+            // Heavy ball (lasts while a heavy ball is in play)
+            if let Some(el) = document.get_element_by_id("powerup-heavy") {
+                if self.state.balls.iter().any(|b| b.mass > 1.0) {
+                    let _ = el.set_attribute("class", "powerup-icon active");
+                } else {
+                    let _ = el.set_attribute("class", "powerup-icon");
+                }
+            }
             // Shield (until used - no timer)
             if let Some(el) = document.get_element_by_id("powerup-shield") {
                 if self.state.effects.shield_active {
@@ -425,6 +972,34 @@ mod wasm_game {
                     let _ = el.set_attribute("class", "powerup-icon");
                 }
             }
+            // Freeze
+            if let Some(el) = document.get_element_by_id("powerup-freeze") {
+                let ticks = self.state.effects.freeze_ticks;
+                if ticks > 0 {
+                    let _ = el.set_attribute("class", "powerup-icon active");
+                    if let Some(bar) = document.get_element_by_id("powerup-freeze-bar") {
+                        let full = self.state.tuning.pickups.freeze_ticks.max(1);
+                        let pct = (ticks as f32 / full as f32 * 100.0).min(100.0);
+                        let _ = bar.set_attribute("style", &format!("width: {}%", pct));
+                    }
+                } else {
+                    let _ = el.set_attribute("class", "powerup-icon");
+                }
+            }
+            // Time Warp
+            if let Some(el) = document.get_element_by_id("powerup-timewarp") {
+                let ticks = self.state.effects.time_warp_ticks;
+                if ticks > 0 {
+                    let _ = el.set_attribute("class", "powerup-icon active");
+                    if let Some(bar) = document.get_element_by_id("powerup-timewarp-bar") {
+                        let full = self.state.tuning.pickups.time_warp_ticks.max(1);
+                        let pct = (ticks as f32 / full as f32 * 100.0).min(100.0);
+                        let _ = bar.set_attribute("style", &format!("width: {}%", pct));
+                    }
+                } else {
+                    let _ = el.set_attribute("class", "powerup-icon");
+                }
+            }
 
             // Show/hide serve prompt
             if let Some(el) = document.get_element_by_id("serve-prompt") {
@@ -435,12 +1010,31 @@ mod wasm_game {
                 }
             }
 
+            // Show/hide touch launch button (relative touch mode only)
+            if let Some(el) = document.get_element_by_id("touch-launch-btn") {
+                if self.is_mobile
+                    && self.settings.touch_relative
+                    && self.state.phase == GamePhase::Serve
+                {
+                    let _ = el.set_attribute("class", "");
+                } else {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+            }
+
             // Show/hide pause menu
             if let Some(el) = document.get_element_by_id("pause-menu") {
                 if self.state.phase == GamePhase::Paused {
                     let _ = el.set_attribute("class", "");
+                    if let Some(seed_el) = document.get_element_by_id("pause-seed") {
+                        seed_el.set_text_content(Some(&self.state.seed.to_string()));
+                    }
                 } else {
                     let _ = el.set_attribute("class", "hidden");
+                    // Resumed (ESC) with the abandon prompt still open
+                    if let Some(modal) = document.get_element_by_id("abandon-modal") {
+                        let _ = modal.set_attribute("class", "hidden");
+                    }
                 }
             }
 
@@ -455,100 +1049,282 @@ mod wasm_game {
                     if let Some(wave_el) = document.get_element_by_id("final-wave") {
                         wave_el.set_text_content(Some(&(self.state.wave_index + 1).to_string()));
                     }
-                    // Clear saved game on game over
-                    clear_saved_game();
+                    if let Some(time_el) = document.get_element_by_id("final-time") {
+                        let secs = self.state.elapsed_secs() as u32;
+                        time_el.set_text_content(Some(&format_run_time(secs)));
+                    }
+                    if let Some(seed_el) = document.get_element_by_id("final-seed") {
+                        seed_el.set_text_content(Some(&self.state.seed.to_string()));
+                    }
+                    if let Some(btn) = document.get_element_by_id("revive-btn") {
+                        let class = if self.state.revive_used || self.watching_replay {
+                            "hidden"
+                        } else {
+                            ""
+                        };
+                        let _ = btn.set_attribute("class", class);
+                    }
+                    if let Some(btn) = document.get_element_by_id("replay-save-btn") {
+                        let class = if self.last_replay.is_some() {
+                            ""
+                        } else {
+                            "hidden"
+                        };
+                        let _ = btn.set_attribute("class", class);
+                    }
+                    // Clear saved game on game over (a watched replay never had one)
+                    if !self.watching_replay {
+                        clear_saved_game(self.storage.as_ref());
+                    }
                 } else {
                     let _ = el.set_attribute("class", "hidden");
                 }
             }
         }
 
-        /// Save game state to LocalStorage
+        /// Save game state to storage
         fn save_game(&self) {
-            if let Ok(json) = serde_json::to_string(&self.state) {
-                if let Some(storage) = web_sys::window()
-                    .and_then(|w| w.local_storage().ok())
-                    .flatten()
-                {
-                    let _ = storage.set_item("roto_pong_save", &json);
-                    log::info!("Game saved (wave {})", self.state.wave_index + 1);
-                }
+            if self.watching_replay {
+                return;
+            }
+            match save_slot(self.storage.as_ref()).write(&self.state) {
+                Ok(bytes) => log::info!(
+                    "Game saved (wave {}, {} bytes)",
+                    self.state.wave_index + 1,
+                    bytes
+                ),
+                Err(e) => log::warn!("Game not saved: {}", e),
             }
         }
 
         /// Reset game state for restart
         fn restart(&mut self, seed: u64) {
-            self.state = GameState::new(seed);
+            self.state = GameState::new(seed).with_tuning(self.settings.difficulty.tuning());
+            self.state.tuning.gravity_enabled = !self.settings.relaxed_mode;
+            self.state.tuning.keyboard_speed = self.settings.keyboard_sensitivity;
             self.accumulator = 0.0;
             self.input = TickInput::default();
             self.score_submitted = false;
+            self.submitted_timestamp = None;
+            self.recorder = None;
+            self.last_replay = None;
+            self.replay = None;
+            self.watching_replay = false;
+        }
+
+        /// Start a fresh run from `seed`, or from the clock if none is given.
+        /// Returns the seed used.
+        fn new_game(&mut self, seed: Option<u64>) -> u64 {
+            let seed = seed.unwrap_or_else(|| js_sys::Date::now() as u64);
+            self.restart(seed);
+            roto_pong::sim::generate_wave(&mut self.state);
+            self.recorder = Some(ReplayRecorder::new(seed, &self.state.tuning));
+            seed
+        }
+
+        /// Watch a recorded run from its start. Nothing it does is saved,
+        /// scored or counted in stats.
+        fn watch_replay(&mut self, recording: Recording) {
+            self.restart(recording.seed);
+            self.state = recording.start_state();
+            self.last_phase = self.state.phase;
+            self.watching_replay = true;
+            self.replay = Some(ReplayPlayer::new(recording));
+        }
+
+        /// Run the next tick of the replay being watched. Returns false once
+        /// the recording has ended or drifted from its checkpoints.
+        fn step_replay(&mut self) -> bool {
+            let Some(player) = &mut self.replay else {
+                return false;
+            };
+            match player.step(&mut self.state) {
+                Ok(true) => true,
+                Ok(false) => {
+                    log::info!("Replay finished");
+                    self.replay = None;
+                    false
+                }
+                Err(e) => {
+                    log::warn!("Replay stopped: {}", e);
+                    self.replay = None;
+                    false
+                }
+            }
+        }
+
+        /// Leave a replay early, with a fresh run behind the main menu
+        fn stop_replay(&mut self) {
+            self.restart(js_sys::Date::now() as u64);
+            self.last_phase = self.state.phase;
+            log::info!("Replay closed");
+        }
+
+        /// Throw the current run away: no save, no high score, no lifetime
+        /// stats checkpoint. Leaves a fresh run behind the main menu, the
+        /// same as at startup.
+        fn abandon_run(&mut self) {
+            clear_saved_game(self.storage.as_ref());
+            self.restart(js_sys::Date::now() as u64);
+            // Skip the phase-transition hooks for the discarded run
+            self.last_phase = self.state.phase;
+            log::info!("Run abandoned");
         }
 
         /// Load game state from saved data
         fn load_state(&mut self, state: GameState) {
             self.state = state;
+            // The save holds the sensitivity it was made with
+            self.state.tuning.keyboard_speed = self.settings.keyboard_sensitivity;
             self.accumulator = 0.0;
             self.input = TickInput::default();
             self.score_submitted = false;
+            self.submitted_timestamp = None;
+            // A run resumed mid-way can't be replayed from its seed
+            self.recorder = None;
+            self.last_replay = None;
+            self.replay = None;
+            self.watching_replay = false;
+        }
+
+        /// Continue a finished run with its one revive.
+        /// The score submitted at game over is withdrawn so the continued
+        /// run's final score is recorded once.
+        fn revive(&mut self) -> bool {
+            if !self.state.revive() {
+                return false;
+            }
+            if let Some(timestamp) = self.submitted_timestamp.take() {
+                if self.highscores.remove_entry(timestamp) {
+                    self.highscores.save(self.storage.as_ref());
+                }
+            }
+            self.score_submitted = false;
+            self.accumulator = 0.0;
+            self.input = TickInput::default();
+            log::info!("Run revived at wave {}", self.state.wave_index + 1);
+            true
         }
 
-        /// Submit score to high scores (returns rank if qualified)
+        /// Submit score to high scores (returns rank if qualified).
+        /// Relaxed runs and watched replays are never ranked.
         fn submit_score(&mut self) -> Option<usize> {
-            if self.score_submitted || self.state.score == 0 {
+            if self.score_submitted
+                || self.watching_replay
+                || self.state.score == 0
+                || !self.state.tuning.gravity_enabled
+            {
                 return None;
             }
             self.score_submitted = true;
             let timestamp = js_sys::Date::now();
-            let rank =
-                self.highscores
-                    .add_score(self.state.score, self.state.wave_index + 1, timestamp);
+            self.submitted_timestamp = Some(timestamp);
+            let rank = self.highscores.add_score(
+                self.state.score,
+                self.state.wave_index + 1,
+                self.state.elapsed_secs() as u32,
+                timestamp,
+                self.state.tuning.difficulty,
+                env!("CARGO_PKG_VERSION"),
+            );
             if rank.is_some() {
-                self.highscores.save();
+                self.highscores.save(self.storage.as_ref());
             }
             rank
         }
     }
 
-    /// Load saved game from LocalStorage
-    fn load_saved_game() -> Option<GameState> {
-        let storage = web_sys::window()?.local_storage().ok()??;
-        let json = storage.get_item("roto_pong_save").ok()??;
-        serde_json::from_str(&json).ok()
+    /// Load the saved game. `Ok(None)` means there is no save; a corrupt
+    /// save is discarded and its error returned so the menu can tell the
+    /// player.
+    fn load_saved_game(storage: &dyn Storage) -> Result<Option<GameState>, SaveError> {
+        save_slot(storage).read().inspect_err(|e| {
+            if e.is_corrupt() {
+                log::warn!("Corrupt save discarded: {}", e);
+                clear_saved_game(storage);
+            } else {
+                // Possibly transient: keep the save for the next launch
+                log::warn!("Saved game not loaded: {}", e);
+            }
+        })
     }
 
-    /// Clear saved game from LocalStorage
-    fn clear_saved_game() {
-        if let Some(storage) = web_sys::window()
-            .and_then(|w| w.local_storage().ok())
-            .flatten()
-        {
-            let _ = storage.remove_item("roto_pong_save");
+    /// Save slot of the in-progress run
+    fn save_slot(storage: &dyn Storage) -> SaveSlot<'_> {
+        SaveSlot::new(storage, SAVE_KEY, SAVE_FORMAT)
+    }
+
+    /// Clear saved game (and its backup)
+    fn clear_saved_game(storage: &dyn Storage) {
+        if save_slot(storage).clear().is_ok() {
             log::info!("Saved game cleared");
         }
     }
 
     /// Render high scores list to DOM
-    fn render_highscores_list(highscores: &HighScores) {
+    fn render_highscores_list(game: &Game) {
         let document = web_sys::window().unwrap().document().unwrap();
+        let filter = game.highscore_filter;
+
+        // Highlight the active filter
+        if let Ok(btns) = document.query_selector_all(".highscore-filter-btn") {
+            for i in 0..btns.length() {
+                if let Some(btn) = btns
+                    .get(i)
+                    .and_then(|n| n.dyn_into::<web_sys::Element>().ok())
+                {
+                    let value = btn.get_attribute("data-difficulty").unwrap_or_default();
+                    let class = if Difficulty::parse(&value) == filter {
+                        "highscore-filter-btn active"
+                    } else {
+                        "highscore-filter-btn"
+                    };
+                    let _ = btn.set_attribute("class", class);
+                }
+            }
+        }
 
         if let Some(list) = document.get_element_by_id("highscores-list") {
-            if highscores.is_empty() {
-                list.set_inner_html(
-                    r#"<div class="highscore-empty">No scores yet. Play to set a record!</div>"#,
-                );
+            if game.highscores.filtered(filter).next().is_none() {
+                let html = match filter {
+                    None => "No scores yet. Play to set a record!".to_string(),
+                    Some(d) => format!("No {} scores yet.", d.as_str()),
+                };
+                list.set_inner_html(&format!(r#"<div class="highscore-empty">{}</div>"#, html));
             } else {
                 let mut html = String::new();
-                for (i, entry) in highscores.entries.iter().enumerate() {
+                for (i, entry) in game.highscores.filtered(filter).enumerate() {
                     let rank = i + 1;
                     let date_str = format_date(entry.timestamp);
+                    let time_str = if entry.time_secs > 0 {
+                        format_run_time(entry.time_secs)
+                    } else {
+                        "--:--".to_string()
+                    };
+                    let difficulty = entry.difficulty.as_str();
+                    let version = if entry.game_version.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" (v{})", entry.game_version)
+                    };
                     html.push_str(&format!(
                         r#"<div class="highscore-entry">
                             <span class="highscore-rank">#{}</span>
                             <span class="highscore-score">{}</span>
+                            <span class="highscore-difficulty {}" title="{}{}">{}</span>
                             <span class="highscore-wave">Wave {}</span>
+                            <span class="highscore-time">{}</span>
                             <span class="highscore-date">{}</span>
                         </div>"#,
-                        rank, entry.score, entry.wave, date_str
+                        rank,
+                        entry.score,
+                        difficulty.to_lowercase(),
+                        difficulty,
+                        version,
+                        &difficulty[..1],
+                        entry.wave,
+                        time_str,
+                        date_str
                     ));
                 }
                 list.set_inner_html(&html);
@@ -556,10 +1332,86 @@ mod wasm_game {
         }
     }
 
+    /// Render lifetime stats to DOM
+    fn render_stats(stats: &Stats) {
+        let document = web_sys::window().unwrap().document().unwrap();
+
+        if let Some(list) = document.get_element_by_id("stats-list") {
+            let rows = [
+                ("Games Played", stats.games_played.to_string()),
+                ("Play Time", stats.format_playtime()),
+                ("Best Wave", stats.best_wave.to_string()),
+                ("Waves Cleared", stats.waves_cleared.to_string()),
+                ("Blocks Destroyed", stats.blocks_destroyed.to_string()),
+                ("Longest Combo", stats.longest_combo.to_string()),
+                ("Pickups Collected", stats.pickups_collected.to_string()),
+                ("Balls Lost", stats.balls_lost.to_string()),
+            ];
+            let html: String = rows
+                .iter()
+                .map(|(label, value)| {
+                    format!(
+                        r#"<div class="stat-entry">
+                            <span class="stat-label">{}</span>
+                            <span class="stat-value">{}</span>
+                        </div>"#,
+                        label, value
+                    )
+                })
+                .collect();
+            list.set_inner_html(&html);
+        }
+    }
+
+    /// Render achievements list to DOM
+    fn render_achievements(achievements: &Achievements) {
+        let document = web_sys::window().unwrap().document().unwrap();
+
+        if let Some(list) = document.get_element_by_id("achievements-list") {
+            let html: String = ACHIEVEMENTS
+                .iter()
+                .map(|def| {
+                    let class = if achievements.is_unlocked(def.id) {
+                        "achievement-entry unlocked"
+                    } else {
+                        "achievement-entry"
+                    };
+                    format!(
+                        r#"<div class="{}">
+                            <span class="achievement-name">{}</span>
+                            <span class="achievement-desc">{}</span>
+                        </div>"#,
+                        class, def.name, def.description
+                    )
+                })
+                .collect();
+            list.set_inner_html(&html);
+        }
+    }
+
+    /// Warn under the Continue button that the saved run couldn't be loaded
+    fn show_save_load_error(error: &SaveError) {
+        let document = web_sys::window().unwrap().document().unwrap();
+        if let Some(info) = document.get_element_by_id("continue-info") {
+            let message = if error.is_corrupt() {
+                "⚠ Saved game was corrupted and has been discarded"
+            } else if matches!(error, SaveError::Version { .. }) {
+                "⚠ Saved game is from another version and can't be continued"
+            } else {
+                "⚠ Saved game couldn't be read"
+            };
+            info.set_text_content(Some(message));
+            let _ = info.class_list().add_1("warning");
+        }
+    }
+
     /// Update main menu continue button state
     fn update_main_menu_continue(saved_game: &Option<GameState>) {
         let document = web_sys::window().unwrap().document().unwrap();
 
+        if let Some(info) = document.get_element_by_id("continue-info") {
+            let _ = info.class_list().remove_1("warning");
+        }
         if let Some(btn) = document.get_element_by_id("menu-continue-btn") {
             if let Some(save) = saved_game {
                 let _ = btn.remove_attribute("disabled");
@@ -638,15 +1490,21 @@ mod wasm_game {
         log::info!("Using adapter: {:?}", adapter.get_info().name);
 
         let mut render_state = SdfRenderState::new(surface, &adapter, width, height).await;
-        render_state.set_start_time(js_sys::Date::now());
+        render_state.set_start_time(game.borrow().clock.now_ms());
         game.borrow_mut().render_state = Some(render_state);
 
-        // Check for saved game
-        let saved_game = load_saved_game();
+        // Check for saved game, telling "no save" apart from a broken one
+        let (saved_game, save_error) = match load_saved_game(game.borrow().storage.as_ref()) {
+            Ok(saved_game) => (saved_game, None),
+            Err(e) => (None, Some(e)),
+        };
 
         // Update main menu state
         update_main_menu_continue(&saved_game);
-        render_highscores_list(&game.borrow().highscores);
+        if let Some(e) = &save_error {
+            show_save_load_error(e);
+        }
+        render_highscores_list(&game.borrow());
 
         // Set up input handlers
         setup_input_handlers(&canvas, game.clone());
@@ -804,8 +1662,28 @@ mod wasm_game {
             let canvas_clone = canvas.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |event: TouchEvent| {
                 event.prevent_default();
+                let mut g = game.borrow_mut();
+
+                // Relative mode: rotate by the steering finger's horizontal drag
+                if g.settings.touch_relative {
+                    if let Some((id, last_x)) = g.steer_touch {
+                        let changed = event.changed_touches();
+                        for i in 0..changed.length() {
+                            if let Some(touch) = changed.get(i) {
+                                if touch.identifier() == id {
+                                    let x = touch.client_x() as f32;
+                                    let delta = -(x - last_x) * TOUCH_DRAG_SENSITIVITY;
+                                    let current = g.state.paddle.theta;
+                                    g.input.target_theta = Some(current + delta);
+                                    g.steer_touch = Some((id, x));
+                                }
+                            }
+                        }
+                    }
+                    return;
+                }
+
                 if let Some(touch) = event.touches().get(0) {
-                    let mut g = game.borrow_mut();
                     let w = canvas_clone.client_width() as f32;
                     let h = canvas_clone.client_height() as f32;
                     g.set_canvas_center(w, h);
@@ -828,9 +1706,27 @@ mod wasm_game {
             let closure = Closure::<dyn FnMut(_)>::new(move |event: TouchEvent| {
                 event.prevent_default();
                 let mut g = game.borrow_mut();
-                g.input.launch = true;
                 // Resume audio context on user gesture
                 g.audio.resume();
+
+                // Relative mode: first finger steers, any other finger launches
+                if g.settings.touch_relative {
+                    let changed = event.changed_touches();
+                    for i in 0..changed.length() {
+                        if let Some(touch) = changed.get(i) {
+                            if g.steer_touch.is_none() {
+                                g.steer_touch = Some((touch.identifier(), touch.client_x() as f32));
+                            } else {
+                                g.input.launch = true;
+                            }
+                        }
+                    }
+                    return;
+                }
+
+                // Absolute mode: every touch launches, and the first finger
+                // aims the paddle at where it touches
+                g.input.launch = true;
                 if let Some(touch) = event.touches().get(0) {
                     let w = canvas_clone.client_width() as f32;
                     let h = canvas_clone.client_height() as f32;
@@ -852,9 +1748,10 @@ mod wasm_game {
             let game = game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |event: TouchEvent| {
                 event.prevent_default();
+                let mut g = game.borrow_mut();
+                release_steer_touch(&mut g, &event);
                 // Only clear if no touches remain
                 if event.touches().length() == 0 {
-                    let mut g = game.borrow_mut();
                     g.input.target_theta = None;
                 }
             });
@@ -869,6 +1766,7 @@ mod wasm_game {
             let closure = Closure::<dyn FnMut(_)>::new(move |event: TouchEvent| {
                 event.prevent_default();
                 let mut g = game.borrow_mut();
+                release_steer_touch(&mut g, &event);
                 g.input.target_theta = None;
             });
             let _ = canvas
@@ -876,36 +1774,84 @@ mod wasm_game {
             closure.forget();
         }
 
-        // Keyboard
+        // On-screen launch button (relative touch mode)
+        if let Some(btn) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.get_element_by_id("touch-launch-btn"))
         {
             let game = game.clone();
-            let window = web_sys::window().unwrap();
-            let closure = Closure::<dyn FnMut(_)>::new(move |event: web_sys::KeyboardEvent| {
+            let closure = Closure::<dyn FnMut(_)>::new(move |event: TouchEvent| {
+                event.prevent_default();
                 let mut g = game.borrow_mut();
-                match event.key().as_str() {
-                    " " | "Enter" => g.input.launch = true,
-                    "Escape" => g.input.pause = true,
-                    "ArrowLeft" | "a" | "A" => g.key_left = true,
-                    "ArrowRight" | "d" | "D" => g.key_right = true,
-                    "+" | "=" => g.input.skip_wave = true, // Debug: skip to next wave
-                    "i" | "I" => {
-                        g.input.idle_mode = !g.input.idle_mode;
-                        log::info!("Idle mode: {}", g.input.idle_mode);
-                    }
-                    "m" | "M" => {
-                        // Toggle mute
-                        let muted = g.settings.master_volume > 0.0;
-                        if muted {
-                            g.audio.set_master_volume(0.0);
-                            g.settings.master_volume = 0.0;
-                            log::info!("Sound: OFF");
-                        } else {
-                            g.audio.set_master_volume(0.8);
-                            g.settings.master_volume = 0.8;
-                            log::info!("Sound: ON");
+                g.input.launch = true;
+                g.audio.resume();
+            });
+            let _ = btn
+                .add_event_listener_with_callback("touchstart", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Keyboard
+        {
+            let game = game.clone();
+            let window = web_sys::window().unwrap();
+            let closure = Closure::<dyn FnMut(_)>::new(move |event: web_sys::KeyboardEvent| {
+                let mut g = game.borrow_mut();
+                let key = event.key();
+
+                // Capture the key for the rebinding UI instead of playing
+                if let Some(action) = g.rebinding.take() {
+                    event.prevent_default();
+                    let status = if key == "Escape" {
+                        String::new()
+                    } else {
+                        match g.settings.key_bindings.bind(action, &key) {
+                            Ok(()) => {
+                                g.settings.save();
+                                String::new()
+                            }
+                            Err(other) => format!(
+                                "{} is already bound to {}",
+                                roto_pong::settings::key_display_name(&key),
+                                other.label()
+                            ),
                         }
+                    };
+                    sync_keybindings_ui(&g.settings, None);
+                    set_keybind_status(&status);
+                    return;
+                }
+
+                match g.settings.key_bindings.action_for(&key) {
+                    Some(KeyAction::Launch) => g.input.launch = true,
+                    Some(KeyAction::Catch) => g.input.catch = true,
+                    // Pausing would desync a replay, so Escape leaves it instead
+                    Some(KeyAction::Pause) if g.watching_replay => {
+                        g.stop_replay();
+                        show_main_menu();
                     }
-                    _ => {}
+                    Some(KeyAction::Pause) => g.input.pause = true,
+                    Some(KeyAction::Left) => g.key_left = true,
+                    Some(KeyAction::Right) => g.key_right = true,
+                    Some(KeyAction::SkipWave) => g.input.skip_wave = true, // Debug: skip to next wave
+                    Some(KeyAction::StepTick) => {
+                        // Debug: single-step the paused sim (Shift steps a burst)
+                        let n = if event.shift_key() {
+                            DEBUG_STEP_BURST
+                        } else {
+                            1
+                        };
+                        g.step_ticks(n);
+                    }
+                    Some(KeyAction::IdleMode) => {
+                        g.input.idle_mode = !g.input.idle_mode;
+                        log::info!("Idle mode: {}", g.input.idle_mode);
+                    }
+                    Some(KeyAction::Mute) => {
+                        let muted = !g.settings.muted;
+                        g.set_muted(muted);
+                    }
+                    None => {}
                 }
             });
             let _ = window
@@ -919,9 +1865,9 @@ mod wasm_game {
             let window = web_sys::window().unwrap();
             let closure = Closure::<dyn FnMut(_)>::new(move |event: web_sys::KeyboardEvent| {
                 let mut g = game.borrow_mut();
-                match event.key().as_str() {
-                    "ArrowLeft" | "a" | "A" => g.key_left = false,
-                    "ArrowRight" | "d" | "D" => g.key_right = false,
+                match g.settings.key_bindings.action_for(&event.key()) {
+                    Some(KeyAction::Left) => g.key_left = false,
+                    Some(KeyAction::Right) => g.key_right = false,
                     _ => {}
                 }
             });
@@ -931,26 +1877,37 @@ mod wasm_game {
         }
     }
 
+    /// Stop relative steering if the steering finger lifted
+    fn release_steer_touch(g: &mut Game, event: &TouchEvent) {
+        if let Some((id, _)) = g.steer_touch {
+            let changed = event.changed_touches();
+            for i in 0..changed.length() {
+                if changed.get(i).is_some_and(|t| t.identifier() == id) {
+                    g.steer_touch = None;
+                }
+            }
+        }
+    }
+
     fn request_animation_frame(game: Rc<RefCell<Game>>) {
         let window = web_sys::window().unwrap();
-        let closure = Closure::once(move |time: f64| {
-            game_loop(game, time);
+        let closure = Closure::once(move |_timestamp: f64| {
+            game_loop(game);
         });
         let _ = window.request_animation_frame(closure.as_ref().unchecked_ref());
         closure.forget();
     }
 
-    fn game_loop(game: Rc<RefCell<Game>>, time: f64) {
+    fn game_loop(game: Rc<RefCell<Game>>) {
         {
             let mut g = game.borrow_mut();
 
             // Calculate delta time
-            let dt = if g.last_time > 0.0 {
-                ((time - g.last_time) / 1000.0) as f32
-            } else {
-                SIM_DT
-            };
-            g.last_time = time;
+            let time = g.clock.now_ms();
+            let dt = g
+                .last_frame_ms
+                .map_or(SIM_DT, |last| ((time - last) / 1000.0) as f32);
+            g.last_frame_ms = Some(time);
 
             g.update(dt, time);
             g.render(time);
@@ -965,22 +1922,47 @@ mod wasm_game {
         let document = window.document().unwrap();
 
         if let Some(btn) = document.get_element_by_id("restart-btn") {
+            let game = game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
-                let seed = js_sys::Date::now() as u64;
-                let mut g = game.borrow_mut();
-                g.restart(seed);
-
-                // Regenerate initial wave
-                roto_pong::sim::generate_wave(&mut g.state);
+                let seed = game.borrow_mut().new_game(None);
 
                 // Clear any saved game
-                clear_saved_game();
+                clear_saved_game(game.borrow().storage.as_ref());
 
                 log::info!("Game restarted with seed: {}", seed);
             });
             let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
             closure.forget();
         }
+
+        // Save the finished run as a replay file
+        if let Some(btn) = document.get_element_by_id("replay-save-btn") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let g = game.borrow();
+                let Some(recording) = &g.last_replay else {
+                    return;
+                };
+                match roto_pong::persistence::encode_replay(recording) {
+                    Ok(text) => {
+                        download_text(&format!("roto-pong-{}.replay", recording.seed), &text);
+                        log::info!("Replay saved ({} bytes)", text.len());
+                    }
+                    Err(e) => log::warn!("Replay not saved: {}", e),
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Second chance: one revive per run
+        if let Some(btn) = document.get_element_by_id("revive-btn") {
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                game.borrow_mut().revive();
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
     }
 
     fn setup_pause_menu(game: Rc<RefCell<Game>>) {
@@ -1016,6 +1998,30 @@ mod wasm_game {
             closure.forget();
         }
 
+        // Quit to Menu: ask before throwing the run away
+        if let Some(btn) = document.get_element_by_id("abandon-btn") {
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                if let Some(el) = document.get_element_by_id("abandon-modal") {
+                    let _ = el.set_attribute("class", "");
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Abandon confirmation: back to the pause menu
+        if let Some(btn) = document.get_element_by_id("abandon-cancel-btn") {
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                if let Some(el) = document.get_element_by_id("abandon-modal") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
         // Save & Quit button
         if let Some(btn) = document.get_element_by_id("save-quit-btn") {
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
@@ -1031,21 +2037,80 @@ mod wasm_game {
         }
     }
 
+    /// Highlight the quality preset the settings match (none when custom)
+    fn sync_quality_buttons(settings: &Settings) {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let current = settings.detect_preset();
+        for preset in roto_pong::settings::QualityPreset::NAMED {
+            let selector = format!(
+                ".quality-btn[data-quality='{}']",
+                preset.as_str().to_lowercase()
+            );
+            if let Ok(Some(btn)) = document.query_selector(&selector) {
+                if preset == current {
+                    let _ = btn.set_attribute("class", "quality-btn active");
+                } else {
+                    let _ = btn.set_attribute("class", "quality-btn");
+                }
+            }
+        }
+    }
+
+    /// Highlight the difficulty the next run will use
+    fn sync_difficulty_buttons(settings: &Settings) {
+        let document = web_sys::window().unwrap().document().unwrap();
+        for difficulty in Difficulty::ALL {
+            let selector = format!(
+                ".difficulty-btn[data-difficulty='{}']",
+                difficulty.as_str().to_lowercase()
+            );
+            if let Ok(Some(btn)) = document.query_selector(&selector) {
+                if difficulty == settings.difficulty {
+                    let _ = btn.set_attribute("class", "difficulty-btn active");
+                } else {
+                    let _ = btn.set_attribute("class", "difficulty-btn");
+                }
+            }
+        }
+    }
+
     /// Sync settings UI toggles/buttons with current settings
     fn sync_settings_ui(settings: &Settings) {
         let document = web_sys::window().unwrap().document().unwrap();
 
-        // Quality preset buttons
-        let qualities = ["low", "medium", "high"];
-        let current_quality = settings.quality.as_str().to_lowercase();
-        for q in qualities {
+        sync_quality_buttons(settings);
+        sync_difficulty_buttons(settings);
+
+        // Sound panning buttons
+        for mode in PanMode::ALL {
+            let name = mode.as_str().to_lowercase();
             if let Ok(Some(btn)) =
-                document.query_selector(&format!(".quality-btn[data-quality='{}']", q))
+                document.query_selector(&format!(".pan-btn[data-pan='{}']", name))
             {
-                if q == current_quality {
-                    let _ = btn.set_attribute("class", "quality-btn active");
+                if mode == settings.pan_mode {
+                    let _ = btn.set_attribute("class", "color-btn pan-btn active");
                 } else {
-                    let _ = btn.set_attribute("class", "quality-btn");
+                    let _ = btn.set_attribute("class", "color-btn pan-btn");
+                }
+            }
+        }
+
+        // Paddle/ball color buttons
+        for (target, choice) in [
+            ("paddle", settings.paddle_color),
+            ("ball", settings.ball_color),
+        ] {
+            for c in ColorChoice::ALL {
+                let name = c.as_str().to_lowercase();
+                if let Ok(Some(btn)) = document.query_selector(&format!(
+                    ".color-btn[data-target='{}'][data-color='{}']",
+                    target, name
+                )) {
+                    if c == choice {
+                        let _ = btn.set_attribute("class", "color-btn active");
+                    } else {
+                        let _ = btn.set_attribute("class", "color-btn");
+                    }
                 }
             }
         }
@@ -1057,10 +2122,25 @@ mod wasm_game {
             ("particles", settings.particles),
             ("wave_flash", settings.wave_flash),
             ("powerup_effects", settings.powerup_effects),
+            ("background_grid", settings.background_grid),
+            ("hp_pips", settings.hp_pips),
+            ("bullet_time", settings.bullet_time),
+            ("hit_stop", settings.hit_stop),
+            ("relaxed_mode", settings.relaxed_mode),
             ("show_fps", settings.show_fps),
+            ("show_timer", settings.show_timer),
+            ("lives_icons", settings.lives_icons),
+            ("low_lives_warning", settings.low_lives_warning),
             ("reduced_motion", settings.reduced_motion),
             ("high_contrast", settings.high_contrast),
+            ("path_preview", settings.path_preview),
+            ("muted", settings.muted),
             ("mute_on_blur", settings.mute_on_blur),
+            ("smooth_audio", settings.smooth_audio),
+            ("touch_relative", settings.touch_relative),
+            ("pause_on_blur", settings.pause_on_blur),
+            ("pause_on_hide", settings.pause_on_hide),
+            ("auto_zoom", settings.zoom_mode == ZoomMode::Auto),
         ];
         for (name, value) in toggles {
             if let Ok(Some(toggle)) =
@@ -1092,6 +2172,57 @@ mod wasm_game {
         if let Some(el) = document.get_element_by_id("sfx-volume-value") {
             el.set_text_content(Some(&format!("{}%", (settings.sfx_volume * 100.0) as u32)));
         }
+        if let Some(slider) = document.get_element_by_id("music-volume") {
+            let input: web_sys::HtmlInputElement = slider.dyn_into().unwrap();
+            input.set_value(&format!("{}", (settings.music_volume * 100.0) as u32));
+        }
+        if let Some(el) = document.get_element_by_id("music-volume-value") {
+            el.set_text_content(Some(&format!(
+                "{}%",
+                (settings.music_volume * 100.0) as u32
+            )));
+        }
+
+        // Trail length / particle density sliders
+        if let Some(slider) = document.get_element_by_id("trail-length") {
+            let input: web_sys::HtmlInputElement = slider.dyn_into().unwrap();
+            input.set_value(&format!("{}", (settings.trail_length * 100.0) as u32));
+        }
+        if let Some(el) = document.get_element_by_id("trail-length-value") {
+            el.set_text_content(Some(&format!(
+                "{}%",
+                (settings.trail_length * 100.0) as u32
+            )));
+        }
+        if let Some(slider) = document.get_element_by_id("particle-density") {
+            let input: web_sys::HtmlInputElement = slider.dyn_into().unwrap();
+            input.set_value(&format!("{}", (settings.particle_density * 100.0) as u32));
+        }
+        if let Some(el) = document.get_element_by_id("particle-density-value") {
+            el.set_text_content(Some(&format!(
+                "{}%",
+                (settings.particle_density * 100.0) as u32
+            )));
+        }
+
+        // Zoom level slider (only shown in fixed zoom mode)
+        let fixed_zoom = match settings.zoom_mode {
+            ZoomMode::Auto => None,
+            ZoomMode::Fixed(zoom) => Some(zoom),
+        };
+        if let Some(row) = document.get_element_by_id("zoom-level-row") {
+            let display = if fixed_zoom.is_some() { "" } else { "none" };
+            let _ = row.set_attribute("style", &format!("display: {}", display));
+        }
+        if let Some(zoom) = fixed_zoom {
+            if let Some(slider) = document.get_element_by_id("zoom-level") {
+                let input: web_sys::HtmlInputElement = slider.dyn_into().unwrap();
+                input.set_value(&format!("{}", (zoom * 100.0) as u32));
+            }
+            if let Some(el) = document.get_element_by_id("zoom-level-value") {
+                el.set_text_content(Some(&format!("{:.2}x", zoom)));
+            }
+        }
 
         // Keyboard sensitivity slider
         if let Some(slider) = document.get_element_by_id("keyboard-sensitivity") {
@@ -1101,6 +2232,53 @@ mod wasm_game {
         if let Some(el) = document.get_element_by_id("keyboard-sensitivity-value") {
             el.set_text_content(Some(&format!("{:.1}", settings.keyboard_sensitivity)));
         }
+
+        // Paddle lead (latency compensation) slider, shown as a percentage
+        if let Some(slider) = document.get_element_by_id("paddle-lead") {
+            let input: web_sys::HtmlInputElement = slider.dyn_into().unwrap();
+            input.set_value(&format!("{}", (settings.paddle_lead * 100.0).round()));
+        }
+        if let Some(el) = document.get_element_by_id("paddle-lead-value") {
+            el.set_text_content(Some(&paddle_lead_label(settings.paddle_lead)));
+        }
+
+        sync_keybindings_ui(settings, None);
+    }
+
+    /// Paddle lead slider readout ("Off" at zero)
+    fn paddle_lead_label(lead: f32) -> String {
+        if lead <= 0.0 {
+            "Off".to_string()
+        } else {
+            format!("{:.0}%", lead * 100.0)
+        }
+    }
+
+    /// Update key binding buttons (`waiting` shows a "press a key" prompt)
+    fn sync_keybindings_ui(settings: &Settings, waiting: Option<KeyAction>) {
+        let document = web_sys::window().unwrap().document().unwrap();
+
+        for action in KeyAction::ALL {
+            if let Ok(Some(btn)) =
+                document.query_selector(&format!(".keybind-btn[data-action='{}']", action.as_str()))
+            {
+                if waiting == Some(action) {
+                    btn.set_text_content(Some("Press a key…"));
+                    let _ = btn.set_attribute("class", "keybind-btn active");
+                } else {
+                    btn.set_text_content(Some(&settings.key_bindings.display(action)));
+                    let _ = btn.set_attribute("class", "keybind-btn");
+                }
+            }
+        }
+    }
+
+    /// Show a message under the key bindings (empty clears it)
+    fn set_keybind_status(message: &str) {
+        let document = web_sys::window().unwrap().document().unwrap();
+        if let Some(el) = document.get_element_by_id("keybind-status") {
+            el.set_text_content(Some(message));
+        }
     }
 
     fn setup_settings_modal(game: Rc<RefCell<Game>>) {
@@ -1140,8 +2318,11 @@ mod wasm_game {
         if let Some(btn) = document.get_element_by_id("settings-reset-btn") {
             let game = game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
-                game.borrow_mut().settings = Settings::default();
-                sync_settings_ui(&game.borrow().settings);
+                let mut g = game.borrow_mut();
+                g.settings = Settings::default();
+                let smooth_audio = g.settings.smooth_audio;
+                g.audio.set_smoothing(smooth_audio);
+                sync_settings_ui(&g.settings);
                 log::info!("Settings reset to defaults");
             });
             let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
@@ -1180,6 +2361,74 @@ mod wasm_game {
             }
         }
 
+        // Sound panning buttons
+        if let Ok(btns) = document.query_selector_all(".pan-btn") {
+            for i in 0..btns.length() {
+                if let Some(btn) = btns.get(i) {
+                    let game = game.clone();
+                    let closure =
+                        Closure::<dyn FnMut(_)>::new(move |event: web_sys::MouseEvent| {
+                            if let Some(target) = event.target() {
+                                let el: web_sys::Element = target.dyn_into().unwrap();
+                                if let Some(mode) = el
+                                    .get_attribute("data-pan")
+                                    .and_then(|s| PanMode::parse(&s))
+                                {
+                                    let mut g = game.borrow_mut();
+                                    g.settings.pan_mode = mode;
+                                    g.settings.save();
+                                    drop(g);
+                                    sync_settings_ui(&game.borrow().settings);
+                                    log::info!("Sound panning set to: {:?}", mode);
+                                }
+                            }
+                        });
+                    let _ = btn.add_event_listener_with_callback(
+                        "click",
+                        closure.as_ref().unchecked_ref(),
+                    );
+                    closure.forget();
+                }
+            }
+        }
+
+        // Paddle/ball color buttons
+        if let Ok(btns) = document.query_selector_all(".color-btn[data-color]") {
+            for i in 0..btns.length() {
+                if let Some(btn) = btns.get(i) {
+                    let game = game.clone();
+                    let closure =
+                        Closure::<dyn FnMut(_)>::new(move |event: web_sys::MouseEvent| {
+                            if let Some(target) = event.target() {
+                                let el: web_sys::Element = target.dyn_into().unwrap();
+                                let color = el
+                                    .get_attribute("data-color")
+                                    .and_then(|s| ColorChoice::parse(&s));
+                                if let (Some(which), Some(color)) =
+                                    (el.get_attribute("data-target"), color)
+                                {
+                                    let mut g = game.borrow_mut();
+                                    match which.as_str() {
+                                        "paddle" => g.settings.paddle_color = color,
+                                        "ball" => g.settings.ball_color = color,
+                                        _ => return,
+                                    }
+                                    g.settings.save();
+                                    drop(g);
+                                    sync_settings_ui(&game.borrow().settings);
+                                    log::info!("{} color set to: {:?}", which, color);
+                                }
+                            }
+                        });
+                    let _ = btn.add_event_listener_with_callback(
+                        "click",
+                        closure.as_ref().unchecked_ref(),
+                    );
+                    closure.forget();
+                }
+            }
+        }
+
         // Toggle switches
         if let Ok(toggles) = document.query_selector_all(".toggle") {
             for i in 0..toggles.length() {
@@ -1210,13 +2459,48 @@ mod wasm_game {
                                         "particles" => g.settings.particles = new_value,
                                         "wave_flash" => g.settings.wave_flash = new_value,
                                         "powerup_effects" => g.settings.powerup_effects = new_value,
+                                        "background_grid" => g.settings.background_grid = new_value,
+                                        "hp_pips" => g.settings.hp_pips = new_value,
+                                        "bullet_time" => g.settings.bullet_time = new_value,
+                                        "hit_stop" => g.settings.hit_stop = new_value,
+                                        "relaxed_mode" => g.settings.relaxed_mode = new_value,
                                         "show_fps" => g.settings.show_fps = new_value,
+                                        "show_timer" => g.settings.show_timer = new_value,
+                                        "lives_icons" => g.settings.lives_icons = new_value,
+                                        "low_lives_warning" => {
+                                            g.settings.low_lives_warning = new_value
+                                        }
                                         "reduced_motion" => g.settings.reduced_motion = new_value,
                                         "high_contrast" => g.settings.high_contrast = new_value,
+                                        "path_preview" => g.settings.path_preview = new_value,
+                                        "muted" => {
+                                            g.settings.muted = new_value;
+                                            g.audio.set_muted(new_value);
+                                        }
                                         "mute_on_blur" => g.settings.mute_on_blur = new_value,
+                                        "pause_on_blur" => g.settings.pause_on_blur = new_value,
+                                        "pause_on_hide" => g.settings.pause_on_hide = new_value,
+                                        "smooth_audio" => {
+                                            g.settings.smooth_audio = new_value;
+                                            g.audio.set_smoothing(new_value);
+                                        }
+                                        "touch_relative" => {
+                                            g.settings.touch_relative = new_value;
+                                            g.steer_touch = None;
+                                        }
+                                        "auto_zoom" => {
+                                            g.settings.zoom_mode = if new_value {
+                                                ZoomMode::Auto
+                                            } else {
+                                                ZoomMode::Fixed(ZoomMode::MIN_ZOOM)
+                                            };
+                                            // Show/hide the zoom level slider
+                                            sync_settings_ui(&g.settings);
+                                        }
                                         _ => {}
                                     }
                                     g.settings.save();
+                                    sync_quality_buttons(&g.settings);
 
                                     // Update toggle visual
                                     if new_value {
@@ -1238,10 +2522,17 @@ mod wasm_game {
             }
         }
 
-        // Volume sliders
+        // Percentage sliders (volume, trail length, particle density)
         for (slider_id, value_id, setting_name) in [
             ("master-volume", "master-volume-value", "master_volume"),
             ("sfx-volume", "sfx-volume-value", "sfx_volume"),
+            ("music-volume", "music-volume-value", "music_volume"),
+            ("trail-length", "trail-length-value", "trail_length"),
+            (
+                "particle-density",
+                "particle-density-value",
+                "particle_density",
+            ),
         ] {
             if let Some(slider) = document.get_element_by_id(slider_id) {
                 let game = game.clone();
@@ -1263,9 +2554,16 @@ mod wasm_game {
                                 g.settings.sfx_volume = normalized;
                                 g.audio.set_sfx_volume(normalized);
                             }
+                            "music_volume" => {
+                                g.settings.music_volume = normalized;
+                                g.audio.set_music_volume(normalized);
+                            }
+                            "trail_length" => g.settings.trail_length = normalized,
+                            "particle_density" => g.settings.particle_density = normalized,
                             _ => {}
                         }
                         g.settings.save();
+                        sync_quality_buttons(&g.settings);
 
                         // Update value display
                         let document = web_sys::window().unwrap().document().unwrap();
@@ -1280,22 +2578,23 @@ mod wasm_game {
             }
         }
 
-        // Keyboard sensitivity slider
-        if let Some(slider) = document.get_element_by_id("keyboard-sensitivity") {
+        // Fixed zoom level slider
+        if let Some(slider) = document.get_element_by_id("zoom-level") {
             let game = game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |event: web_sys::Event| {
                 if let Some(target) = event.target() {
                     let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
-                    let value: f32 = input.value().parse().unwrap_or(6.0);
+                    let value: f32 = input.value().parse().unwrap_or(100.0);
+                    let zoom = (value / 100.0).clamp(ZoomMode::MIN_ZOOM, ZoomMode::MAX_ZOOM);
 
                     let mut g = game.borrow_mut();
-                    g.settings.keyboard_sensitivity = value;
+                    g.settings.zoom_mode = ZoomMode::Fixed(zoom);
                     g.settings.save();
 
                     // Update value display
                     let document = web_sys::window().unwrap().document().unwrap();
-                    if let Some(el) = document.get_element_by_id("keyboard-sensitivity-value") {
-                        el.set_text_content(Some(&format!("{:.1}", value)));
+                    if let Some(el) = document.get_element_by_id("zoom-level-value") {
+                        el.set_text_content(Some(&format!("{:.2}x", zoom)));
                     }
                 }
             });
@@ -1303,60 +2602,156 @@ mod wasm_game {
                 .add_event_listener_with_callback("input", closure.as_ref().unchecked_ref());
             closure.forget();
         }
-    }
-
-    fn setup_main_menu(game: Rc<RefCell<Game>>, saved_game: Option<GameState>) {
-        let window = web_sys::window().unwrap();
-        let document = window.document().unwrap();
-
-        // Helper to start game (hide menu, show HUD)
-        fn start_game() {
-            let document = web_sys::window().unwrap().document().unwrap();
-            if let Some(el) = document.get_element_by_id("main-menu") {
-                let _ = el.set_attribute("class", "hidden");
-            }
-            if let Some(el) = document.get_element_by_id("hud") {
-                let _ = el.set_attribute("class", "");
-            }
-        }
 
-        // Helper to show main menu
-        fn show_main_menu() {
-            let document = web_sys::window().unwrap().document().unwrap();
-            if let Some(el) = document.get_element_by_id("main-menu") {
-                let _ = el.set_attribute("class", "");
-            }
-            if let Some(el) = document.get_element_by_id("hud") {
-                let _ = el.set_attribute("class", "hidden");
-            }
-            if let Some(el) = document.get_element_by_id("game-over") {
-                let _ = el.set_attribute("class", "hidden");
+        // Key binding buttons - click, then press the new key
+        if let Ok(btns) = document.query_selector_all(".keybind-btn") {
+            for i in 0..btns.length() {
+                if let Some(btn) = btns.get(i) {
+                    let game = game.clone();
+                    let closure =
+                        Closure::<dyn FnMut(_)>::new(move |event: web_sys::MouseEvent| {
+                            if let Some(target) = event.target() {
+                                let el: web_sys::Element = target.dyn_into().unwrap();
+                                if let Some(action) = el
+                                    .get_attribute("data-action")
+                                    .and_then(|s| KeyAction::parse(&s))
+                                {
+                                    let mut g = game.borrow_mut();
+                                    g.rebinding = Some(action);
+                                    sync_keybindings_ui(&g.settings, Some(action));
+                                    set_keybind_status("Press Esc to cancel");
+                                }
+                            }
+                        });
+                    let _ = btn.add_event_listener_with_callback(
+                        "click",
+                        closure.as_ref().unchecked_ref(),
+                    );
+                    closure.forget();
+                }
             }
         }
 
-        // Continue button
-        if let Some(btn) = document.get_element_by_id("menu-continue-btn") {
+        // Reset key bindings
+        if let Some(btn) = document.get_element_by_id("keybind-reset-btn") {
             let game = game.clone();
-            let saved = saved_game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
-                if let Some(ref state) = saved {
-                    game.borrow_mut().load_state(state.clone());
-                    log::info!("Loaded saved game at wave {}", state.wave_index + 1);
-                    start_game();
-                }
+                let mut g = game.borrow_mut();
+                g.rebinding = None;
+                g.settings.key_bindings = Default::default();
+                g.settings.save();
+                sync_keybindings_ui(&g.settings, None);
+                set_keybind_status("");
             });
             let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
             closure.forget();
         }
 
-        // New Game button
-        if let Some(btn) = document.get_element_by_id("menu-newgame-btn") {
-            let game = game.clone();
+        // Keyboard sensitivity slider
+        if let Some(slider) = document.get_element_by_id("keyboard-sensitivity") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |event: web_sys::Event| {
+                if let Some(target) = event.target() {
+                    let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
+                    let value: f32 = input.value().parse().unwrap_or(6.0);
+
+                    let mut g = game.borrow_mut();
+                    g.settings.keyboard_sensitivity = value;
+                    g.settings.save();
+                    // Applies to the run in progress; its replay would no
+                    // longer match, so stop recording it
+                    if g.state.tuning.keyboard_speed != value {
+                        g.state.tuning.keyboard_speed = value;
+                        g.recorder = None;
+                    }
+
+                    // Update value display
+                    let document = web_sys::window().unwrap().document().unwrap();
+                    if let Some(el) = document.get_element_by_id("keyboard-sensitivity-value") {
+                        el.set_text_content(Some(&format!("{:.1}", value)));
+                    }
+                }
+            });
+            let _ = slider
+                .add_event_listener_with_callback("input", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Paddle lead slider (percent in the UI, 0-1 in settings)
+        if let Some(slider) = document.get_element_by_id("paddle-lead") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |event: web_sys::Event| {
+                if let Some(target) = event.target() {
+                    let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
+                    let percent: f32 = input.value().parse().unwrap_or(0.0);
+                    let lead = (percent / 100.0).clamp(0.0, 1.0);
+
+                    let mut g = game.borrow_mut();
+                    g.settings.paddle_lead = lead;
+                    g.settings.save();
+
+                    let document = web_sys::window().unwrap().document().unwrap();
+                    if let Some(el) = document.get_element_by_id("paddle-lead-value") {
+                        el.set_text_content(Some(&paddle_lead_label(lead)));
+                    }
+                }
+            });
+            let _ =
+                slider.add_event_listener_with_callback("input", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+    }
+
+    /// Show the main menu (hides the HUD and game over overlay)
+    fn show_main_menu() {
+        let document = web_sys::window().unwrap().document().unwrap();
+        if let Some(el) = document.get_element_by_id("main-menu") {
+            let _ = el.set_attribute("class", "");
+        }
+        if let Some(el) = document.get_element_by_id("hud") {
+            let _ = el.set_attribute("class", "hidden");
+        }
+        if let Some(el) = document.get_element_by_id("game-over") {
+            let _ = el.set_attribute("class", "hidden");
+        }
+    }
+
+    fn setup_main_menu(game: Rc<RefCell<Game>>, saved_game: Option<GameState>) {
+        let window = web_sys::window().unwrap();
+        let document = window.document().unwrap();
+
+        // Helper to start game (hide menu, show HUD)
+        fn start_game() {
+            let document = web_sys::window().unwrap().document().unwrap();
+            if let Some(el) = document.get_element_by_id("main-menu") {
+                let _ = el.set_attribute("class", "hidden");
+            }
+            if let Some(el) = document.get_element_by_id("hud") {
+                let _ = el.set_attribute("class", "");
+            }
+        }
+
+        // Continue button
+        if let Some(btn) = document.get_element_by_id("menu-continue-btn") {
+            let game = game.clone();
+            let saved = saved_game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
-                clear_saved_game();
-                let seed = js_sys::Date::now() as u64;
-                game.borrow_mut().restart(seed);
-                roto_pong::sim::generate_wave(&mut game.borrow_mut().state);
+                if let Some(ref state) = saved {
+                    game.borrow_mut().load_state(state.clone());
+                    log::info!("Loaded saved game at wave {}", state.wave_index + 1);
+                    start_game();
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // New Game button
+        if let Some(btn) = document.get_element_by_id("menu-newgame-btn") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                clear_saved_game(game.borrow().storage.as_ref());
+                let seed = game.borrow_mut().new_game(None);
                 start_game();
                 log::info!("Started new game with seed: {}", seed);
             });
@@ -1364,13 +2759,165 @@ mod wasm_game {
             closure.forget();
         }
 
+        // Difficulty buttons: pick the difficulty for the next new run
+        if let Ok(btns) = document.query_selector_all(".difficulty-btn") {
+            for i in 0..btns.length() {
+                if let Some(btn) = btns.get(i) {
+                    let game = game.clone();
+                    let closure =
+                        Closure::<dyn FnMut(_)>::new(move |event: web_sys::MouseEvent| {
+                            if let Some(target) = event.target() {
+                                let el: web_sys::Element = target.dyn_into().unwrap();
+                                if let Some(difficulty) = el
+                                    .get_attribute("data-difficulty")
+                                    .and_then(|s| Difficulty::parse(&s))
+                                {
+                                    let mut g = game.borrow_mut();
+                                    g.settings.difficulty = difficulty;
+                                    g.settings.save();
+                                    sync_difficulty_buttons(&g.settings);
+                                    log::info!("Difficulty set to: {:?}", difficulty);
+                                }
+                            }
+                        });
+                    let _ = btn.add_event_listener_with_callback(
+                        "click",
+                        closure.as_ref().unchecked_ref(),
+                    );
+                    closure.forget();
+                }
+            }
+        }
+
+        // High score filter buttons: show one difficulty or all of them
+        if let Ok(btns) = document.query_selector_all(".highscore-filter-btn") {
+            for i in 0..btns.length() {
+                if let Some(btn) = btns.get(i) {
+                    let game = game.clone();
+                    let closure =
+                        Closure::<dyn FnMut(_)>::new(move |event: web_sys::MouseEvent| {
+                            if let Some(target) = event.target() {
+                                let el: web_sys::Element = target.dyn_into().unwrap();
+                                let value = el.get_attribute("data-difficulty").unwrap_or_default();
+                                game.borrow_mut().highscore_filter = Difficulty::parse(&value);
+                                render_highscores_list(&game.borrow());
+                            }
+                        });
+                    let _ = btn.add_event_listener_with_callback(
+                        "click",
+                        closure.as_ref().unchecked_ref(),
+                    );
+                    closure.forget();
+                }
+            }
+        }
+
+        // Seeded game button: open the seed entry modal
+        if let Some(btn) = document.get_element_by_id("menu-seed-btn") {
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                if let Some(el) = document.get_element_by_id("seed-error") {
+                    el.set_text_content(None);
+                }
+                if let Some(el) = document.get_element_by_id("main-menu") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+                if let Some(el) = document.get_element_by_id("seed-modal") {
+                    let _ = el.set_attribute("class", "");
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Seed modal: start a run from the entered seed
+        if let Some(btn) = document.get_element_by_id("seed-start-btn") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                let text = document
+                    .get_element_by_id("seed-input")
+                    .and_then(|el| el.dyn_into::<HtmlInputElement>().ok())
+                    .map(|input| input.value())
+                    .unwrap_or_default();
+                let Some(seed) = GameState::parse_seed(&text) else {
+                    if let Some(el) = document.get_element_by_id("seed-error") {
+                        el.set_text_content(Some("Enter a whole number"));
+                    }
+                    return;
+                };
+                if let Some(el) = document.get_element_by_id("seed-modal") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+                clear_saved_game(game.borrow().storage.as_ref());
+                game.borrow_mut().new_game(Some(seed));
+                start_game();
+                log::info!("Started new game from entered seed: {}", seed);
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Seed modal back button
+        if let Some(btn) = document.get_element_by_id("seed-back-btn") {
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                if let Some(el) = document.get_element_by_id("seed-modal") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+                if let Some(el) = document.get_element_by_id("main-menu") {
+                    let _ = el.set_attribute("class", "");
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Watch Replay: pick a replay file; it's verified before it plays
+        if let Some(btn) = document.get_element_by_id("menu-replay-btn") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let game = game.clone();
+                let on_load = Closure::once_into_js(move |text: String| {
+                    match roto_pong::persistence::decode_replay(&text) {
+                        Ok(recording) => {
+                            log::info!(
+                                "Watching replay of seed {} ({} ticks)",
+                                recording.seed,
+                                recording.ticks
+                            );
+                            game.borrow_mut().watch_replay(recording);
+                            start_game();
+                        }
+                        Err(e) => {
+                            log::warn!("Replay not loaded: {}", e);
+                            let message = match e {
+                                SaveError::IntegrityMismatch => {
+                                    "This replay doesn't match its recorded run: it was \
+                                     edited or made by another version of the game."
+                                        .to_string()
+                                }
+                                e => format!("This file isn't a readable replay ({}).", e),
+                            };
+                            if let Some(window) = web_sys::window() {
+                                let _ = window.alert_with_message(&message);
+                            }
+                        }
+                    }
+                });
+                pick_text_file(on_load.unchecked_ref());
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
         // High Scores button
         if let Some(btn) = document.get_element_by_id("menu-highscores-btn") {
             let game = game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
                 let document = web_sys::window().unwrap().document().unwrap();
                 // Update high scores display
-                render_highscores_list(&game.borrow().highscores);
+                render_highscores_list(&game.borrow());
                 // Hide main menu, show high scores
                 if let Some(el) = document.get_element_by_id("main-menu") {
                     let _ = el.set_attribute("class", "hidden");
@@ -1398,6 +2945,90 @@ mod wasm_game {
             closure.forget();
         }
 
+        // Stats button
+        if let Some(btn) = document.get_element_by_id("menu-stats-btn") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                render_stats(&game.borrow().stats);
+                if let Some(el) = document.get_element_by_id("main-menu") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+                if let Some(el) = document.get_element_by_id("stats-modal") {
+                    let _ = el.set_attribute("class", "");
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Stats reset button
+        if let Some(btn) = document.get_element_by_id("stats-reset-btn") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let window = web_sys::window().unwrap();
+                let confirmed = window
+                    .confirm_with_message("Reset all lifetime stats?")
+                    .unwrap_or(false);
+                if confirmed {
+                    let mut g = game.borrow_mut();
+                    g.stats.reset();
+                    g.stats.save();
+                    render_stats(&g.stats);
+                    log::info!("Stats reset");
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Stats back button
+        if let Some(btn) = document.get_element_by_id("stats-back-btn") {
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                if let Some(el) = document.get_element_by_id("stats-modal") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+                if let Some(el) = document.get_element_by_id("main-menu") {
+                    let _ = el.set_attribute("class", "");
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Achievements button
+        if let Some(btn) = document.get_element_by_id("menu-achievements-btn") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                render_achievements(&game.borrow().achievements);
+                if let Some(el) = document.get_element_by_id("main-menu") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+                if let Some(el) = document.get_element_by_id("achievements-modal") {
+                    let _ = el.set_attribute("class", "");
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
+        // Achievements back button
+        if let Some(btn) = document.get_element_by_id("achievements-back-btn") {
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                if let Some(el) = document.get_element_by_id("achievements-modal") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+                if let Some(el) = document.get_element_by_id("main-menu") {
+                    let _ = el.set_attribute("class", "");
+                }
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
         // How to Play button
         if let Some(btn) = document.get_element_by_id("menu-howtoplay-btn") {
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
@@ -1445,12 +3076,31 @@ mod wasm_game {
             closure.forget();
         }
 
+        // Abandon confirmed: discard the run and return to the main menu
+        if let Some(btn) = document.get_element_by_id("abandon-confirm-btn") {
+            let game = game.clone();
+            let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
+                let document = web_sys::window().unwrap().document().unwrap();
+                game.borrow_mut().abandon_run();
+                if let Some(el) = document.get_element_by_id("abandon-modal") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+                if let Some(el) = document.get_element_by_id("pause-menu") {
+                    let _ = el.set_attribute("class", "hidden");
+                }
+                update_main_menu_continue(&None);
+                show_main_menu();
+            });
+            let _ = btn.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+            closure.forget();
+        }
+
         // Game Over -> Main Menu button
         if let Some(btn) = document.get_element_by_id("gameover-menu-btn") {
             let game = game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::MouseEvent| {
                 // Update highscores display
-                render_highscores_list(&game.borrow().highscores);
+                render_highscores_list(&game.borrow());
                 // Update continue button state (no save after game over)
                 update_main_menu_continue(&None);
                 show_main_menu();
@@ -1473,8 +3123,10 @@ mod wasm_game {
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::Event| {
                 let mut g = game.borrow_mut();
                 if document_clone.visibility_state() == web_sys::VisibilityState::Hidden {
-                    // Auto-pause if playing
-                    if g.state.phase == GamePhase::Playing || g.state.phase == GamePhase::Serve {
+                    // Auto-pause if playing and the setting is enabled
+                    let active =
+                        g.state.phase == GamePhase::Playing || g.state.phase == GamePhase::Serve;
+                    if g.settings.pause_on_hide && active {
                         g.input.pause = true;
                         log::info!("Auto-paused (tab hidden)");
                     }
@@ -1483,8 +3135,9 @@ mod wasm_game {
                         g.audio.set_muted(true);
                     }
                 } else {
-                    // Unmute when visible again
-                    g.audio.set_muted(false);
+                    // Unmute when visible again, unless muted by hand
+                    let muted = g.settings.muted;
+                    g.audio.set_muted(muted);
                 }
             });
             let _ = document.add_event_listener_with_callback(
@@ -1499,7 +3152,9 @@ mod wasm_game {
             let game = game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::FocusEvent| {
                 let mut g = game.borrow_mut();
-                if g.state.phase == GamePhase::Playing || g.state.phase == GamePhase::Serve {
+                let active =
+                    g.state.phase == GamePhase::Playing || g.state.phase == GamePhase::Serve;
+                if g.settings.pause_on_blur && active {
                     g.input.pause = true;
                     log::info!("Auto-paused (window blur)");
                 }
@@ -1518,8 +3173,9 @@ mod wasm_game {
             let game = game.clone();
             let closure = Closure::<dyn FnMut(_)>::new(move |_event: web_sys::FocusEvent| {
                 let mut g = game.borrow_mut();
-                // Unmute audio
-                g.audio.set_muted(false);
+                // Unmute audio, unless muted by hand
+                let muted = g.settings.muted;
+                g.audio.set_muted(muted);
             });
             let _ =
                 window.add_event_listener_with_callback("focus", closure.as_ref().unchecked_ref());
@@ -1534,33 +3190,298 @@ pub async fn wasm_main() {
     wasm_game::run().await;
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+mod native_game {
+    use std::sync::Arc;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use winit::application::ApplicationHandler;
+    use winit::dpi::LogicalSize;
+    use winit::event::{ElementState, KeyEvent, MouseButton, WindowEvent};
+    use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
+    use winit::keyboard::{Key, NamedKey};
+    use winit::window::{Window, WindowId};
+
+    use roto_pong::consts::*;
+    use roto_pong::platform::time::{Clock, InstantClock};
+    use roto_pong::renderer::SdfRenderState;
+    use roto_pong::settings::{KeyAction, Settings};
+    use roto_pong::sim::{GamePhase, GameState, TickInput, generate_wave, tick};
+
+    /// Sim state and input for the native window. No HUD, audio or saves
+    /// yet: this is for iterating on the sim and renderer without a browser.
+    struct Game {
+        state: GameState,
+        settings: Settings,
+        accumulator: f32,
+        input: TickInput,
+        window_center: (f32, f32),
+        // Arrow key states for keyboard paddle control
+        key_left: bool,
+        key_right: bool,
+    }
+
+    impl Game {
+        fn new() -> Self {
+            let settings = Settings::load().unwrap_or_default();
+            let mut game = Self {
+                state: GameState::new(0),
+                settings,
+                accumulator: 0.0,
+                input: TickInput::default(),
+                window_center: (0.0, 0.0),
+                key_left: false,
+                key_right: false,
+            };
+            game.new_game();
+            game
+        }
+
+        /// Start a fresh run seeded from the clock
+        fn new_game(&mut self) {
+            let seed = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_millis() as u64);
+            self.state = GameState::new(seed).with_tuning(self.settings.difficulty.tuning());
+            self.state.tuning.gravity_enabled = !self.settings.relaxed_mode;
+            self.state.tuning.keyboard_speed = self.settings.keyboard_sensitivity;
+            generate_wave(&mut self.state);
+            self.accumulator = 0.0;
+            self.input = TickInput::default();
+            log::info!("Started new game with seed: {}", seed);
+        }
+
+        /// Convert a cursor position to paddle angle (same as the web path)
+        fn pos_to_angle(&self, x: f32, y: f32) -> f32 {
+            let dx = x - self.window_center.0;
+            let dy = -(y - self.window_center.1); // Negate Y (screen coords are flipped)
+            dy.atan2(dx)
+        }
+
+        /// Run simulation ticks for `dt` seconds of real time
+        fn update(&mut self, dt: f32) {
+            let dt = dt.min(0.1);
+            self.accumulator += dt;
+
+            // Held rotate keys spin the paddle (opposite keys cancel out)
+            self.input.paddle_spin = self.key_left as i8 as f32 - self.key_right as i8 as f32;
+            if self.input.paddle_spin != 0.0 {
+                self.input.target_theta = None;
+            }
+
+            let mut substeps = 0;
+            while self.accumulator >= SIM_DT && substeps < MAX_SUBSTEPS {
+                tick(&mut self.state, &self.input, SIM_DT);
+                self.accumulator -= SIM_DT;
+                substeps += 1;
+
+                // Clear one-shot inputs after processing
+                self.input.launch = false;
+                self.input.catch = false;
+                self.input.pause = false;
+                self.input.skip_wave = false;
+            }
+        }
+
+        /// Launch the served ball. There's no game over screen yet, so after
+        /// game over this starts the next run.
+        fn launch(&mut self) {
+            if self.state.phase == GamePhase::GameOver {
+                self.new_game();
+            } else {
+                self.input.launch = true;
+            }
+        }
+
+        fn key_pressed(&mut self, key: &str) {
+            match self.settings.key_bindings.action_for(key) {
+                Some(KeyAction::Launch) => self.launch(),
+                Some(KeyAction::Catch) => self.input.catch = true,
+                Some(KeyAction::Pause) => self.input.pause = true,
+                Some(KeyAction::Left) => self.key_left = true,
+                Some(KeyAction::Right) => self.key_right = true,
+                Some(KeyAction::SkipWave) => self.input.skip_wave = true,
+                Some(KeyAction::IdleMode) => self.input.idle_mode = !self.input.idle_mode,
+                Some(KeyAction::StepTick | KeyAction::Mute) | None => {}
+            }
+        }
+
+        fn key_released(&mut self, key: &str) {
+            match self.settings.key_bindings.action_for(key) {
+                Some(KeyAction::Left) => self.key_left = false,
+                Some(KeyAction::Right) => self.key_right = false,
+                _ => {}
+            }
+        }
+    }
+
+    /// Key name as the browser's `KeyboardEvent.key` spells it, so native
+    /// input goes through the same key bindings as the web build
+    fn dom_key_name(key: &Key) -> Option<String> {
+        match key {
+            Key::Character(c) => Some(c.to_string()),
+            Key::Named(NamedKey::Space) => Some(" ".to_string()),
+            Key::Named(NamedKey::Enter) => Some("Enter".to_string()),
+            Key::Named(NamedKey::Escape) => Some("Escape".to_string()),
+            Key::Named(NamedKey::ArrowLeft) => Some("ArrowLeft".to_string()),
+            Key::Named(NamedKey::ArrowRight) => Some("ArrowRight".to_string()),
+            _ => None,
+        }
+    }
+
+    /// winit application: owns the window once the event loop resumes
+    struct App {
+        game: Game,
+        window: Option<Arc<Window>>,
+        render_state: Option<SdfRenderState>,
+        clock: InstantClock,
+        last_frame_ms: Option<f64>,
+    }
+
+    impl App {
+        fn frame(&mut self) {
+            let time = self.clock.now_ms();
+            let dt = self
+                .last_frame_ms
+                .map_or(SIM_DT, |last| ((time - last) / 1000.0) as f32);
+            self.last_frame_ms = Some(time);
+            self.game.update(dt);
+
+            let Some(render_state) = &mut self.render_state else {
+                return;
+            };
+            match render_state.render(
+                &self.game.state,
+                &self.game.settings,
+                self.game.input.target_theta,
+                time,
+            ) {
+                Ok(_) => {}
+                Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                    render_state.resize(render_state.size.0, render_state.size.1);
+                }
+                Err(wgpu::SurfaceError::OutOfMemory) => {
+                    log::error!("Out of memory!");
+                }
+                Err(e) => log::warn!("Render error: {:?}", e),
+            }
+        }
+    }
+
+    impl ApplicationHandler for App {
+        fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+            if self.window.is_some() {
+                return;
+            }
+            let attributes = Window::default_attributes()
+                .with_title("Roto Pong")
+                .with_inner_size(LogicalSize::new(960.0, 960.0));
+            let window = Arc::new(
+                event_loop
+                    .create_window(attributes)
+                    .expect("Failed to create window"),
+            );
+            let size = window.inner_size();
+            self.game.window_center = (size.width as f32 / 2.0, size.height as f32 / 2.0);
+
+            let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+            let surface = instance
+                .create_surface(window.clone())
+                .expect("Failed to create surface");
+            let adapter =
+                pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+                    power_preference: wgpu::PowerPreference::HighPerformance,
+                    compatible_surface: Some(&surface),
+                    force_fallback_adapter: false,
+                }))
+                .expect("Failed to get adapter");
+            log::info!("Using adapter: {:?}", adapter.get_info().name);
+
+            self.render_state = Some(pollster::block_on(SdfRenderState::new(
+                surface,
+                &adapter,
+                size.width.max(1),
+                size.height.max(1),
+            )));
+            window.request_redraw();
+            self.window = Some(window);
+        }
+
+        fn window_event(
+            &mut self,
+            event_loop: &ActiveEventLoop,
+            _window_id: WindowId,
+            event: WindowEvent,
+        ) {
+            match event {
+                WindowEvent::CloseRequested => event_loop.exit(),
+                WindowEvent::Resized(size) => {
+                    self.game.window_center = (size.width as f32 / 2.0, size.height as f32 / 2.0);
+                    if let Some(render_state) = &mut self.render_state {
+                        render_state.resize(size.width, size.height);
+                    }
+                }
+                WindowEvent::CursorMoved { position, .. } => {
+                    let angle = self.game.pos_to_angle(position.x as f32, position.y as f32);
+                    self.game.input.target_theta = Some(angle);
+                }
+                WindowEvent::MouseInput {
+                    state: ElementState::Pressed,
+                    button: MouseButton::Left,
+                    ..
+                } => self.game.launch(),
+                WindowEvent::KeyboardInput {
+                    event:
+                        KeyEvent {
+                            logical_key,
+                            state,
+                            repeat: false,
+                            ..
+                        },
+                    ..
+                } => {
+                    if let Some(key) = dom_key_name(&logical_key) {
+                        match state {
+                            ElementState::Pressed => self.game.key_pressed(&key),
+                            ElementState::Released => self.game.key_released(&key),
+                        }
+                    }
+                }
+                WindowEvent::RedrawRequested => {
+                    self.frame();
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn run() {
+        let event_loop = EventLoop::new().expect("Failed to create event loop");
+        event_loop.set_control_flow(ControlFlow::Poll);
+        let mut app = App {
+            game: Game::new(),
+            window: None,
+            render_state: None,
+            clock: InstantClock::new(),
+            last_frame_ms: None,
+        };
+        if let Err(e) = event_loop.run_app(&mut app) {
+            log::error!("Event loop error: {}", e);
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn main() {
     env_logger::init();
     log::info!("Roto Pong (native) starting...");
-    log::info!("Native mode requires winit integration - run with `trunk serve` for web version");
-
-    // Run tests
-    println!("\nRunning collision tests...");
-    test_arc_collision();
+    native_game::run();
 }
 
 #[cfg(target_arch = "wasm32")]
 fn main() {
     // WASM entry point is wasm_main, this is just to satisfy the compiler
 }
-
-#[cfg(not(target_arch = "wasm32"))]
-fn test_arc_collision() {
-    use roto_pong::polar_to_cartesian;
-    use roto_pong::sim::{ArcSegment, ball_arc_collision};
-    use std::f32::consts::PI;
-
-    let paddle = ArcSegment::new(360.0, 12.0, -PI / 2.0 - 0.2, -PI / 2.0 + 0.2);
-    let ball_pos = polar_to_cartesian(357.0, -PI / 2.0);
-    let ball_radius = 8.0;
-
-    let result = ball_arc_collision(ball_pos, ball_radius, &paddle);
-    assert!(result.hit, "Collision should be detected");
-    println!("✓ Arc collision tests passed!");
-}
@@ -0,0 +1,249 @@
+//! Versioned save envelope
+//!
+//! A stored run is wrapped with the save format version that wrote it and a
+//! BLAKE3 digest of the run itself. The digest is taken over the payload's
+//! canonical JSON (object keys sorted, floats printed shortest-first), so it
+//! doesn't depend on the platform or on which encoding carries the
+//! envelope: a binary save re-derives the same bytes from its decoded
+//! payload, and a JSON save stores exactly those bytes as its payload.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+
+use super::{SaveError, migrate};
+
+/// Save format version written by this build. Bump it (and add a
+/// migration) whenever a saved struct changes shape: bincode isn't
+/// self-describing, so the version is all a binary save has to tell
+/// layouts apart.
+pub const SAVE_VERSION: u32 = 3;
+
+/// A payload sealed with its format version and integrity digest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaveEnvelope<T> {
+    pub version: u32,
+    pub payload: T,
+    /// BLAKE3 hash of the payload's canonical JSON
+    pub digest: [u8; 32],
+}
+
+/// JSON envelope with the payload kept as the exact text that was hashed
+#[derive(Serialize, Deserialize)]
+struct StoredEnvelope<'a> {
+    version: u32,
+    #[serde(borrow)]
+    payload: &'a RawValue,
+    digest: [u8; 32],
+}
+
+/// Canonical JSON of a payload. Going through `Value` sorts object keys,
+/// so field declaration order doesn't leak into the digest.
+fn canonical_json<T: Serialize>(payload: &T) -> Result<String, SaveError> {
+    Ok(serde_json::to_string(&serde_json::to_value(payload)?)?)
+}
+
+fn hash(canonical: &str) -> [u8; 32] {
+    *blake3::hash(canonical.as_bytes()).as_bytes()
+}
+
+fn check_version(version: u32) -> Result<(), SaveError> {
+    if version == SAVE_VERSION {
+        Ok(())
+    } else {
+        Err(SaveError::Version {
+            found: version,
+            supported: SAVE_VERSION,
+        })
+    }
+}
+
+impl<T: Serialize> SaveEnvelope<T> {
+    /// Wrap a payload, digesting it at the current save version
+    pub fn seal(payload: T) -> Result<Self, SaveError> {
+        let digest = hash(&canonical_json(&payload)?);
+        Ok(Self {
+            version: SAVE_VERSION,
+            payload,
+            digest,
+        })
+    }
+
+    /// JSON text of the envelope, payload in canonical form
+    pub fn to_json(&self) -> Result<String, SaveError> {
+        let payload = RawValue::from_string(canonical_json(&self.payload)?)?;
+        Ok(serde_json::to_string(&StoredEnvelope {
+            version: self.version,
+            payload: &payload,
+            digest: self.digest,
+        })?)
+    }
+
+    /// bincode bytes of the envelope
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SaveError> {
+        Ok(bincode::serialize(self)?)
+    }
+}
+
+impl<T: DeserializeOwned> SaveEnvelope<T> {
+    /// Verify a JSON envelope and return its payload, migrated up from
+    /// the version it was saved at
+    pub fn open(json: &str) -> Result<T, SaveError> {
+        let stored: StoredEnvelope = serde_json::from_str(json)?;
+        if hash(stored.payload.get()) != stored.digest {
+            return Err(SaveError::IntegrityMismatch);
+        }
+        if stored.version == SAVE_VERSION {
+            return Ok(serde_json::from_str(stored.payload.get())?);
+        }
+        let payload = migrate(stored.version, serde_json::from_str(stored.payload.get())?)?;
+        Ok(serde_json::from_value(payload)?)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> SaveEnvelope<T> {
+    /// Verify a bincode envelope and return its payload. bincode isn't
+    /// self-describing, so saves from other versions can't be migrated.
+    /// The version leads the envelope and is checked before the payload
+    /// is decoded, so another version's layout is reported as such rather
+    /// than as a corrupt save.
+    pub fn open_bytes(bytes: &[u8]) -> Result<T, SaveError> {
+        let version: u32 = bincode::deserialize(bytes)?;
+        check_version(version)?;
+        let envelope: SaveEnvelope<T> = bincode::deserialize(bytes)?;
+        if hash(&canonical_json(&envelope.payload)?) != envelope.digest {
+            return Err(SaveError::IntegrityMismatch);
+        }
+        Ok(envelope.payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::sim::GameState;
+
+    /// Digest of the saved `GameState`'s field paths at `SAVE_VERSION`
+    const SCHEMA_DIGEST: (u32, &str) = (3, "8f7614417727fb35");
+
+    /// Every object key path in `value`, array elements merged
+    fn field_paths(value: &serde_json::Value, path: &str, out: &mut BTreeSet<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, child) in map {
+                    let child_path = format!("{}.{}", path, key);
+                    field_paths(child, &child_path, out);
+                    out.insert(child_path);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    field_paths(item, &format!("{}[]", path), out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_schema_changes_bump_version() {
+        use crate::sim::state::Pickup;
+        use crate::sim::{InnerWall, PickupKind};
+
+        // A state with every optional part filled in
+        let mut state = GameState::heavy(3);
+        state.inner_wall = Some(InnerWall::new(2, 0.0));
+        let id = state.next_entity_id();
+        state.pickups.push(Pickup {
+            id,
+            kind: PickupKind::Shield,
+            pos: glam::Vec2::ZERO,
+            vel: glam::Vec2::ZERO,
+            ttl_ticks: 1,
+        });
+        state.balls[0].plowing = Some(1);
+
+        let mut paths = BTreeSet::new();
+        field_paths(&serde_json::to_value(&state).unwrap(), "", &mut paths);
+        let joined = paths.into_iter().collect::<Vec<_>>().join("\n");
+        let digest = blake3::hash(joined.as_bytes()).to_hex();
+        assert_eq!(
+            (SAVE_VERSION, &digest[..16]),
+            SCHEMA_DIGEST,
+            "saved fields changed: bump SAVE_VERSION, add a migration, then \
+             update SCHEMA_DIGEST"
+        );
+    }
+
+    #[test]
+    fn test_older_binary_save_is_refused_not_corrupt() {
+        let state = GameState::new(3);
+        let mut bytes = SaveEnvelope::seal(&state).unwrap().to_bytes().unwrap();
+        // An older build's header, followed by a payload in a layout this
+        // build can't decode
+        bytes[..4].copy_from_slice(&(SAVE_VERSION - 1).to_le_bytes());
+        bytes.truncate(bytes.len() / 2);
+        bytes.push(7);
+
+        let err = SaveEnvelope::<GameState>::open_bytes(&bytes).unwrap_err();
+        assert!(
+            matches!(err, SaveError::Version { found, .. } if found == SAVE_VERSION - 1),
+            "{}",
+            err
+        );
+        assert!(!err.is_corrupt());
+    }
+
+    #[test]
+    fn test_digest_is_canonical() {
+        #[derive(Serialize)]
+        struct Unsorted {
+            b: u32,
+            a: f32,
+        }
+        let envelope = SaveEnvelope::seal(Unsorted { b: 1, a: 0.1 }).unwrap();
+        assert_eq!(
+            envelope.digest,
+            *blake3::hash(br#"{"a":0.10000000149011612,"b":1}"#).as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_envelope_rejects_tampering() {
+        let mut state = GameState::new(3);
+        state.score = 4200;
+        let envelope = SaveEnvelope::seal(&state).unwrap();
+
+        let json = envelope.to_json().unwrap();
+        assert_eq!(SaveEnvelope::<GameState>::open(&json).unwrap().score, 4200);
+        let bytes = envelope.to_bytes().unwrap();
+        assert_eq!(
+            SaveEnvelope::<GameState>::open_bytes(&bytes).unwrap().score,
+            4200
+        );
+        // Both encodings carry the same digest
+        let from_json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(from_json["digest"], serde_json::json!(envelope.digest));
+
+        let edited = json.replace("\"score\":4200", "\"score\":9999");
+        assert_ne!(edited, json);
+        let err = SaveEnvelope::<GameState>::open(&edited).unwrap_err();
+        assert_eq!(err, SaveError::IntegrityMismatch);
+
+        let mut edited = SaveEnvelope::seal(&state).unwrap();
+        edited.digest[0] ^= 1;
+        let err = SaveEnvelope::<GameState>::open_bytes(&edited.to_bytes().unwrap()).unwrap_err();
+        assert_eq!(err, SaveError::IntegrityMismatch);
+
+        let version = format!("\"version\":{}", SAVE_VERSION);
+        let future = json.replacen(&version, "\"version\":99", 1);
+        let err = SaveEnvelope::<GameState>::open(&future).unwrap_err();
+        assert!(
+            matches!(err, SaveError::Version { found: 99, .. }),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Persistence errors
+//!
+//! Loading a save can fail in several distinct ways, and "my save
+//! disappeared" reports are only diagnosable if the caller knows which one
+//! happened. `SaveError` keeps them apart so the UI can log the specific
+//! failure and decide whether the stored save is worth keeping.
+
+use std::fmt;
+
+use super::{Invariant, MigrateError, SAVE_VERSION};
+use crate::platform::StorageError;
+
+/// Why a save couldn't be written or read back
+#[derive(Debug, Clone, PartialEq)]
+pub enum SaveError {
+    /// Stored integrity digest doesn't match the payload
+    IntegrityMismatch,
+    /// Payload doesn't decode to a saved run (bad JSON, binary or base64)
+    Parse(String),
+    /// Save was written by an unsupported format version
+    Version { found: u32, supported: u32 },
+    /// Storage backend is unavailable or the read/write failed
+    Storage(String),
+    /// Save decoded but breaks state invariants
+    Invalid(Vec<Invariant>),
+}
+
+impl SaveError {
+    /// Whether the stored save is unusable and should be discarded.
+    /// Storage failures may be transient, so the save is kept for a retry;
+    /// a save from another version is intact, just not readable by this
+    /// build, so it's kept too.
+    pub fn is_corrupt(&self) -> bool {
+        !matches!(self, SaveError::Storage(_) | SaveError::Version { .. })
+    }
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::IntegrityMismatch => write!(f, "integrity digest mismatch"),
+            SaveError::Parse(err) => write!(f, "parse error: {}", err),
+            SaveError::Version { found, supported } => write!(
+                f,
+                "unsupported save version {} (supported: {})",
+                found, supported
+            ),
+            SaveError::Storage(err) => write!(f, "storage error: {}", err),
+            SaveError::Invalid(problems) => {
+                write!(f, "invalid state: ")?;
+                for (i, problem) in problems.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", problem)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<StorageError> for SaveError {
+    fn from(err: StorageError) -> Self {
+        SaveError::Storage(err.0)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Parse(err.to_string())
+    }
+}
+
+impl From<MigrateError> for SaveError {
+    fn from(err: MigrateError) -> Self {
+        match err {
+            MigrateError::Unsupported { found } => SaveError::Version {
+                found,
+                supported: SAVE_VERSION,
+            },
+            MigrateError::Malformed(err) => SaveError::Parse(err),
+        }
+    }
+}
+
+impl From<bincode::Error> for SaveError {
+    fn from(err: bincode::Error) -> Self {
+        SaveError::Parse(err.to_string())
+    }
+}
+
+impl From<base64::DecodeError> for SaveError {
+    fn from(err: base64::DecodeError) -> Self {
+        SaveError::Parse(err.to_string())
+    }
+}
@@ -0,0 +1,138 @@
+//! Save migrations
+//!
+//! `#[serde(default)]` covers new fields whose zero value is right, but some
+//! schema changes need the old data rewritten. Each migration upgrades a
+//! JSON payload by one save version; `migrate` runs the chain from the
+//! version a save was written at up to `SAVE_VERSION`. Migrations work on
+//! JSON, so only JSON saves (and untagged legacy saves) can be upgraded;
+//! an older binary save is refused.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use super::SAVE_VERSION;
+
+/// Why a payload couldn't be upgraded
+#[derive(Debug, Clone, PartialEq)]
+pub enum MigrateError {
+    /// No migration chain leads from this version to the current one
+    Unsupported { found: u32 },
+    /// Payload doesn't have the shape its version should have
+    Malformed(String),
+}
+
+impl fmt::Display for MigrateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrateError::Unsupported { found } => {
+                write!(f, "can't migrate save version {}", found)
+            }
+            MigrateError::Malformed(err) => write!(f, "malformed save: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for MigrateError {}
+
+type Migration = fn(Value) -> Result<Value, MigrateError>;
+
+/// `MIGRATIONS[n]` upgrades version `n + 1` to `n + 2`
+const MIGRATIONS: [Migration; SAVE_VERSION as usize - 1] = [v1_ring_ids, v2_new_fields];
+
+/// Upgrade a payload written at `version` to `SAVE_VERSION`
+pub fn migrate(version: u32, mut value: Value) -> Result<Value, MigrateError> {
+    if version == 0 || version > SAVE_VERSION {
+        return Err(MigrateError::Unsupported { found: version });
+    }
+    for migration in &MIGRATIONS[version as usize - 1..] {
+        value = migration(value)?;
+    }
+    Ok(value)
+}
+
+/// v1 → v2: blocks saved before rings were tracked all default to ring 0,
+/// which makes every block look like the outer ring to ring clears. Number
+/// the rings from their radius instead, outermost first, the way
+/// `generate_wave` does.
+fn v1_ring_ids(mut value: Value) -> Result<Value, MigrateError> {
+    let Some(blocks) = value.get_mut("blocks").and_then(Value::as_array_mut) else {
+        return Err(MigrateError::Malformed("no blocks".into()));
+    };
+    if blocks.iter().all(|block| block.get("ring_id").is_some()) {
+        return Ok(value);
+    }
+
+    let radius = |block: &Value| block.pointer("/arc/radius").and_then(Value::as_f64);
+    let mut radii = Vec::with_capacity(blocks.len());
+    for block in blocks.iter() {
+        radii.push(radius(block).ok_or_else(|| MigrateError::Malformed("block radius".into()))?);
+    }
+    radii.sort_by(|a, b| b.total_cmp(a));
+    radii.dedup_by(|a, b| (*a - *b).abs() < 0.5);
+
+    for block in blocks.iter_mut() {
+        let r = radius(block).unwrap_or_default();
+        let ring = radii
+            .iter()
+            .position(|&ring| (ring - r).abs() < 0.5)
+            .unwrap_or(0);
+        block["ring_id"] = ring.into();
+    }
+    Ok(value)
+}
+
+/// v2 → v3: fields added since v2 (ball mass and plowing, bumper chains,
+/// sticky and time warp effects, combo milestones, the tuning tables) all
+/// load with `#[serde(default)]`. Only the binary layout changed, and the
+/// version bump is what tells the layouts apart.
+fn v2_new_fields(value: Value) -> Result<Value, MigrateError> {
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_v1_blocks_get_ring_ids() {
+        let v1 = json!({
+            "seed": 1,
+            "blocks": [
+                { "id": 1, "arc": { "radius": 220.0 } },
+                { "id": 2, "arc": { "radius": 300.0 } },
+                { "id": 3, "arc": { "radius": 260.0 } },
+                { "id": 4, "arc": { "radius": 300.0 } }
+            ]
+        });
+        let migrated = migrate(1, v1).unwrap();
+        let rings: Vec<_> = migrated["blocks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|block| block["ring_id"].as_u64().unwrap())
+            .collect();
+        assert_eq!(rings, [2, 0, 1, 0]);
+        assert_eq!(migrated["seed"], 1);
+
+        // Current saves pass through untouched
+        let current = json!({ "blocks": [{ "ring_id": 3, "arc": { "radius": 1.0 } }] });
+        assert_eq!(migrate(SAVE_VERSION, current.clone()).unwrap(), current);
+
+        assert_eq!(
+            migrate(1, json!({})),
+            Err(MigrateError::Malformed("no blocks".into()))
+        );
+        assert_eq!(
+            migrate(0, json!({})),
+            Err(MigrateError::Unsupported { found: 0 })
+        );
+        assert_eq!(
+            migrate(SAVE_VERSION + 1, json!({})),
+            Err(MigrateError::Unsupported {
+                found: SAVE_VERSION + 1
+            })
+        );
+    }
+}
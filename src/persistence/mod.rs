@@ -1,12 +1,304 @@
 //! Save/load persistence with integrity verification
 //!
 //! Features:
-//! - Versioned JSON envelope
+//! - Versioned JSON envelope, with migrations from older save versions
+//! - Compact binary format (bincode), selected by a leading format byte
 //! - BLAKE3 integrity digest
 //! - Backup rotation (tmp → save, old save → backup)
 //! - Corruption detection and recovery
+//! - Shareable replay files, verified by re-simulating them on load
 
-// TODO: Implement persistence
-// pub mod envelope;
-// pub mod validation;
-// pub mod migration;
+pub mod envelope;
+pub mod error;
+pub mod migration;
+pub mod validation;
+
+pub use envelope::{SAVE_VERSION, SaveEnvelope};
+pub use error::SaveError;
+pub use migration::{MigrateError, migrate};
+pub use validation::{Invariant, SaveSlot};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+use crate::sim::{GameState, Recording};
+
+/// Leading byte of a replay file (bincode, base64-encoded like binary saves)
+pub const REPLAY_TAG: char = 'R';
+
+/// Encoding of a stored run's `SaveEnvelope`. The stored text starts with
+/// the format's tag byte; text starting with `{` is an untagged, unsealed
+/// save from before formats existed and is read as plain version 1 JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveFormat {
+    /// JSON, readable in the browser's storage inspector
+    Json,
+    /// bincode, base64-encoded since LocalStorage only holds strings
+    Binary,
+}
+
+impl SaveFormat {
+    /// Leading byte that marks the format in stored text
+    pub fn tag(self) -> char {
+        match self {
+            SaveFormat::Json => 'J',
+            SaveFormat::Binary => 'B',
+        }
+    }
+
+    fn from_tag(tag: char) -> Option<Self> {
+        match tag {
+            'J' => Some(SaveFormat::Json),
+            'B' => Some(SaveFormat::Binary),
+            _ => None,
+        }
+    }
+}
+
+/// Seal a run in its envelope and serialize it for storage
+pub fn encode_game(state: &GameState, format: SaveFormat) -> Result<String, SaveError> {
+    let envelope = SaveEnvelope::seal(state)?;
+    let mut text = String::from(format.tag());
+    match format {
+        SaveFormat::Json => text.push_str(&envelope.to_json()?),
+        SaveFormat::Binary => BASE64.encode_string(envelope.to_bytes()?, &mut text),
+    }
+    Ok(text)
+}
+
+/// Decode a stored run in any format, rejecting saves whose digest doesn't
+/// match or that break state invariants
+pub fn decode_game(text: &str) -> Result<GameState, SaveError> {
+    let state: GameState = if text.starts_with('{') {
+        serde_json::from_value(migrate(1, serde_json::from_str(text)?)?)?
+    } else {
+        let mut chars = text.chars();
+        let tag = chars.next().unwrap_or_default();
+        let payload = chars.as_str();
+        match SaveFormat::from_tag(tag) {
+            Some(SaveFormat::Json) => SaveEnvelope::open(payload)?,
+            Some(SaveFormat::Binary) => SaveEnvelope::open_bytes(&BASE64.decode(payload)?)?,
+            None => return Err(SaveError::Parse(format!("unknown save format {:?}", tag))),
+        }
+    };
+    state.validate().map_err(SaveError::Invalid)?;
+    Ok(state)
+}
+
+/// Serialize a recording as a shareable replay file
+pub fn encode_replay(recording: &Recording) -> Result<String, SaveError> {
+    let mut text = String::from(REPLAY_TAG);
+    BASE64.encode_string(bincode::serialize(recording)?, &mut text);
+    Ok(text)
+}
+
+/// Decode a replay file (or a recording's JSON) and play it through
+/// headless, refusing replays whose checkpoint hashes don't match (edited,
+/// or from another build)
+pub fn decode_replay(text: &str) -> Result<Recording, SaveError> {
+    let text = text.trim();
+    let recording = if text.starts_with('{') {
+        Recording::from_json(text)?
+    } else if let Some(payload) = text.strip_prefix(REPLAY_TAG) {
+        bincode::deserialize(&BASE64.decode(payload)?)?
+    } else {
+        return Err(SaveError::Parse("not a replay file".into()));
+    };
+    recording.verify().map_err(|err| {
+        log::warn!("Replay refused: {}", err);
+        SaveError::IntegrityMismatch
+    })?;
+    Ok(recording)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_errors() {
+        let state = GameState::new(9);
+        let json = encode_game(&state, SaveFormat::Json).unwrap();
+        assert_eq!(decode_game(&json).unwrap().seed, 9);
+
+        let truncated = &json[..json.len() / 2];
+        let err = decode_game(truncated).unwrap_err();
+        assert!(matches!(err, SaveError::Parse(_)), "{}", err);
+        assert!(err.is_corrupt());
+
+        let mut broken = state;
+        broken.lives = 0;
+        let err = decode_game(&encode_game(&broken, SaveFormat::Json).unwrap()).unwrap_err();
+        assert!(matches!(err, SaveError::Invalid(_)), "{}", err);
+        // Validation runs on binary saves too
+        let err = decode_game(&encode_game(&broken, SaveFormat::Binary).unwrap()).unwrap_err();
+        assert!(matches!(err, SaveError::Invalid(_)), "{}", err);
+
+        let err = decode_game("X{}").unwrap_err();
+        assert!(matches!(err, SaveError::Parse(_)), "{}", err);
+        let err = decode_game("B!!not base64!!").unwrap_err();
+        assert!(matches!(err, SaveError::Parse(_)), "{}", err);
+
+        // A bit flipped inside a binary save fails the digest
+        let binary = encode_game(&GameState::new(9), SaveFormat::Binary).unwrap();
+        let mut bytes = BASE64.decode(&binary[1..]).unwrap();
+        bytes[20] ^= 0x40;
+        let flipped = format!("B{}", BASE64.encode(bytes));
+        let err = decode_game(&flipped).unwrap_err();
+        assert_eq!(err, SaveError::IntegrityMismatch);
+        assert!(err.is_corrupt());
+
+        assert!(!SaveError::Storage("quota".into()).is_corrupt());
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        // Late wave with a full set of rings
+        let mut state = GameState::new(21);
+        state.wave_index = 12;
+        crate::sim::generate_wave(&mut state);
+        state.score = 123_456;
+        assert!(state.blocks.len() > 100);
+
+        let binary = encode_game(&state, SaveFormat::Binary).unwrap();
+        let json = encode_game(&state, SaveFormat::Json).unwrap();
+        let decoded = decode_game(&binary).unwrap();
+        assert_eq!(
+            serde_json::to_string(&decoded).unwrap(),
+            serde_json::to_string(&state).unwrap()
+        );
+
+        // Well under JSON's size, even after base64
+        assert!(
+            binary.len() * 2 < json.len(),
+            "binary {} vs json {} bytes",
+            binary.len(),
+            json.len()
+        );
+
+        // Saves from before format tags still load
+        let untagged = serde_json::to_string(&state).unwrap();
+        assert_eq!(decode_game(&untagged).unwrap().score, 123_456);
+    }
+
+    #[test]
+    fn test_v1_save_upgrades() {
+        let mut state = GameState::new(31);
+        state.wave_index = 6;
+        crate::sim::generate_wave(&mut state);
+        let rings: Vec<u32> = state.blocks.iter().map(|b| b.ring_id).collect();
+        assert!(rings.iter().any(|&ring| ring > 0));
+
+        // A v1 save, written before blocks knew their ring
+        let mut payload = serde_json::to_value(&state).unwrap();
+        for block in payload["blocks"].as_array_mut().unwrap() {
+            block.as_object_mut().unwrap().remove("ring_id");
+        }
+        let payload = serde_json::to_string(&payload).unwrap();
+        let digest = *blake3::hash(payload.as_bytes()).as_bytes();
+        let v1 = format!(
+            r#"J{{"version":1,"payload":{},"digest":{}}}"#,
+            payload,
+            serde_json::to_string(&digest).unwrap()
+        );
+
+        let loaded = decode_game(&v1).unwrap();
+        let migrated: Vec<u32> = loaded.blocks.iter().map(|b| b.ring_id).collect();
+        assert_eq!(migrated, rings);
+
+        // Untagged legacy saves are v1 too
+        let loaded = decode_game(&payload).unwrap();
+        assert_eq!(
+            loaded.blocks.iter().map(|b| b.ring_id).collect::<Vec<_>>(),
+            rings
+        );
+
+        // Binary saves can't be migrated
+        let mut envelope = SaveEnvelope::seal(&state).unwrap();
+        envelope.version = 1;
+        let binary = format!("B{}", BASE64.encode(envelope.to_bytes().unwrap()));
+        let err = decode_game(&binary).unwrap_err();
+        assert!(
+            matches!(err, SaveError::Version { found: 1, .. }),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_v2_save_upgrades() {
+        let state = GameState::new(12)
+            .with_ball_free(glam::Vec2::new(150.0, 0.0), glam::Vec2::new(0.0, 200.0));
+
+        // A v2 JSON save, written before the fields v3 added
+        let mut payload = serde_json::to_value(&state).unwrap();
+        payload.as_object_mut().unwrap().remove("combo_milestone");
+        for ball in payload["balls"].as_array_mut().unwrap() {
+            let ball = ball.as_object_mut().unwrap();
+            for field in ["mass", "plow_ready", "plowing", "bumper_chain"] {
+                ball.remove(field);
+            }
+        }
+        let effects = payload["effects"].as_object_mut().unwrap();
+        effects.remove("sticky_active");
+        effects.remove("time_warp_ticks");
+        let payload = serde_json::to_string(&payload).unwrap();
+        let digest = *blake3::hash(payload.as_bytes()).as_bytes();
+        let v2 = format!(
+            r#"J{{"version":2,"payload":{},"digest":{}}}"#,
+            payload,
+            serde_json::to_string(&digest).unwrap()
+        );
+
+        let loaded = decode_game(&v2).unwrap();
+        assert!(loaded.balls.iter().all(|b| b.mass == 1.0));
+        assert_eq!(loaded.effects.time_warp_ticks, 0);
+        assert_eq!(loaded.balls.len(), state.balls.len());
+
+        // A v2 binary save is kept for a build that can read it
+        let mut envelope = SaveEnvelope::seal(&state).unwrap();
+        envelope.version = 2;
+        let binary = format!("B{}", BASE64.encode(envelope.to_bytes().unwrap()));
+        let err = decode_game(&binary).unwrap_err();
+        assert!(
+            matches!(err, SaveError::Version { found: 2, .. }),
+            "{}",
+            err
+        );
+        assert!(!err.is_corrupt());
+    }
+
+    #[test]
+    fn test_replay_file_round_trip() {
+        use crate::consts::SIM_DT;
+        use crate::sim::{ReplayRecorder, TickInput, tick};
+
+        let tuning = crate::tuning::TuningConfig::default();
+        let mut recorder = ReplayRecorder::new(77, &tuning);
+        let mut state = Recording::new(77, &tuning).start_state();
+        for i in 0..700 {
+            let input = TickInput {
+                target_theta: Some(i as f32 * 0.01),
+                launch: i == 5,
+                ..Default::default()
+            };
+            tick(&mut state, &input, SIM_DT);
+            recorder.record(&input, &state);
+        }
+        let recording = recorder.finish(&state);
+
+        let text = encode_replay(&recording).unwrap();
+        assert_eq!(decode_replay(&format!("{}\n", text)).unwrap(), recording);
+        let json = recording.to_json().unwrap();
+        assert_eq!(decode_replay(&json).unwrap(), recording);
+
+        // Tampered replays are refused
+        let mut tampered = recording;
+        tampered.inputs.retain(|(_, input)| !input.launch);
+        let err = decode_replay(&encode_replay(&tampered).unwrap()).unwrap_err();
+        assert_eq!(err, SaveError::IntegrityMismatch);
+
+        let err = decode_replay(&text[1..]).unwrap_err();
+        assert!(matches!(err, SaveError::Parse(_)), "{}", err);
+    }
+}
@@ -0,0 +1,489 @@
+//! Save validation
+//!
+//! A save that deserializes cleanly can still be internally inconsistent
+//! (hand-edited, truncated by a migration bug, written mid-crash). Loading
+//! runs `GameState::validate` and throws such saves away instead of handing
+//! the simulation state it was never designed to reach.
+//!
+//! Writes go through a `SaveSlot`, which never overwrites the only good
+//! copy: a new save lands in a tmp key and is read back and verified before
+//! it replaces the primary, and the previous primary is kept as a backup
+//! that loading falls back to if the primary is damaged.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use super::{SaveError, SaveFormat, decode_game, encode_game};
+use crate::consts::PADDLE_RADIUS;
+use crate::platform::Storage;
+use crate::sim::{BallState, GamePhase, GameState};
+
+/// A broken invariant found in a loaded state
+#[derive(Debug, Clone, PartialEq)]
+pub enum Invariant {
+    /// A float field is NaN or infinite
+    NonFinite(String),
+    /// Two entities share an ID
+    DuplicateId(u32),
+    /// An entity ID that the ID allocator would hand out again
+    UnallocatedId(u32),
+    /// A sliding ball references a block that doesn't exist
+    MissingSlideBlock { ball_id: u32, block_id: u32 },
+    /// A block with no HP left is still in play
+    DeadBlock(u32),
+    /// Lives are exhausted but the run hasn't ended
+    NoLivesLeft(GamePhase),
+    /// Arena doesn't fit around the black hole and paddle
+    InvalidArena(f32),
+}
+
+impl fmt::Display for Invariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Invariant::NonFinite(field) => write!(f, "{} is not finite", field),
+            Invariant::DuplicateId(id) => write!(f, "entity id {} is used twice", id),
+            Invariant::UnallocatedId(id) => write!(f, "entity id {} was never allocated", id),
+            Invariant::MissingSlideBlock { ball_id, block_id } => write!(
+                f,
+                "ball {} is sliding through missing block {}",
+                ball_id, block_id
+            ),
+            Invariant::DeadBlock(id) => write!(f, "block {} has no hp left", id),
+            Invariant::NoLivesLeft(phase) => write!(f, "no lives left in phase {:?}", phase),
+            Invariant::InvalidArena(radius) => write!(f, "arena radius {} is too small", radius),
+        }
+    }
+}
+
+/// Collects `NonFinite` entries for a named group of floats
+fn check_finite(problems: &mut Vec<Invariant>, field: impl Fn() -> String, values: &[f32]) {
+    if values.iter().any(|v| !v.is_finite()) {
+        problems.push(Invariant::NonFinite(field()));
+    }
+}
+
+impl GameState {
+    /// Check that the state is internally consistent: every float is
+    /// finite, entity IDs are unique and allocated, references between
+    /// entities resolve, and the phase agrees with the remaining lives.
+    pub fn validate(&self) -> Result<(), Vec<Invariant>> {
+        let mut problems = Vec::new();
+
+        check_finite(
+            &mut problems,
+            || "arena_radius".into(),
+            &[self.arena_radius],
+        );
+        if self.arena_radius.is_finite() && self.arena_radius <= PADDLE_RADIUS {
+            problems.push(Invariant::InvalidArena(self.arena_radius));
+        }
+
+        let paddle = &self.paddle;
+        check_finite(
+            &mut problems,
+            || "paddle".into(),
+            &[
+                paddle.theta,
+                paddle.arc_width,
+                paddle.angular_vel,
+                paddle.arc_width_vel,
+            ],
+        );
+
+        if let Some(wall) = &self.inner_wall {
+            check_finite(
+                &mut problems,
+                || "inner_wall".into(),
+                &[wall.radius, wall.rotation, wall.spin],
+            );
+        }
+
+        check_finite(
+            &mut problems,
+            || "wind".into(),
+            &[self.wind_strength, self.wind_phase],
+        );
+
+        for wave in &self.shockwaves {
+            check_finite(
+                &mut problems,
+                || "shockwave".into(),
+                &[wave.center.x, wave.center.y, wave.radius, wave.expand_rate],
+            );
+        }
+
+        let tuning = &self.tuning;
+        check_finite(
+            &mut problems,
+            || "tuning".into(),
+            &[tuning.paddle_max_speed, tuning.portal_slide_speed],
+        );
+
+        // Entity IDs: unique across kinds and below the next ID to allocate
+        let mut seen = HashSet::new();
+        let ids = self
+            .balls
+            .iter()
+            .map(|b| b.id)
+            .chain(self.blocks.iter().map(|b| b.id))
+            .chain(self.pickups.iter().map(|p| p.id));
+        for id in ids {
+            if !seen.insert(id) {
+                problems.push(Invariant::DuplicateId(id));
+            }
+            if id >= self.next_id() {
+                problems.push(Invariant::UnallocatedId(id));
+            }
+        }
+
+        for block in &self.blocks {
+            let arc = &block.arc;
+            check_finite(
+                &mut problems,
+                || format!("block {}", block.id),
+                &[
+                    arc.radius,
+                    arc.thickness,
+                    arc.theta_start,
+                    arc.theta_end,
+                    block.rotation_speed,
+                    block.wobble,
+                    block.visibility,
+                    block.ghost_phase,
+                ],
+            );
+            if block.hp == 0 {
+                problems.push(Invariant::DeadBlock(block.id));
+            }
+        }
+
+        for ball in &self.balls {
+            let mut values = vec![
+                ball.pos.x,
+                ball.pos.y,
+                ball.vel.x,
+                ball.vel.y,
+                ball.radius,
+                ball.electric_charge,
+                ball.curve,
+            ];
+            match ball.state {
+                BallState::Attached { offset } => values.push(offset),
+                BallState::Free => {}
+                BallState::Sliding {
+                    block_id,
+                    theta,
+                    direction,
+                    entry_speed,
+                    arc_start,
+                    arc_end,
+                    radius,
+                    total_traveled,
+                    max_travel,
+                } => {
+                    values.extend([
+                        theta,
+                        direction,
+                        entry_speed,
+                        arc_start,
+                        arc_end,
+                        radius,
+                        total_traveled,
+                        max_travel,
+                    ]);
+                    if !self.blocks.iter().any(|b| b.id == block_id) {
+                        problems.push(Invariant::MissingSlideBlock {
+                            ball_id: ball.id,
+                            block_id,
+                        });
+                    }
+                }
+                BallState::Dying { timer, start_pos } => {
+                    values.extend([timer, start_pos.0, start_pos.1])
+                }
+            }
+            check_finite(&mut problems, || format!("ball {}", ball.id), &values);
+        }
+
+        for pickup in &self.pickups {
+            check_finite(
+                &mut problems,
+                || format!("pickup {}", pickup.id),
+                &[pickup.pos.x, pickup.pos.y, pickup.vel.x, pickup.vel.y],
+            );
+        }
+
+        if self.lives == 0 && self.phase != GamePhase::GameOver {
+            problems.push(Invariant::NoLivesLeft(self.phase));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+}
+
+/// A saved run stored under three keys: `key` (primary), `key.tmp` (write
+/// in progress) and `key.bak` (previous good primary) of `storage`
+pub struct SaveSlot<'a> {
+    storage: &'a dyn Storage,
+    key: String,
+    format: SaveFormat,
+}
+
+impl<'a> SaveSlot<'a> {
+    pub fn new(storage: &'a dyn Storage, key: &str, format: SaveFormat) -> Self {
+        Self {
+            storage,
+            key: key.to_string(),
+            format,
+        }
+    }
+
+    fn tmp_key(&self) -> String {
+        format!("{}.tmp", self.key)
+    }
+
+    fn backup_key(&self) -> String {
+        format!("{}.bak", self.key)
+    }
+
+    /// Save a run: write it to tmp, verify it reads back, then move the old
+    /// primary to backup and promote tmp. Returns the stored size in bytes.
+    /// If anything fails the primary is left as it was.
+    pub fn write(&self, state: &GameState) -> Result<usize, SaveError> {
+        let text = encode_game(state, self.format)?;
+        let tmp_key = self.tmp_key();
+        self.storage.set(&tmp_key, &text)?;
+        let written = self
+            .storage
+            .get(&tmp_key)?
+            .ok_or_else(|| SaveError::Storage("save vanished after writing".into()))?;
+        decode_game(&written)?;
+
+        // Only a primary that still verifies is worth keeping as backup;
+        // otherwise the backup already holds the last good save
+        if let Some(old) = self.storage.get(&self.key)?
+            && decode_game(&old).is_ok()
+        {
+            self.storage.set(&self.backup_key(), &old)?;
+        }
+        self.storage.set(&self.key, &written)?;
+        self.storage.remove(&tmp_key)?;
+        Ok(written.len())
+    }
+
+    /// Load the saved run (`Ok(None)` if there is none), falling back to
+    /// the backup if the primary is corrupt
+    pub fn read(&self) -> Result<Option<GameState>, SaveError> {
+        match self.read_key(&self.key) {
+            Err(err) if err.is_corrupt() => match self.read_key(&self.backup_key()) {
+                Ok(Some(state)) => {
+                    log::warn!("Save recovered from backup ({})", err);
+                    Ok(Some(state))
+                }
+                _ => Err(err),
+            },
+            result => result,
+        }
+    }
+
+    fn read_key(&self, key: &str) -> Result<Option<GameState>, SaveError> {
+        self.storage
+            .get(key)?
+            .map(|text| decode_game(&text))
+            .transpose()
+    }
+
+    /// Delete the save along with its backup and any unfinished write
+    pub fn clear(&self) -> Result<(), SaveError> {
+        self.storage.remove(&self.tmp_key())?;
+        self.storage.remove(&self.backup_key())?;
+        Ok(self.storage.remove(&self.key)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::{MemoryStorage, StorageError};
+    use crate::sim::{ArcSegment, BlockKind};
+    use glam::Vec2;
+
+    fn valid_state() -> GameState {
+        GameState::new(5)
+            .with_ball_free(Vec2::new(150.0, 0.0), Vec2::new(0.0, 200.0))
+            .with_block(
+                ArcSegment::new(250.0, 20.0, 0.0, 0.5),
+                BlockKind::Portal { pair_id: 1 },
+            )
+            .with_phase(GamePhase::Playing)
+    }
+
+    #[test]
+    fn test_valid_states_pass() {
+        assert_eq!(GameState::new(1).validate(), Ok(()));
+        assert_eq!(valid_state().validate(), Ok(()));
+
+        // Survives a save/load round trip
+        let json = serde_json::to_string(&valid_state()).unwrap();
+        let loaded: GameState = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_broken_states_rejected() {
+        let mut state = valid_state();
+        state.balls[1].pos.x = f32::NAN;
+        let ball_id = state.balls[1].id;
+        assert_eq!(
+            state.validate(),
+            Err(vec![Invariant::NonFinite(format!("ball {}", ball_id))])
+        );
+
+        let mut state = valid_state();
+        state.balls[1].state = BallState::Sliding {
+            block_id: 999,
+            theta: 0.2,
+            direction: 1.0,
+            entry_speed: 200.0,
+            arc_start: 0.0,
+            arc_end: 0.5,
+            radius: 250.0,
+            total_traveled: 0.1,
+            max_travel: 1.0,
+        };
+        assert_eq!(
+            state.validate(),
+            Err(vec![Invariant::MissingSlideBlock {
+                ball_id,
+                block_id: 999
+            }])
+        );
+
+        let mut state = valid_state();
+        state.lives = 0;
+        assert_eq!(
+            state.validate(),
+            Err(vec![Invariant::NoLivesLeft(GamePhase::Playing)])
+        );
+
+        let mut state = valid_state();
+        let block_id = state.blocks[0].id;
+        state.balls[1].id = block_id;
+        state.blocks[0].hp = 0;
+        state.paddle.theta = f32::INFINITY;
+        let problems = state.validate().unwrap_err();
+        assert!(problems.contains(&Invariant::DuplicateId(block_id)));
+        assert!(problems.contains(&Invariant::DeadBlock(block_id)));
+        assert!(problems.contains(&Invariant::NonFinite("paddle".into())));
+    }
+
+    /// Storage whose writes to tmp keys get cut short, like a tab killed
+    /// mid-save
+    struct TornWrites(MemoryStorage);
+
+    impl Storage for TornWrites {
+        fn get(&self, key: &str) -> Result<Option<String>, StorageError> {
+            self.0.get(key)
+        }
+
+        fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
+            let value = if key.ends_with(".tmp") {
+                &value[..value.len() / 2]
+            } else {
+                value
+            };
+            self.0.set(key, value)
+        }
+
+        fn remove(&self, key: &str) -> Result<(), StorageError> {
+            self.0.remove(key)
+        }
+    }
+
+    /// Storage that can't be read at all, like LocalStorage blocked by the
+    /// browser's privacy settings
+    struct Unreadable;
+
+    impl Storage for Unreadable {
+        fn get(&self, _key: &str) -> Result<Option<String>, StorageError> {
+            Err(StorageError("access denied".into()))
+        }
+
+        fn set(&self, _key: &str, _value: &str) -> Result<(), StorageError> {
+            Ok(())
+        }
+
+        fn remove(&self, _key: &str) -> Result<(), StorageError> {
+            Ok(())
+        }
+    }
+
+    fn state_at_wave(wave_index: u32) -> GameState {
+        let mut state = GameState::new(5);
+        state.wave_index = wave_index;
+        state
+    }
+
+    #[test]
+    fn test_save_slot_rotates_and_recovers() {
+        let storage = MemoryStorage::new();
+        let slot = SaveSlot::new(&storage, "save", SaveFormat::Json);
+        assert!(slot.read().unwrap().is_none());
+
+        slot.write(&state_at_wave(1)).unwrap();
+        slot.write(&state_at_wave(2)).unwrap();
+        assert_eq!(slot.read().unwrap().unwrap().wave_index, 2);
+        assert_eq!(storage.get("save.tmp"), Ok(None));
+        assert_eq!(slot.read_key("save.bak").unwrap().unwrap().wave_index, 1);
+
+        // A damaged primary falls back to the backup...
+        let primary = storage.get("save").unwrap().unwrap();
+        let primary = primary.replace("\"wave_index\":2", "\"wave_index\":7");
+        storage.set("save", &primary).unwrap();
+        assert_eq!(slot.read().unwrap().unwrap().wave_index, 1);
+        // ...and doesn't displace it on the next write
+        slot.write(&state_at_wave(3)).unwrap();
+        assert_eq!(slot.read_key("save.bak").unwrap().unwrap().wave_index, 1);
+
+        // With both copies damaged the error comes through
+        storage.set("save", "J{").unwrap();
+        storage.set("save.bak", "J{").unwrap();
+        assert!(slot.read().unwrap_err().is_corrupt());
+
+        slot.clear().unwrap();
+        for key in ["save", "save.bak", "save.tmp"] {
+            assert_eq!(storage.get(key), Ok(None));
+        }
+    }
+
+    #[test]
+    fn test_save_slot_survives_torn_write() {
+        let storage = TornWrites(MemoryStorage::new());
+        let slot = SaveSlot::new(&storage, "save", SaveFormat::Binary);
+        storage
+            .0
+            .set(
+                "save",
+                &encode_game(&state_at_wave(4), SaveFormat::Binary).unwrap(),
+            )
+            .unwrap();
+
+        assert!(slot.write(&state_at_wave(5)).is_err());
+        // The half-written save never replaced the good one
+        assert_eq!(slot.read().unwrap().unwrap().wave_index, 4);
+        assert_eq!(storage.0.get("save.bak"), Ok(None));
+    }
+
+    #[test]
+    fn test_unreadable_storage_is_an_error_not_no_save() {
+        let slot = SaveSlot::new(&Unreadable, "save", SaveFormat::Json);
+        let err = slot.read().unwrap_err();
+        assert!(matches!(err, SaveError::Storage(_)));
+        // Kept for the next launch rather than thrown away
+        assert!(!err.is_corrupt());
+    }
+}
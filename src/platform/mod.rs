@@ -4,9 +4,13 @@
 //! - Time/ticks
 //! - Input events
 //! - Visibility/focus detection
-//! - Storage (LocalStorage on web)
+//! - Storage (LocalStorage on web, files on native)
+
+pub mod storage;
+pub mod time;
 
 // TODO: Implement platform-specific modules
 // pub mod input;
-// pub mod storage;
-// pub mod time;
+
+pub use storage::{MemoryStorage, Storage, StorageError, default_storage};
+pub use time::{Clock, default_clock};
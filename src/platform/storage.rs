@@ -0,0 +1,187 @@
+//! Key-value storage for saves and high scores
+//!
+//! The web build keeps everything in LocalStorage; native builds write one
+//! file per key under the user's config directory. Game code only sees
+//! `&dyn Storage`, so it runs the same on both, and tests can hand it a
+//! `MemoryStorage`.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A read, write or remove the backend refused (quota, permissions, no
+/// disk)
+#[derive(Debug, Clone, PartialEq)]
+pub struct StorageError(pub String);
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+/// String key-value store
+pub trait Storage {
+    /// Value stored under `key`, or `None` if there is none. A key that
+    /// exists but can't be read is an error, not `None`.
+    fn get(&self, key: &str) -> Result<Option<String>, StorageError>;
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError>;
+    /// Remove `key`; removing a missing key is not an error
+    fn remove(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// In-memory storage, for tests and as a fallback when the real backend is
+/// unavailable (nothing survives a restart)
+#[derive(Debug, Default)]
+pub struct MemoryStorage(RefCell<BTreeMap<String, String>>);
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, key: &str) -> Result<Option<String>, StorageError> {
+        Ok(self.0.borrow().get(key).cloned())
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
+        self.0
+            .borrow_mut()
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        self.0.borrow_mut().remove(key);
+        Ok(())
+    }
+}
+
+/// The browser's LocalStorage
+#[cfg(target_arch = "wasm32")]
+pub struct LocalStorageBackend(web_sys::Storage);
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStorageBackend {
+    /// LocalStorage of the current window, if the browser allows it
+    /// (private browsing modes may not)
+    pub fn new() -> Option<Self> {
+        web_sys::window()?.local_storage().ok().flatten().map(Self)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Storage for LocalStorageBackend {
+    fn get(&self, key: &str) -> Result<Option<String>, StorageError> {
+        self.0
+            .get_item(key)
+            .map_err(|e| StorageError(format!("{:?}", e)))
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
+        self.0
+            .set_item(key, value)
+            .map_err(|e| StorageError(format!("{:?}", e)))
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        self.0
+            .remove_item(key)
+            .map_err(|e| StorageError(format!("{:?}", e)))
+    }
+}
+
+/// One JSON file per key in a directory
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStorageBackend {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorageBackend {
+    /// Storage in `dir`, created on first write
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Storage in the platform config directory (e.g.
+    /// `~/.config/roto-pong` on Linux), if the OS has one
+    pub fn in_config_dir() -> Option<Self> {
+        let dirs = directories::ProjectDirs::from("", "", "roto-pong")?;
+        Some(Self::new(dirs.config_dir()))
+    }
+
+    fn path(&self, key: &str) -> std::path::PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Storage for FileStorageBackend {
+    fn get(&self, key: &str) -> Result<Option<String>, StorageError> {
+        match std::fs::read_to_string(self.path(key)) {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(StorageError(e.to_string())),
+        }
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
+        std::fs::create_dir_all(&self.dir)
+            .and_then(|_| std::fs::write(self.path(key), value))
+            .map_err(|e| StorageError(e.to_string()))
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        match std::fs::remove_file(self.path(key)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(StorageError(e.to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The platform's persistent storage, or `MemoryStorage` if it's
+/// unavailable
+pub fn default_storage() -> Box<dyn Storage> {
+    #[cfg(target_arch = "wasm32")]
+    let storage = LocalStorageBackend::new().map(|s| Box::new(s) as Box<dyn Storage>);
+    #[cfg(not(target_arch = "wasm32"))]
+    let storage = FileStorageBackend::in_config_dir().map(|s| Box::new(s) as Box<dyn Storage>);
+
+    storage.unwrap_or_else(|| {
+        log::warn!("Persistent storage unavailable, progress won't be kept");
+        Box::new(MemoryStorage::new())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(storage: &dyn Storage) {
+        assert_eq!(storage.get("k"), Ok(None));
+        storage.set("k", "{\"a\":1}").unwrap();
+        assert_eq!(storage.get("k"), Ok(Some("{\"a\":1}".into())));
+        storage.set("k", "2").unwrap();
+        assert_eq!(storage.get("k"), Ok(Some("2".into())));
+        storage.remove("k").unwrap();
+        assert_eq!(storage.get("k"), Ok(None));
+        storage.remove("k").unwrap();
+    }
+
+    #[test]
+    fn test_memory_storage() {
+        round_trip(&MemoryStorage::new());
+    }
+
+    #[test]
+    fn test_file_storage() {
+        let dir = std::env::temp_dir().join(format!("roto-pong-storage-{}", std::process::id()));
+        round_trip(&FileStorageBackend::new(&dir));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,86 @@
+//! Monotonic clock for the game loop
+//!
+//! Frame deltas must come from a clock that never jumps. `Date::now()` is
+//! wall-clock time and can step backward or forward when the system syncs
+//! with NTP, which shows up as a negative or giant dt (and a burst of
+//! clamped substeps). `performance.now()` and `Instant` are monotonic.
+
+/// Milliseconds since an arbitrary fixed start, never decreasing
+pub trait Clock {
+    fn now_ms(&self) -> f64;
+}
+
+/// The browser's `performance.now()`
+#[cfg(target_arch = "wasm32")]
+pub struct PerfClock(web_sys::Performance);
+
+#[cfg(target_arch = "wasm32")]
+impl PerfClock {
+    /// Performance timer of the current window, if there is one
+    pub fn new() -> Option<Self> {
+        web_sys::window()?.performance().map(Self)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Clock for PerfClock {
+    fn now_ms(&self) -> f64 {
+        self.0.now()
+    }
+}
+
+/// `std::time::Instant`, counting from when the clock was made
+#[cfg(not(target_arch = "wasm32"))]
+pub struct InstantClock(std::time::Instant);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl InstantClock {
+    pub fn new() -> Self {
+        Self(std::time::Instant::now())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for InstantClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Clock for InstantClock {
+    fn now_ms(&self) -> f64 {
+        self.0.elapsed().as_secs_f64() * 1000.0
+    }
+}
+
+/// The platform's monotonic clock
+#[cfg(target_arch = "wasm32")]
+pub fn default_clock() -> Box<dyn Clock> {
+    Box::new(PerfClock::new().expect("performance.now() unavailable"))
+}
+
+/// The platform's monotonic clock
+#[cfg(not(target_arch = "wasm32"))]
+pub fn default_clock() -> Box<dyn Clock> {
+    Box::new(InstantClock::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instant_clock_is_monotonic() {
+        let clock = InstantClock::new();
+        let mut last = clock.now_ms();
+        assert!(last >= 0.0);
+        for _ in 0..1000 {
+            let now = clock.now_ms();
+            assert!(now >= last);
+            last = now;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        assert!(clock.now_ms() - last >= 5.0);
+    }
+}
@@ -3,18 +3,51 @@
 //! Renders the entire scene in fragment shader using signed distance fields.
 
 use bytemuck::{Pod, Zeroable};
+use glam::Vec2;
 use wgpu::util::DeviceExt;
 
 use crate::consts::*;
-use crate::settings::Settings;
-use crate::sim::GameState;
+use crate::settings::{Settings, ZoomMode};
+use crate::sim::{Block, GamePhase, GameState, preview_wave};
+use crate::tuning::params::{MERCY_TICKS, SHOCKWAVE_MAX_RADIUS, WIND_ACCEL};
 
-/// Maximum number of balls supported
-const MAX_BALLS: usize = 8;
 /// Maximum number of trail points
 const MAX_TRAIL: usize = 256; // 8 balls * 32 points each
+/// Trail points always uploaded per ball (newest first), regardless of LOD
+const TRAIL_LOD_HEAD: usize = 4;
+/// Minimum spacing between uploaded trail points at zoom 1.0 (world units)
+const TRAIL_LOD_SPACING: f32 = 1.5;
+/// Distance outside the loss radius at which the danger vignette starts
+const DANGER_RANGE: f32 = 35.0;
+/// Angle wind dust travels over half a wind cycle (radians)
+const WIND_DUST_DRIFT: f32 = 0.6;
+/// `Globals::danger_style` bit: draw the vignette without pulsing
+const DANGER_STYLE_STATIC: u32 = 1;
+/// `Globals::danger_style` bit: draw a solid border instead of a soft tint
+const DANGER_STYLE_HIGH_CONTRAST: u32 = 2;
+/// MultiBall body colors, picked by ball id (Okabe-Ito, colorblind safe).
+/// Ordered so neighbors alternate between bright and dark.
+const BALL_PALETTE: [[f32; 3]; 8] = [
+    [0.94, 0.89, 0.26], // Yellow
+    [0.0, 0.45, 0.70],  // Blue
+    [0.95, 0.95, 0.95], // White
+    [0.84, 0.37, 0.0],  // Vermillion
+    [0.34, 0.71, 0.91], // Sky blue
+    [0.0, 0.62, 0.45],  // Bluish green
+    [0.90, 0.62, 0.0],  // Orange
+    [0.80, 0.47, 0.65], // Reddish purple
+];
+/// Brightness of the dark palette slots in high contrast mode
+const BALL_PALETTE_DIM: f32 = 0.6;
+/// Ball spin (rad/s) at which the spin marker is fully visible
+const SPIN_VISIBLE: f32 = 4.0;
+/// Furthest the drawn paddle may lead the simulated one, in ticks of
+/// travel at the paddle's max speed
+const PADDLE_LEAD_TICKS: f32 = 2.0;
 /// Maximum number of blocks
 const MAX_BLOCKS: usize = 256;
+/// Most HP pips drawn on one block
+const MAX_HP_PIPS: u32 = 12;
 /// Maximum number of particles
 const MAX_PARTICLES: usize = 256;
 
@@ -25,22 +58,33 @@ const MAX_PARTICLES: usize = 256;
 #[repr(C)]
 #[derive(Copy, Clone, Pod, Zeroable)]
 struct Globals {
-    resolution: [f32; 2],   // offset 0
-    time: f32,              // offset 8
-    arena_radius: f32,      // offset 12
-    black_hole_radius: f32, // offset 16
-    ball_count: u32,        // offset 20
-    block_count: u32,       // offset 24
-    trail_count: u32,       // offset 28
-    particle_count: u32,    // offset 32
-    _pad1: u32,             // offset 36 - align camera_pos to 8 bytes
-    camera_pos: [f32; 2],   // offset 40 (8-byte aligned for WGSL vec2)
-    camera_zoom: f32,       // offset 48
-    screen_shake: f32,      // offset 52
-    pickup_count: u32,      // offset 56
-    shield_active: u32,     // offset 60 - 1 if shield active, 0 otherwise
-    wave_flash: f32,        // offset 64 - wave clear flash effect
-    _pad2: [u32; 3],        // pad to 80 bytes for alignment
+    resolution: [f32; 2],     // offset 0
+    time: f32,                // offset 8
+    arena_radius: f32,        // offset 12
+    black_hole_radius: f32,   // offset 16
+    ball_count: u32,          // offset 20
+    block_count: u32,         // offset 24
+    trail_count: u32,         // offset 28
+    particle_count: u32,      // offset 32
+    lensing: u32,             // offset 36 - black hole lensing level (0 = off, 2 = High)
+    camera_pos: [f32; 2],     // offset 40 (8-byte aligned for WGSL vec2)
+    camera_zoom: f32,         // offset 48
+    screen_shake: f32,        // offset 52
+    pickup_count: u32,        // offset 56
+    shield_active: u32,       // offset 60 - 1 if shield active, 0 otherwise
+    wave_flash: f32,          // offset 64 - wave clear flash effect
+    inner_wall_radius: f32,   // offset 68 - inner ring wall (0 = none)
+    inner_wall_rotation: f32, // offset 72 - first opening center
+    inner_wall_gaps: u32,     // offset 76 - opening count
+    ball_color: [f32; 4],     // offset 80 - rgb + flag (a=0: speed color)
+    danger: f32,              // offset 96 - danger vignette urgency (0-1)
+    danger_style: u32,        // offset 100 - DANGER_STYLE_* bits
+    wave_modifiers: u32,      // offset 104 - chaos modifier bits (WaveModifiers)
+    wind: f32,                // offset 108 - current wind (-1..1 of peak)
+    wind_drift: f32,          // offset 112 - angle the wind has carried dust (radians)
+    shockwave_count: u32,     // offset 116
+    preview_count: u32,       // offset 120 - predicted path dots
+    background_grid: u32,     // offset 124 - 1 to draw the polar grid, total 128 bytes
 }
 
 #[repr(C)]
@@ -50,6 +94,7 @@ struct PaddleUniform {
     arc_width: f32,
     radius: f32,
     thickness: f32,
+    color: [f32; 4], // rgb + flag (a=0: classic gradient)
 }
 
 #[repr(C)]
@@ -60,7 +105,11 @@ struct BallData {
     speed: f32,
     sliding_block_id: u32, // 0 = not sliding, else = portal block ID
     electric_charge: f32,  // 0-1 electric charge for visual effect
-    _pad: [u32; 2],        // Pad to 32 bytes for alignment
+    mercy: f32,            // 0-1 post-respawn mercy left (shimmer)
+    spin_angle: f32,       // Spin marker rotation (radians)
+    color: [f32; 4],       // MultiBall body color: rgb + flag (a=0: shared ball color)
+    spin_fade: f32,        // 0-1 spin marker visibility (0 = not spinning)
+    _pad: [u32; 3],        // Pad to a 16-byte multiple
 }
 
 #[repr(C)]
@@ -77,7 +126,7 @@ struct BlockData {
     visibility: f32, // Ghost block visibility (0-1)
     pole_flags: u32, // Magnet: bit0=red_active, bit1=silver_active
     ring_id: u32,    // Ring/layer index (for electric arc connections)
-    _pad3: u32,
+    pips: u32,       // HP pips to draw (0 = none)
 }
 
 #[repr(C)]
@@ -107,10 +156,63 @@ const MAX_PICKUPS: usize = 16;
 #[derive(Copy, Clone, Pod, Zeroable)]
 struct PickupData {
     pos: [f32; 2],
-    kind: u32,      // 0=MultiBall, 1=Slow, 2=Piercing, 3=Widen, 4=Shield
+    kind: u32,      // 0=MultiBall, 1=Slow, 2=Piercing, 3=Widen, 4=Shield, 5=Freeze
     ttl_ratio: f32, // 0-1, for pulsing effect
 }
 
+/// Maximum explosion rings drawn at once
+const MAX_SHOCKWAVES: usize = 8;
+
+#[repr(C)]
+#[derive(Copy, Clone, Pod, Zeroable)]
+struct ShockwaveData {
+    center: [f32; 2],
+    radius: f32,
+    fade: f32, // 1 at detonation, 0 when fully spread
+}
+
+/// Maximum predicted path dots (all balls)
+const MAX_PREVIEW: usize = 64;
+/// Ticks of flight the path preview looks ahead (1 second)
+const PREVIEW_TICKS: u32 = 120;
+/// Ticks between consecutive path preview dots
+const PREVIEW_DOT_STEP: usize = 5;
+
+#[repr(C)]
+#[derive(Copy, Clone, Pod, Zeroable)]
+struct PreviewDot {
+    pos: [f32; 2],
+    alpha: f32, // Fades toward the end of the prediction
+    _pad: u32,
+}
+
+/// Opacity of the upcoming wave's blocks ghosted in during the breather
+const WAVE_PREVIEW_ALPHA: f32 = 0.25;
+
+/// Byte sizes of the per-frame buffers, in staging upload order:
+/// globals, paddle, balls, blocks, trail, particles, pickups, shockwaves, preview
+const STAGING_SIZES: [u64; 9] = [
+    std::mem::size_of::<Globals>() as u64,
+    std::mem::size_of::<PaddleUniform>() as u64,
+    (std::mem::size_of::<BallData>() * MAX_BALLS) as u64,
+    (std::mem::size_of::<BlockData>() * MAX_BLOCKS) as u64,
+    (std::mem::size_of::<TrailPoint>() * MAX_TRAIL) as u64,
+    (std::mem::size_of::<ParticleData>() * MAX_PARTICLES) as u64,
+    (std::mem::size_of::<PickupData>() * MAX_PICKUPS) as u64,
+    (std::mem::size_of::<ShockwaveData>() * MAX_SHOCKWAVES) as u64,
+    (std::mem::size_of::<PreviewDot>() * MAX_PREVIEW) as u64,
+];
+/// Total size of the staging upload
+const STAGING_SIZE: u64 = STAGING_SIZES[0]
+    + STAGING_SIZES[1]
+    + STAGING_SIZES[2]
+    + STAGING_SIZES[3]
+    + STAGING_SIZES[4]
+    + STAGING_SIZES[5]
+    + STAGING_SIZES[6]
+    + STAGING_SIZES[7]
+    + STAGING_SIZES[8];
+
 // ============================================================================
 // SDF RENDER STATE
 // ============================================================================
@@ -130,6 +232,26 @@ pub struct SdfRenderState {
     trail_buffer: wgpu::Buffer,
     particles_buffer: wgpu::Buffer,
     pickups_buffer: wgpu::Buffer,
+    shockwaves_buffer: wgpu::Buffer,
+    preview_buffer: wgpu::Buffer,
+
+    // Per-frame upload: written once, then copied into the buffers above
+    staging_buffer: wgpu::Buffer,
+    staging_bytes: Vec<u8>,
+
+    // Per-frame scratch arrays, reused to avoid allocating every frame.
+    // Live entries are overwritten and the tails zeroed each frame.
+    trail_scratch: Vec<TrailPoint>,
+    balls_scratch: Vec<BallData>,
+    blocks_scratch: Vec<BlockData>,
+    particles_scratch: Vec<ParticleData>,
+    pickups_scratch: Vec<PickupData>,
+    shockwaves_scratch: Vec<ShockwaveData>,
+    preview_scratch: Vec<PreviewDot>,
+
+    /// Upcoming wave's blocks for the breather preview, built once per
+    /// breather and keyed by (seed, wave)
+    next_wave: Option<((u64, u32), Vec<Block>)>,
 
     bind_group: wgpu::BindGroup,
 
@@ -212,14 +334,25 @@ impl SdfRenderState {
                 block_count: 0,
                 trail_count: 0,
                 particle_count: 0,
-                _pad1: 0,
+                lensing: 0,
                 camera_pos: [0.0, 0.0],
                 camera_zoom: 1.0,
                 screen_shake: 0.0,
                 pickup_count: 0,
                 shield_active: 0,
                 wave_flash: 0.0,
-                _pad2: [0; 3],
+                inner_wall_radius: 0.0,
+                inner_wall_rotation: 0.0,
+                inner_wall_gaps: 0,
+                ball_color: [0.0; 4],
+                danger: 0.0,
+                danger_style: 0,
+                wave_modifiers: 0,
+                wind: 0.0,
+                wind_drift: 0.0,
+                shockwave_count: 0,
+                preview_count: 0,
+                background_grid: 0,
             }),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
@@ -231,6 +364,7 @@ impl SdfRenderState {
                 arc_width: PADDLE_ARC_WIDTH,
                 radius: PADDLE_RADIUS,
                 thickness: PADDLE_THICKNESS,
+                color: [0.0; 4],
             }),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
@@ -270,6 +404,27 @@ impl SdfRenderState {
             mapped_at_creation: false,
         });
 
+        let shockwaves_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("shockwaves"),
+            size: (std::mem::size_of::<ShockwaveData>() * MAX_SHOCKWAVES) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let preview_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("preview"),
+            size: (std::mem::size_of::<PreviewDot>() * MAX_PREVIEW) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let staging_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("staging"),
+            size: STAGING_SIZE,
+            usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // Bind group layout
         let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("sdf_bind_group_layout"),
@@ -344,6 +499,26 @@ impl SdfRenderState {
                     },
                     count: None,
                 },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 8,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -379,6 +554,14 @@ impl SdfRenderState {
                     binding: 6,
                     resource: pickups_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: shockwaves_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 8,
+                    resource: preview_buffer.as_entire_binding(),
+                },
             ],
         });
 
@@ -430,6 +613,18 @@ impl SdfRenderState {
             trail_buffer,
             particles_buffer,
             pickups_buffer,
+            shockwaves_buffer,
+            preview_buffer,
+            staging_buffer,
+            staging_bytes: Vec::with_capacity(STAGING_SIZE as usize),
+            trail_scratch: vec![TrailPoint::zeroed(); MAX_TRAIL],
+            balls_scratch: vec![BallData::zeroed(); MAX_BALLS],
+            blocks_scratch: vec![BlockData::zeroed(); MAX_BLOCKS],
+            particles_scratch: vec![ParticleData::zeroed(); MAX_PARTICLES],
+            pickups_scratch: vec![PickupData::zeroed(); MAX_PICKUPS],
+            shockwaves_scratch: vec![ShockwaveData::zeroed(); MAX_SHOCKWAVES],
+            preview_scratch: vec![PreviewDot::zeroed(); MAX_PREVIEW],
+            next_wave: None,
             bind_group,
             size: (width, height),
             start_time: 0.0,
@@ -451,51 +646,133 @@ impl SdfRenderState {
         self.start_time = time;
     }
 
-    /// Update GPU buffers from game state and render
+    /// Update GPU buffers from game state and render. `target_theta` is the
+    /// latest paddle input, not yet ticked (used for latency compensation).
     pub fn render(
         &mut self,
         state: &GameState,
         settings: &Settings,
+        target_theta: Option<f32>,
         time: f64,
     ) -> Result<(), wgpu::SurfaceError> {
         // time is ms since page load from requestAnimationFrame, convert to seconds
         let elapsed = (time / 1000.0) as f32;
 
-        let ball_count = state.balls.len().min(MAX_BALLS) as u32;
-        let block_count = state.blocks.len().min(MAX_BLOCKS) as u32;
-
-        // Apply settings for trails
-        let trail_count = if settings.trails {
-            let quality_factor = settings.quality.trail_quality();
-            let raw_count = state.balls.iter().map(|b| b.trail.len()).sum::<usize>();
-            ((raw_count as f32 * quality_factor) as usize).min(MAX_TRAIL) as u32
-        } else {
-            0
+        // During the breather the next wave is ghosted in where it will appear
+        let breather = state.phase == GamePhase::Breather;
+        if breather {
+            let key = (state.seed, state.wave_index);
+            if self.next_wave.as_ref().is_none_or(|(k, _)| *k != key) {
+                self.next_wave = Some((key, preview_wave(state)));
+            }
+        }
+        let (blocks, block_alpha) = match &self.next_wave {
+            Some((_, preview)) if breather => (preview.as_slice(), WAVE_PREVIEW_ALPHA),
+            _ => (state.blocks.as_slice(), 1.0),
         };
 
+        let ball_count = state.balls.len().min(MAX_BALLS) as u32;
+        let block_count = blocks.len().min(MAX_BLOCKS) as u32;
+
         // Apply settings for particles
         let max_particles = settings.max_particles().min(MAX_PARTICLES);
-        let particle_count = state.particles.len().min(max_particles) as u32;
+        let show_scorch = settings.effective_scorch_marks();
+
+        // Update particles (scorch marks hidden when effects are reduced)
+        let visible_particles = state
+            .particles
+            .iter()
+            .filter(|p| show_scorch || !p.is_scorch())
+            .take(max_particles);
+        let mut live_particles = 0;
+        for (i, particle) in visible_particles.enumerate() {
+            live_particles = i + 1;
+            self.particles_scratch[i] = ParticleData {
+                pos: [particle.pos.x, particle.pos.y],
+                size: particle.size,
+                life: particle.life,
+                color: particle.color,
+                vel_x: particle.vel.x,
+                vel_y: particle.vel.y,
+                _pad3: 0,
+            };
+        }
+        self.particles_scratch[live_particles..].fill(ParticleData::zeroed());
+        let particle_count = live_particles as u32;
         let pickup_count = state.pickups.len().min(MAX_PICKUPS) as u32;
+        let shockwave_count = state.shockwaves.len().min(MAX_SHOCKWAVES) as u32;
 
         // Camera zoom - adjusts to fit larger arenas
         // Base viewport shows arena radius * 1.1 (440px at base 400)
         // When arena grows, zoom out to keep everything visible
-        let base_arena = 400.0;
-        let base_viewport = base_arena * 1.1;
-
-        // Calculate target zoom to fit current arena
-        let target_zoom = state.arena_radius * 1.1 / base_viewport;
+        let target_zoom = settings.zoom_mode.target_zoom(state.arena_radius);
 
         // Smooth zoom transitions
         let dt = 1.0 / 60.0;
         let zoom_smooth = 2.0;
         self.camera_zoom += (target_zoom - self.camera_zoom) * zoom_smooth * dt;
-        self.camera_zoom = self.camera_zoom.clamp(1.0, 2.0);
+        self.camera_zoom = self
+            .camera_zoom
+            .clamp(ZoomMode::MIN_ZOOM, ZoomMode::MAX_ZOOM);
 
         // Keep camera centered (arena is circular, no need to follow ball)
         self.camera_pos = [0.0, 0.0];
 
+        // Update trail (with level of detail)
+        // Points closer than the minimum spacing to the last kept point are
+        // skipped: slow balls leave dense trails, and when zoomed out the
+        // extra points are sub-pixel anyway. The head is always kept.
+        let trail_points = settings.trail_points(state.tuning.trail_length);
+        let min_spacing = TRAIL_LOD_SPACING * self.camera_zoom;
+        let mut trail_idx = 0;
+        'balls: for ball in &state.balls {
+            let shown = ball.trail.len().min(trail_points);
+            let mut last_kept: Option<glam::Vec2> = None;
+            for (i, point) in ball.trail.iter().take(shown).enumerate() {
+                if trail_idx >= MAX_TRAIL {
+                    break 'balls;
+                }
+                if i >= TRAIL_LOD_HEAD
+                    && last_kept
+                        .is_some_and(|p| p.distance_squared(point.pos) < min_spacing * min_spacing)
+                {
+                    continue;
+                }
+                last_kept = Some(point.pos);
+                let alpha = 1.0 - (i as f32 / shown.max(1) as f32);
+                self.trail_scratch[trail_idx] = TrailPoint {
+                    pos: [point.pos.x, point.pos.y],
+                    speed: point.speed,
+                    alpha,
+                };
+                trail_idx += 1;
+            }
+        }
+        self.trail_scratch[trail_idx..].fill(TrailPoint::zeroed());
+        let trail_count = trail_idx as u32;
+
+        // Predicted path of each free ball, as a dotted line
+        let mut preview_idx = 0;
+        if settings.path_preview {
+            'preview: for ball in &state.balls {
+                let path = crate::sim::predict_path(state, ball.id, PREVIEW_TICKS);
+                let dots = path.iter().enumerate().skip(PREVIEW_DOT_STEP - 1);
+                for (i, point) in dots.step_by(PREVIEW_DOT_STEP) {
+                    if preview_idx >= MAX_PREVIEW {
+                        break 'preview;
+                    }
+                    self.preview_scratch[preview_idx] = PreviewDot {
+                        pos: [point.x, point.y],
+                        alpha: 1.0 - i as f32 / PREVIEW_TICKS as f32,
+                        _pad: 0,
+                    };
+                    preview_idx += 1;
+                }
+            }
+        }
+        self.preview_scratch[preview_idx..].fill(PreviewDot::zeroed());
+        let preview_count = preview_idx as u32;
+
         // Apply settings to visual effects
         let effective_shake = if settings.effective_screen_shake() {
             state.screen_shake
@@ -508,6 +785,17 @@ impl SdfRenderState {
             0.0
         };
 
+        // Wind dust follows the integral of the wind, so it drifts one way,
+        // slows and comes back as the wind reverses
+        let (wind, wind_drift) = if state.wind_strength != 0.0 {
+            (
+                state.wind(),
+                (1.0 - state.wind_phase.cos()) * WIND_DUST_DRIFT,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
         // Update globals
         let globals = Globals {
             resolution: [self.size.0 as f32, self.size.1 as f32],
@@ -518,40 +806,46 @@ impl SdfRenderState {
             block_count,
             trail_count,
             particle_count,
-            _pad1: 0,
+            lensing: settings.quality.lensing_level(),
             camera_pos: self.camera_pos,
             camera_zoom: self.camera_zoom,
             screen_shake: effective_shake,
             pickup_count,
             shield_active: if state.effects.shield_active { 1 } else { 0 },
             wave_flash: effective_flash,
-            _pad2: [0; 3],
+            inner_wall_radius: state.inner_wall.as_ref().map_or(0.0, |w| w.radius),
+            inner_wall_rotation: state.inner_wall.as_ref().map_or(0.0, |w| w.rotation),
+            inner_wall_gaps: state.inner_wall.as_ref().map_or(0, |w| w.gaps),
+            ball_color: settings.ball_color.to_gpu(),
+            danger: danger_urgency(state),
+            danger_style: danger_style(settings),
+            wave_modifiers: state.wave_modifiers.0,
+            wind: wind / WIND_ACCEL,
+            wind_drift,
+            shockwave_count,
+            preview_count,
+            background_grid: settings.background_grid as u32,
         };
-        self.queue
-            .write_buffer(&self.globals_buffer, 0, bytemuck::bytes_of(&globals));
 
         // Update paddle
+        let max_lead = state.tuning.paddle_max_speed * SIM_DT * PADDLE_LEAD_TICKS;
         let paddle = PaddleUniform {
-            theta: state.paddle.theta,
+            theta: paddle_draw_theta(
+                state.paddle.theta,
+                target_theta,
+                settings.paddle_lead,
+                max_lead,
+            ),
             arc_width: state.paddle.arc_width,
             radius: PADDLE_RADIUS,
             thickness: PADDLE_THICKNESS,
+            color: settings.paddle_color.to_gpu(),
         };
-        self.queue
-            .write_buffer(&self.paddle_buffer, 0, bytemuck::bytes_of(&paddle));
-
-        // Update balls
-        let mut balls_data = vec![
-            BallData {
-                pos: [0.0; 2],
-                radius: 0.0,
-                speed: 0.0,
-                sliding_block_id: 0,
-                electric_charge: 0.0,
-                _pad: [0; 2]
-            };
-            MAX_BALLS
-        ];
+
+        // Update balls (every ball shimmers while the mercy window lasts).
+        // With more than one ball in play each gets its own body color.
+        let mercy = state.mercy_ticks as f32 / MERCY_TICKS as f32;
+        let multiball = state.balls.len() > 1;
         for (i, ball) in state.balls.iter().take(MAX_BALLS).enumerate() {
             let sliding_block_id =
                 if let crate::sim::BallState::Sliding { block_id, .. } = ball.state {
@@ -559,89 +853,34 @@ impl SdfRenderState {
                 } else {
                     0
                 };
-            balls_data[i] = BallData {
+            self.balls_scratch[i] = BallData {
                 pos: [ball.pos.x, ball.pos.y],
                 radius: ball.radius,
                 speed: ball.vel.length(),
                 sliding_block_id,
                 electric_charge: ball.electric_charge,
-                _pad: [0; 2],
+                mercy,
+                spin_angle: ball.spin_angle,
+                color: if multiball {
+                    ball_tint(ball.id, settings.high_contrast)
+                } else {
+                    [0.0; 4]
+                },
+                spin_fade: if settings.reduced_motion {
+                    0.0
+                } else {
+                    (ball.spin.abs() / SPIN_VISIBLE).min(1.0)
+                },
+                _pad: [0; 3],
             };
         }
-        self.queue
-            .write_buffer(&self.balls_buffer, 0, bytemuck::cast_slice(&balls_data));
+        self.balls_scratch[ball_count as usize..].fill(BallData::zeroed());
 
         // Update blocks
-        let mut blocks_data = vec![
-            BlockData {
-                theta_start: 0.0,
-                theta_end: 0.0,
-                radius: 0.0,
-                thickness: 0.0,
-                kind: 0,
-                wobble: 0.0,
-                block_id: 0,
-                hp: 0,
-                visibility: 1.0,
-                pole_flags: 0,
-                ring_id: 0,
-                _pad3: 0,
-            };
-            MAX_BLOCKS
-        ];
-        for (i, block) in state.blocks.iter().take(MAX_BLOCKS).enumerate() {
-            let kind = match block.kind {
-                crate::sim::BlockKind::Glass => 0,
-                crate::sim::BlockKind::Armored => 1,
-                crate::sim::BlockKind::Explosive => 2,
-                crate::sim::BlockKind::Invincible => 3,
-                crate::sim::BlockKind::Portal { .. } => 4,
-                crate::sim::BlockKind::Jello => 5,
-                crate::sim::BlockKind::Crystal => 6,
-                crate::sim::BlockKind::Electric => 7,
-                crate::sim::BlockKind::Magnet => 8,
-                crate::sim::BlockKind::Ghost => 9,
-            };
-
-            // Compute pole_flags for magnet blocks (chain detection)
-            let mut pole_flags: u32 = 0b11; // Default: both ends active
-            if block.kind == crate::sim::BlockKind::Magnet {
-                let angle_tolerance = 0.15_f32;
-                let radius_tolerance = 5.0_f32;
-                let mut red_active = true;
-                let mut silver_active = true;
-
-                for other in &state.blocks {
-                    if other.id == block.id {
-                        continue;
-                    }
-                    if other.kind != crate::sim::BlockKind::Magnet {
-                        continue;
-                    }
-                    if (other.arc.radius - block.arc.radius).abs() > radius_tolerance {
-                        continue;
-                    }
+        for (i, block) in blocks.iter().take(MAX_BLOCKS).enumerate() {
+            let kind = block.kind.color_index();
 
-                    // Check if other's theta_end connects to our theta_start (red end)
-                    let diff_to_red = (other.arc.theta_end - block.arc.theta_start).abs();
-                    let tau = std::f32::consts::TAU;
-                    let diff_to_red_wrapped = (diff_to_red - tau).abs().min(diff_to_red);
-                    if diff_to_red_wrapped < angle_tolerance {
-                        red_active = false;
-                    }
-
-                    // Check if other's theta_start connects to our theta_end (silver end)
-                    let diff_to_silver = (other.arc.theta_start - block.arc.theta_end).abs();
-                    let diff_to_silver_wrapped = (diff_to_silver - tau).abs().min(diff_to_silver);
-                    if diff_to_silver_wrapped < angle_tolerance {
-                        silver_active = false;
-                    }
-                }
-
-                pole_flags = (if red_active { 1 } else { 0 }) | (if silver_active { 2 } else { 0 });
-            }
-
-            blocks_data[i] = BlockData {
+            self.blocks_scratch[i] = BlockData {
                 theta_start: block.arc.theta_start,
                 theta_end: block.arc.theta_end,
                 radius: block.arc.radius,
@@ -650,83 +889,17 @@ impl SdfRenderState {
                 wobble: block.wobble,
                 block_id: block.id,
                 hp: block.hp as u32,
-                visibility: block.visibility,
-                pole_flags,
+                visibility: block.visibility * block_alpha,
+                pole_flags: block.pole_flags,
                 ring_id: block.ring_id,
-                _pad3: 0,
-            };
-        }
-        self.queue
-            .write_buffer(&self.blocks_buffer, 0, bytemuck::cast_slice(&blocks_data));
-
-        // Update trail
-        let mut trail_data = vec![
-            TrailPoint {
-                pos: [0.0, 0.0],
-                speed: 0.0,
-                alpha: 0.0
-            };
-            MAX_TRAIL
-        ];
-        let mut trail_idx = 0;
-        for ball in &state.balls {
-            for (i, point) in ball.trail.iter().enumerate() {
-                if trail_idx >= MAX_TRAIL {
-                    break;
-                }
-                let alpha = 1.0 - (i as f32 / ball.trail.len().max(1) as f32);
-                trail_data[trail_idx] = TrailPoint {
-                    pos: [point.pos.x, point.pos.y],
-                    speed: point.speed,
-                    alpha,
-                };
-                trail_idx += 1;
-            }
-        }
-        self.queue
-            .write_buffer(&self.trail_buffer, 0, bytemuck::cast_slice(&trail_data));
-
-        // Update particles
-        let mut particles_data = vec![
-            ParticleData {
-                pos: [0.0, 0.0],
-                size: 0.0,
-                life: 0.0,
-                color: 0,
-                vel_x: 0.0,
-                vel_y: 0.0,
-                _pad3: 0,
-            };
-            MAX_PARTICLES
-        ];
-        for (i, particle) in state.particles.iter().take(MAX_PARTICLES).enumerate() {
-            particles_data[i] = ParticleData {
-                pos: [particle.pos.x, particle.pos.y],
-                size: particle.size,
-                life: particle.life,
-                color: particle.color,
-                vel_x: particle.vel.x,
-                vel_y: particle.vel.y,
-                _pad3: 0,
+                pips: hp_pips(block, settings.hp_pips),
             };
         }
-        self.queue.write_buffer(
-            &self.particles_buffer,
-            0,
-            bytemuck::cast_slice(&particles_data),
-        );
+        self.blocks_scratch[block_count as usize..].fill(BlockData::zeroed());
 
         // Update pickups
-        let mut pickups_data = vec![
-            PickupData {
-                pos: [0.0, 0.0],
-                kind: 0,
-                ttl_ratio: 0.0,
-            };
-            MAX_PICKUPS
-        ];
         for (i, pickup) in state.pickups.iter().take(MAX_PICKUPS).enumerate() {
-            pickups_data[i] = PickupData {
+            self.pickups_scratch[i] = PickupData {
                 pos: [pickup.pos.x, pickup.pos.y],
                 kind: match pickup.kind {
                     crate::sim::PickupKind::MultiBall => 0,
@@ -734,12 +907,49 @@ impl SdfRenderState {
                     crate::sim::PickupKind::Piercing => 2,
                     crate::sim::PickupKind::WidenPaddle => 3,
                     crate::sim::PickupKind::Shield => 4,
+                    crate::sim::PickupKind::Freeze => 5,
+                    crate::sim::PickupKind::Sticky => 6,
+                    crate::sim::PickupKind::HeavyBall => 7,
+                    crate::sim::PickupKind::TimeWarp => 8,
                 },
                 ttl_ratio: pickup.ttl_ticks as f32 / 1200.0, // 10 seconds at 120Hz
             };
         }
+        self.pickups_scratch[pickup_count as usize..].fill(PickupData::zeroed());
+
+        // Update explosion rings
+        for (i, wave) in state.shockwaves.iter().take(MAX_SHOCKWAVES).enumerate() {
+            self.shockwaves_scratch[i] = ShockwaveData {
+                center: [wave.center.x, wave.center.y],
+                radius: wave.radius,
+                fade: (1.0 - wave.radius / SHOCKWAVE_MAX_RADIUS).clamp(0.0, 1.0),
+            };
+        }
+        self.shockwaves_scratch[shockwave_count as usize..].fill(ShockwaveData::zeroed());
+
+        // Pack everything into one staging upload (order must match STAGING_SIZES)
+        self.staging_bytes.clear();
+        self.staging_bytes
+            .extend_from_slice(bytemuck::bytes_of(&globals));
+        self.staging_bytes
+            .extend_from_slice(bytemuck::bytes_of(&paddle));
+        self.staging_bytes
+            .extend_from_slice(bytemuck::cast_slice(&self.balls_scratch));
+        self.staging_bytes
+            .extend_from_slice(bytemuck::cast_slice(&self.blocks_scratch));
+        self.staging_bytes
+            .extend_from_slice(bytemuck::cast_slice(&self.trail_scratch));
+        self.staging_bytes
+            .extend_from_slice(bytemuck::cast_slice(&self.particles_scratch));
+        self.staging_bytes
+            .extend_from_slice(bytemuck::cast_slice(&self.pickups_scratch));
+        self.staging_bytes
+            .extend_from_slice(bytemuck::cast_slice(&self.shockwaves_scratch));
+        self.staging_bytes
+            .extend_from_slice(bytemuck::cast_slice(&self.preview_scratch));
+        debug_assert_eq!(self.staging_bytes.len() as u64, STAGING_SIZE);
         self.queue
-            .write_buffer(&self.pickups_buffer, 0, bytemuck::cast_slice(&pickups_data));
+            .write_buffer(&self.staging_buffer, 0, &self.staging_bytes);
 
         // Render
         let output = self.surface.get_current_texture()?;
@@ -753,6 +963,24 @@ impl SdfRenderState {
                 label: Some("sdf_encoder"),
             });
 
+        // Scatter the staging upload into the bound buffers
+        let targets = [
+            &self.globals_buffer,
+            &self.paddle_buffer,
+            &self.balls_buffer,
+            &self.blocks_buffer,
+            &self.trail_buffer,
+            &self.particles_buffer,
+            &self.pickups_buffer,
+            &self.shockwaves_buffer,
+            &self.preview_buffer,
+        ];
+        let mut offset = 0;
+        for (target, size) in targets.into_iter().zip(STAGING_SIZES) {
+            encoder.copy_buffer_to_buffer(&self.staging_buffer, offset, target, 0, size);
+            offset += size;
+        }
+
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("sdf_render_pass"),
@@ -781,4 +1009,150 @@ impl SdfRenderState {
 
         Ok(())
     }
+
+    /// Where world `pos` was drawn last frame, as fractions (0-1) of the
+    /// surface width and height from its top-left corner. For placing DOM
+    /// overlays; ignores screen shake.
+    pub fn world_to_screen(&self, pos: Vec2) -> (f32, f32) {
+        world_to_screen(pos, self.size, self.camera_pos, self.camera_zoom)
+    }
+}
+
+/// Danger vignette urgency (0-1): how close the free ball nearest the black
+/// hole is to being lost. Zero while the shield would save it anyway.
+fn danger_urgency(state: &GameState) -> f32 {
+    if state.effects.shield_active || state.mercy_ticks > 0 {
+        return 0.0;
+    }
+    state
+        .free_balls()
+        .map(|ball| {
+            let margin = ball.pos.length() - ball.radius - BLACK_HOLE_LOSS_RADIUS;
+            1.0 - (margin / DANGER_RANGE).clamp(0.0, 1.0)
+        })
+        .fold(0.0, f32::max)
+}
+
+/// Danger vignette style bits for the accessibility settings
+fn danger_style(settings: &Settings) -> u32 {
+    let mut style = 0;
+    if settings.reduced_motion {
+        style |= DANGER_STYLE_STATIC;
+    }
+    if settings.high_contrast {
+        style |= DANGER_STYLE_HIGH_CONTRAST;
+    }
+    style
+}
+
+/// Inverse of the shader's pixel-to-world mapping (`fs_main`): world `pos`
+/// as fractions of a `size` surface, y pointing down
+fn world_to_screen(pos: Vec2, size: (u32, u32), camera_pos: [f32; 2], zoom: f32) -> (f32, f32) {
+    let aspect = size.0 as f32 / size.1.max(1) as f32;
+    let mut uv = (pos - Vec2::from(camera_pos)) / (ARENA_OUTER_RADIUS * 1.1 * zoom);
+    if aspect > 1.0 {
+        uv.x /= aspect;
+    } else {
+        uv.y *= aspect;
+    }
+    ((uv.x + 1.0) * 0.5, (1.0 - uv.y) * 0.5)
+}
+
+/// Angle to draw the paddle at: the ticked angle moved `lead` of the way
+/// toward the latest input target, by at most `max_lead` radians. Never
+/// passes the target, so it can't overshoot. Render-only.
+fn paddle_draw_theta(theta: f32, target: Option<f32>, lead: f32, max_lead: f32) -> f32 {
+    let Some(target) = target.filter(|t| t.is_finite()) else {
+        return theta;
+    };
+    let delta = crate::normalize_angle(target - theta);
+    let offset = (delta * lead.clamp(0.0, 1.0)).clamp(-max_lead, max_lead);
+    crate::normalize_angle(theta + offset)
+}
+
+/// HP pips shown on a block: one per hit left, for blocks that take more
+/// than one hit. Invincible blocks and bumpers have no meaningful HP.
+fn hp_pips(block: &crate::sim::Block, enabled: bool) -> u32 {
+    if !enabled || block.kind.is_indestructible() || block.hp <= 1 {
+        return 0;
+    }
+    (block.hp as u32).min(MAX_HP_PIPS)
+}
+
+/// MultiBall body color for a ball (flag set). High contrast dims every
+/// other palette slot so neighbors also differ clearly in brightness.
+fn ball_tint(id: u32, high_contrast: bool) -> [f32; 4] {
+    let slot = id as usize % BALL_PALETTE.len();
+    let [r, g, b] = BALL_PALETTE[slot];
+    let level = if high_contrast && slot % 2 == 1 {
+        BALL_PALETTE_DIM
+    } else {
+        1.0
+    };
+    [r * level, g * level, b * level, 1.0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paddle_lead_is_clamped() {
+        // Off, or no input: the simulated angle
+        assert_eq!(paddle_draw_theta(1.0, Some(1.5), 0.0, 0.2), 1.0);
+        assert_eq!(paddle_draw_theta(1.0, None, 1.0, 0.2), 1.0);
+        assert_eq!(paddle_draw_theta(1.0, Some(f32::NAN), 1.0, 0.2), 1.0);
+
+        // Partway toward a near target, never past it
+        assert!((paddle_draw_theta(1.0, Some(1.1), 0.5, 0.2) - 1.05).abs() < 1e-5);
+        assert!((paddle_draw_theta(1.0, Some(1.1), 1.0, 0.2) - 1.1).abs() < 1e-5);
+        // A far target only pulls the paddle ahead by max_lead
+        assert!((paddle_draw_theta(1.0, Some(2.5), 1.0, 0.2) - 1.2).abs() < 1e-5);
+
+        // Leads the short way around the wrap
+        let theta = paddle_draw_theta(3.1, Some(-3.1), 1.0, 0.2);
+        assert!(theta < -3.0, "{}", theta);
+    }
+
+    #[test]
+    fn test_world_to_screen() {
+        let at = |pos, size, zoom| world_to_screen(pos, size, [0.0, 0.0], zoom);
+        let viewport = ARENA_OUTER_RADIUS * 1.1;
+
+        assert_eq!(at(Vec2::ZERO, (800, 600), 1.0), (0.5, 0.5));
+        // Square surface: the base viewport spans the shorter side
+        assert_eq!(at(Vec2::new(viewport, 0.0), (600, 600), 1.0), (1.0, 0.5));
+        // Wide surface shows more world across; +y is up on screen
+        let (x, y) = at(Vec2::new(viewport, viewport), (1200, 600), 1.0);
+        assert!((x - 0.75).abs() < 1e-5 && y.abs() < 1e-5, "{x} {y}");
+        // Tall surface shows more world down; zooming out shrinks things
+        let (x, y) = at(Vec2::new(0.0, -viewport), (600, 1200), 2.0);
+        assert!(
+            (x - 0.5).abs() < 1e-5 && (y - 0.625).abs() < 1e-5,
+            "{x} {y}"
+        );
+    }
+
+    #[test]
+    fn test_high_contrast_ball_tints_differ_in_brightness() {
+        let luma = |c: [f32; 4]| 0.2126 * c[0] + 0.7152 * c[1] + 0.0722 * c[2];
+        for id in 0..BALL_PALETTE.len() as u32 {
+            let a = luma(ball_tint(id, true));
+            let b = luma(ball_tint(id + 1, true));
+            assert!(
+                (a - b).abs() > 0.25,
+                "ids {} and {}: {} vs {}",
+                id,
+                id + 1,
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn test_shader_ball_cap_matches_sim() {
+        let decl = format!("const MAX_BALLS: u32 = {}u;", MAX_BALLS);
+        assert!(include_str!("sdf_shader.wgsl").contains(&decl));
+    }
 }
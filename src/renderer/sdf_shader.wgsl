@@ -10,6 +10,10 @@ const MAX_BLOCKS: u32 = 256u;
 const MAX_TRAIL: u32 = 256u;
 const MAX_PARTICLES: u32 = 256u;
 
+// Inner ring wall shape (must match sim::state)
+const INNER_WALL_THICKNESS: f32 = 6.0;
+const INNER_WALL_GAP_WIDTH: f32 = 0.6;
+
 struct Globals {
     resolution: vec2<f32>,   // offset 0
     time: f32,               // offset 8
@@ -19,16 +23,25 @@ struct Globals {
     block_count: u32,        // offset 24
     trail_count: u32,        // offset 28
     particle_count: u32,     // offset 32
-    _pad1: u32,              // offset 36
+    lensing: u32,            // offset 36 - black hole lensing (0=off, 1=warp, 2=prism)
     camera_pos: vec2<f32>,   // offset 40 (8-byte aligned)
     camera_zoom: f32,        // offset 48
     screen_shake: f32,       // offset 52
     pickup_count: u32,       // offset 56
     shield_active: u32,      // offset 60
     wave_flash: f32,         // offset 64 - wave clear flash
-    _pad2a: u32,             // offset 68
-    _pad2b: u32,             // offset 72
-    _pad2c: u32,             // offset 76 - total 80 bytes
+    inner_wall_radius: f32,  // offset 68 - inner ring wall (0 = none)
+    inner_wall_rotation: f32, // offset 72 - first opening center
+    inner_wall_gaps: u32,    // offset 76 - opening count
+    ball_color: vec4<f32>,   // offset 80 - rgb + flag (a=0: speed color)
+    danger: f32,             // offset 96 - danger vignette urgency (0-1)
+    danger_style: u32,       // offset 100 - bit0: static, bit1: high contrast
+    wave_modifiers: u32,     // offset 104 - chaos bits (4 = invisible blocks)
+    wind: f32,               // offset 108 - current wind (-1..1 of peak)
+    wind_drift: f32,         // offset 112 - angle the wind has carried dust
+    shockwave_count: u32,    // offset 116
+    preview_count: u32,      // offset 120 - predicted path dots
+    background_grid: u32,    // offset 124 - 1 to draw the polar grid, total 128 bytes
 }
 
 struct Paddle {
@@ -36,6 +49,7 @@ struct Paddle {
     arc_width: f32,
     radius: f32,
     thickness: f32,
+    color: vec4<f32>,        // rgb + flag (a=0: classic gradient)
 }
 
 struct Ball {
@@ -44,8 +58,13 @@ struct Ball {
     speed: f32,
     sliding_block_id: u32,  // 0 = not sliding, else = portal block ID
     electric_charge: f32,   // 0-1 electric charge visual
-    _pad2: u32,
-    _pad3: u32,
+    mercy: f32,             // 0-1 post-respawn mercy left (shimmer)
+    spin_angle: f32,        // Spin marker rotation (radians)
+    color: vec4<f32>,       // MultiBall body color: rgb + flag (a=0: shared ball color)
+    spin_fade: f32,         // 0-1 spin marker visibility (0 = not spinning)
+    _pad4: u32,
+    _pad5: u32,
+    _pad6: u32,
 }
 
 struct Block {
@@ -60,7 +79,7 @@ struct Block {
     visibility: f32,
     pole_flags: u32,  // Magnet: bit0=red_active, bit1=silver_active
     ring_id: u32,     // Ring/layer index (for electric arc connections)
-    _pad3: u32,
+    pips: u32,        // HP pips to draw on the outer edge (0 = none)
 }
 
 struct TrailPoint {
@@ -83,10 +102,26 @@ const MAX_PICKUPS: u32 = 16u;
 
 struct Pickup {
     pos: vec2<f32>,
-    kind: u32,      // 0=MultiBall, 1=Slow, 2=Piercing, 3=Widen, 4=Shield
+    kind: u32,      // 0=MultiBall, 1=Slow, 2=Piercing, 3=Widen, 4=Shield, 5=Freeze
     ttl_ratio: f32, // 0-1, for pulsing effect
 }
 
+const MAX_SHOCKWAVES: u32 = 8u;
+
+struct Shockwave {
+    center: vec2<f32>,
+    radius: f32,
+    fade: f32,      // 1 at detonation, 0 when fully spread
+}
+
+const MAX_PREVIEW: u32 = 64u;
+
+struct PreviewDot {
+    pos: vec2<f32>,
+    alpha: f32,     // Fades toward the end of the prediction
+    _pad: u32,
+}
+
 @group(0) @binding(0) var<uniform> globals: Globals;
 @group(0) @binding(1) var<uniform> paddle: Paddle;
 @group(0) @binding(2) var<storage, read> balls: array<Ball, MAX_BALLS>;
@@ -94,6 +129,8 @@ struct Pickup {
 @group(0) @binding(4) var<storage, read> trail: array<TrailPoint, MAX_TRAIL>;
 @group(0) @binding(5) var<storage, read> particles: array<Particle, MAX_PARTICLES>;
 @group(0) @binding(6) var<storage, read> pickups: array<Pickup, MAX_PICKUPS>;
+@group(0) @binding(7) var<storage, read> shockwaves: array<Shockwave, MAX_SHOCKWAVES>;
+@group(0) @binding(8) var<storage, read> preview: array<PreviewDot, MAX_PREVIEW>;
 
 // ============================================================================
 // SDF PRIMITIVES
@@ -111,6 +148,25 @@ fn sdRing(p: vec2<f32>, inner: f32, outer: f32) -> f32 {
     return max(inner - d, d - outer);
 }
 
+// Ring wall with evenly spaced rounded openings (matches sim::sdf::sd_inner_wall)
+fn sdInnerWall(p: vec2<f32>, radius: f32, gaps: u32, rotation: f32) -> f32 {
+    let half_thick = INNER_WALL_THICKNESS * 0.5;
+    let ring = abs(sdCircle(p, radius)) - half_thick;
+    if (gaps == 0u) { return ring; }
+    
+    let sector = TAU / f32(gaps);
+    let angle = atan2(p.y, p.x) - rotation;
+    let nearest = round(angle / sector) * sector;
+    let offset = angle - nearest;
+    let half_gap = INNER_WALL_GAP_WIDTH * 0.5;
+    if (abs(offset) >= half_gap) { return ring; }
+    
+    var edge = rotation + nearest + half_gap;
+    if (offset < 0.0) { edge = rotation + nearest - half_gap; }
+    let cap = vec2<f32>(cos(edge), sin(edge)) * radius;
+    return length(p - cap) - half_thick;
+}
+
 // Arc SDF - simplified for performance
 fn sdArc(p: vec2<f32>, theta_start: f32, theta_end: f32, radius: f32, thickness: f32) -> f32 {
     let r = length(p);
@@ -178,6 +234,69 @@ fn fbm(p: vec2<f32>) -> f32 {
     return value;
 }
 
+// Starfield backdrop - bent around the black hole
+// Gravitational lensing: where the backdrop seen at p actually comes from.
+// Light passing at distance r is bent toward the hole by einstein^2 / r, so
+// sources behind the hole smear into an Einstein ring around it. Only the
+// backdrop is warped; gameplay geometry (paddle, balls, hole edge) is not.
+fn lensBackdrop(p: vec2<f32>, hole_radius: f32, strength: f32) -> vec2<f32> {
+    let r = length(p);
+    if (r < 0.001) {
+        return p;
+    }
+    let einstein = hole_radius * 1.6 * strength;
+    let bend = einstein * einstein / max(r, hole_radius);
+    return p - (p / r) * bend;
+}
+
+// Two parallax star layers sampled at a backdrop position
+fn starfield(backdrop_uv: vec2<f32>) -> vec3<f32> {
+    let drift_dir = vec2<f32>(1.0, 0.3);
+    
+    // Layer 1: far stars (unique offset for different pattern)
+    let star_uv1 = backdrop_uv + drift_dir * globals.time * 0.004;
+    let star_grid1 = floor(star_uv1 * 150.0);
+    let star1 = hash(star_grid1 + vec2<f32>(0.0, 0.0)); // Base pattern
+    
+    // Layer 2: near stars (different grid size + large offset = completely different pattern)
+    let star_uv2 = backdrop_uv * 1.5 + drift_dir * globals.time * 0.01;
+    let star_grid2 = floor(star_uv2 * 100.0);
+    let star2 = hash(star_grid2 + vec2<f32>(1337.0, 7919.0)); // Offset for different pattern
+    
+    let star_bright1 = step(0.985, star1) * 0.15;
+    let star_bright2 = step(0.98, star2) * 0.1;
+    
+    // Simple twinkle
+    let twinkle1 = sin(globals.time * 1.5 + star1 * 100.0) * 0.15 + 0.85;
+    let twinkle2 = sin(globals.time * 1.8 + star2 * 60.0) * 0.12 + 0.88;
+    
+    return vec3<f32>(0.9, 0.95, 1.0) * star_bright1 * twinkle1
+        + vec3<f32>(0.7, 0.85, 1.0) * star_bright2 * twinkle2;
+}
+
+// Wind dust - motes in concentric bands carried around the arena by the
+// wind, streaked along their motion while it blows hard
+fn windDust(p: vec2<f32>) -> f32 {
+    let r = length(p);
+    let band = floor(r / 30.0);
+    // Outer bands lag a little so the field shears instead of rotating rigidly
+    let speed = 0.7 + hash(vec2<f32>(band, 3.0)) * 0.6;
+    let a = atan2(p.y, p.x) - globals.wind_drift * speed;
+    let cells = max(floor(r * 6.2832 / 45.0), 1.0);
+    let cell_f = (a / 6.2832 + 0.5) * cells;
+    let cell = floor(cell_f);
+    let h = hash(vec2<f32>(band, cell));
+    if (h < 0.55) {
+        return 0.0;
+    }
+    // Mote position inside its cell, stretched along the tangent
+    let along = (fract(cell_f) - 0.5) * 45.0;
+    let across = r - (band + 0.2 + fract(h * 17.0) * 0.6) * 30.0;
+    let stretch = 1.0 + abs(globals.wind) * 4.0;
+    let d = length(vec2<f32>(along / stretch, across));
+    return (1.0 - smoothstep(0.6, 1.6, d)) * (h - 0.55) * 2.2;
+}
+
 // M87-style black hole - asymmetric photon ring
 fn blackHoleSwirl(p: vec2<f32>, hole_radius: f32) -> vec3<f32> {
     let r = length(p);
@@ -240,6 +359,13 @@ fn velocityColor(speed: f32) -> vec3<f32> {
     }
 }
 
+// Rotate a color's hue by `angle` radians around the gray axis
+fn hueShift(color: vec3<f32>, angle: f32) -> vec3<f32> {
+    let k = vec3<f32>(0.57735, 0.57735, 0.57735);
+    let c = cos(angle);
+    return color * c + cross(k, color) * sin(angle) + k * dot(k, color) * (1.0 - c);
+}
+
 // ============================================================================
 // VERTEX & FRAGMENT SHADERS
 // ============================================================================
@@ -337,34 +463,47 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     var color = vec3<f32>(0.01, 0.01, 0.03);
     let aa = 2.5; // Anti-aliasing
     
-    // Starfield backdrop - 2 independent random layers
-    let backdrop_uv = p / 500.0;
-    let drift_dir = vec2<f32>(1.0, 0.3);
-    
-    // Layer 1: far stars (unique offset for different pattern)
-    let star_uv1 = backdrop_uv + drift_dir * globals.time * 0.004;
-    let star_grid1 = floor(star_uv1 * 150.0);
-    let star1 = hash(star_grid1 + vec2<f32>(0.0, 0.0)); // Base pattern
-    
-    // Layer 2: near stars (different grid size + large offset = completely different pattern)
-    let star_uv2 = backdrop_uv * 1.5 + drift_dir * globals.time * 0.01;
-    let star_grid2 = floor(star_uv2 * 100.0);
-    let star2 = hash(star_grid2 + vec2<f32>(1337.0, 7919.0)); // Offset for different pattern
-    
-    let star_bright1 = step(0.985, star1) * 0.15;
-    let star_bright2 = step(0.98, star2) * 0.1;
-    
-    // Simple twinkle
-    let twinkle1 = sin(globals.time * 1.5 + star1 * 100.0) * 0.15 + 0.85;
-    let twinkle2 = sin(globals.time * 1.8 + star2 * 60.0) * 0.12 + 0.88;
+    // Starfield backdrop - 2 independent random layers, lensed by the black hole
+    var backdrop_p = p;
+    if (globals.lensing > 0u) {
+        backdrop_p = lensBackdrop(p, globals.black_hole_radius, 1.0);
+    }
+    let backdrop_uv = backdrop_p / 500.0;
     
-    color += vec3<f32>(0.9, 0.95, 1.0) * star_bright1 * twinkle1;
-    color += vec3<f32>(0.7, 0.85, 1.0) * star_bright2 * twinkle2;
+    if (globals.lensing > 1u) {
+        // Prism split: each channel bends by a slightly different amount
+        let uv_r = lensBackdrop(p, globals.black_hole_radius, 0.96) / 500.0;
+        let uv_b = lensBackdrop(p, globals.black_hole_radius, 1.04) / 500.0;
+        color += vec3<f32>(starfield(uv_r).r, starfield(backdrop_uv).g, starfield(uv_b).b);
+    } else {
+        color += starfield(backdrop_uv);
+    }
     
     // Simple nebula (single noise sample, no FBM)
     let nebula_uv = backdrop_uv * 0.8 + vec2<f32>(globals.time * 0.003, globals.time * 0.002);
     let nebula = noise(nebula_uv * 2.0) * 0.025;
     color += vec3<f32>(0.12, 0.06, 0.18) * nebula;
+
+    // Wind dust (Wind chaos wave only), brighter while the wind is strong
+    if ((globals.wave_modifiers & 8u) != 0u && length(p_dist) < globals.arena_radius) {
+        let dust = windDust(p_dist);
+        color += vec3<f32>(0.6, 0.7, 0.8) * dust * (0.12 + abs(globals.wind) * 0.25);
+    }
+    
+    // Polar grid - faint rings and spokes for motion and distance reference.
+    // Ring spacing follows the arena so the grid scales as it grows.
+    let grid_r = length(p_dist);
+    if (globals.background_grid != 0u && grid_r < globals.arena_radius && grid_r > globals.black_hole_radius) {
+        let ring_step = globals.arena_radius / 8.0;
+        let ring_d = abs(grid_r - round(grid_r / ring_step) * ring_step);
+        let sector = TAU / 12.0;
+        let grid_angle = atan2(p_dist.y, p_dist.x);
+        let spoke_d = abs(grid_angle - round(grid_angle / sector) * sector) * grid_r;
+        let grid_line = 1.0 - smoothstep(0.0, aa * 0.6, min(ring_d, spoke_d));
+        // Spokes fade in away from the hole, where they crowd together
+        let grid_fade = smoothstep(globals.black_hole_radius, globals.black_hole_radius * 3.0, grid_r);
+        color += vec3<f32>(0.25, 0.3, 0.45) * grid_line * grid_fade * 0.12;
+    }
     
     // Arena wall
     let wall_d = sdRing(p_dist, globals.arena_radius - 5.0, globals.arena_radius);
@@ -373,6 +512,15 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let wall_mask = 1.0 - smoothstep(-aa, aa, wall_d);
     color = mix(color, vec3<f32>(0.35, 0.35, 0.45), wall_mask);
     
+    // Inner ring wall (same look as the arena wall)
+    if (globals.inner_wall_radius > 0.0) {
+        let inner_d = sdInnerWall(p_dist, globals.inner_wall_radius, globals.inner_wall_gaps, globals.inner_wall_rotation);
+        let inner_glow = exp(-max(inner_d, 0.0) * 0.1) * 0.15;
+        color += vec3<f32>(0.3, 0.3, 0.5) * inner_glow;
+        let inner_mask = 1.0 - smoothstep(-aa, aa, inner_d);
+        color = mix(color, vec3<f32>(0.35, 0.35, 0.45), inner_mask);
+    }
+    
     // Pre-compute shimmer (frame-global, doesn't depend on block)
     let shimmer_phase = fract(globals.time / 22.0);
     var shimmer_value = 0.0;
@@ -400,6 +548,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     var closest_block_thickness = 0.0;
     var closest_block_wobble = 0.0;
     var closest_block_hp = 0u;
+    var closest_block_pips = 0u;
     var closest_block_visibility = 1.0;
     var closest_block_id = 0u;
     var closest_block_pole_flags = 3u; // Default: both poles active
@@ -481,6 +630,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
             closest_block_thickness = b.thickness;
             closest_block_wobble = b.wobble;
             closest_block_hp = b.hp;
+            closest_block_pips = b.pips;
             closest_block_visibility = b.visibility;
             closest_block_id = b.block_id;
             closest_block_pole_flags = b.pole_flags;
@@ -512,34 +662,6 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
             stroke_color = vec3<f32>(0.9, 0.92, 0.95);
             emission = 0.1;
             opacity = 0.85;
-            
-            // HP indicator: show dots/pips based on HP
-            // Each pip is a bright golden spot along the arc
-            let hp = closest_block_hp;
-            if (hp > 1u) {
-                // Calculate position along block for pip display
-                let block_mid_angle = (blocks[u32(closest_block_idx)].theta_start + blocks[u32(closest_block_idx)].theta_end) / 2.0;
-                let block_arc_span = abs(blocks[u32(closest_block_idx)].theta_end - blocks[u32(closest_block_idx)].theta_start);
-                
-                // Draw HP pips (up to 12)
-                let max_pips = min(hp, 12u);
-                let pip_spacing = block_arc_span / f32(max_pips + 1u);
-                
-                for (var pip = 1u; pip <= max_pips; pip++) {
-                    let pip_angle = blocks[u32(closest_block_idx)].theta_start + pip_spacing * f32(pip);
-                    let pip_pos = vec2<f32>(cos(pip_angle), sin(pip_angle)) * closest_block_radius;
-                    let pip_dist = length(p_dist - pip_pos);
-                    
-                    // Bright golden pip - bigger and more visible
-                    if (pip_dist < 7.0) {
-                        let pip_glow = 1.0 - pip_dist / 7.0;
-                        let pip_intensity = pip_glow * pip_glow * pip_glow; // Sharper falloff
-                        // Golden yellow color, very bright
-                        inner_color += vec3<f32>(1.0, 0.85, 0.3) * pip_intensity * 1.5;
-                        outer_color += vec3<f32>(1.0, 0.9, 0.5) * pip_intensity * 0.8;
-                    }
-                }
-            }
         } else if (closest_block_kind == 2u) { // Explosive
             inner_color = vec3<f32>(1.0, 0.2, 0.0);
             outer_color = vec3<f32>(1.0, 0.6, 0.1);
@@ -646,8 +768,48 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
             emission = 0.15 * ghost_alpha;
             opacity = 0.6 * ghost_alpha;
             has_specular = true;
+        } else if (closest_block_kind == 10u) { // Bumper - hot pink, flashes when hit
+            // Wobble doubles as the hit flash (the sim sets it on every kick)
+            let flash = closest_block_wobble;
+            let bumper_pulse = sin(globals.time * 4.0 + f32(closest_block_id)) * 0.1 + 0.9;
+            inner_color = vec3<f32>(0.7, 0.05, 0.45) * bumper_pulse;
+            outer_color = mix(vec3<f32>(1.0, 0.3, 0.75), vec3<f32>(1.0, 0.9, 1.0), flash);
+            stroke_color = vec3<f32>(1.0, 0.75, 0.95);
+            shimmer_color = vec3<f32>(1.0, 0.85, 1.0);
+            emission = 0.3 + flash * 0.5;
+            opacity = 0.9;
+            has_specular = true;
         }
         
+        // Per-block tint: a small hue/shade offset seeded by the block id gives
+        // rings some texture without changing from frame to frame
+        let tint = hash(vec2<f32>(f32(closest_block_id), 7.31)) * 2.0 - 1.0;
+        var hue_range = 0.08;
+        if (closest_block_kind == 0u) {
+            hue_range = 0.15; // Glass is the most common, so it varies most
+        } else if (closest_block_kind == 6u || closest_block_kind == 8u) {
+            hue_range = 0.0; // Crystal is already rainbow; magnet poles are colored
+        }
+        let shade = 1.0 + tint * 0.07;
+        inner_color = hueShift(inner_color, tint * hue_range) * shade;
+        outer_color = hueShift(outer_color, tint * hue_range) * shade;
+        
+        // Invisible blocks chaos wave: blocks only show up around a ball
+        var reveal = 1.0;
+        if ((globals.wave_modifiers & 4u) != 0u) {
+            reveal = max(1.0 - smoothstep(40.0, 110.0, closest_ball_dist), 0.04);
+            opacity *= reveal;
+            emission *= reveal;
+        }
+        
+        // Faded blocks, e.g. the next-wave preview during the breather
+        // (Ghost already folds its visibility into the opacity above)
+        if (closest_block_kind != 9u) {
+            reveal *= closest_block_visibility;
+            opacity *= closest_block_visibility;
+            emission *= closest_block_visibility;
+        }
+
         let block_color = mix(inner_color, outer_color, block_t);
         
         // Subtle outer glow
@@ -675,7 +837,30 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         let outer_edge = smoothstep(0.0, 2.0, closest_block_radius - block_r);
         let stroke_d = abs(radial_dist) - 1.0;
         let stroke_mask = 1.0 - smoothstep(-aa * 0.5, aa * 0.5, stroke_d);
-        color = mix(color, stroke_color, stroke_mask * mask * outer_edge * 0.6);
+        color = mix(color, stroke_color, stroke_mask * mask * outer_edge * 0.6 * reveal);
+        
+        // HP pips: small golden dots just inside the outer edge, one per hit left
+        if (closest_block_pips > 0u) {
+            let pb = blocks[u32(closest_block_idx)];
+            let pip_r = pb.radius + pb.thickness * 0.5 - 4.0;
+            var span = pb.theta_end - pb.theta_start;
+            span = span - round(span / TAU) * TAU;
+            if (span <= 0.0) { span += TAU; }
+            // Keep at least 8px between pips on short arcs
+            let pip_n = min(closest_block_pips, u32(span * pip_r / 8.0));
+            if (pip_n > 0u) {
+                let spacing = span / f32(pip_n + 1u);
+                var pa = block_angle - pb.theta_start;
+                pa = pa - floor(pa / TAU) * TAU;
+                // Nearest pip only - they never overlap
+                let idx = clamp(round(pa / spacing), 1.0, f32(pip_n));
+                let pip_angle = pb.theta_start + spacing * idx;
+                let pip_pos = vec2<f32>(cos(pip_angle), sin(pip_angle)) * pip_r;
+                let pip_d = length(p_dist - pip_pos) - 2.0;
+                let pip_mask = 1.0 - smoothstep(-aa, aa, pip_d);
+                color = mix(color, vec3<f32>(1.0, 0.88, 0.45), pip_mask * mask * reveal);
+            }
+        }
     }
     
     // Electric arcs between adjacent electric blocks on same ring
@@ -829,6 +1014,19 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // Black hole with swirling accretion disk
     let hole_d = sdCircle(p, globals.black_hole_radius);
     
+    // Photon ring (High quality): faint Einstein ring well outside the paddle
+    // so it can't be mistaken for the event horizon
+    if (globals.lensing > 1u) {
+        let photon_r = globals.black_hole_radius * 1.6;
+        let r = length(p);
+        let prism = vec3<f32>(
+            exp(-abs(r - photon_r * 0.98) * 0.5),
+            exp(-abs(r - photon_r) * 0.5),
+            exp(-abs(r - photon_r * 1.02) * 0.5)
+        );
+        color += prism * vec3<f32>(0.6, 0.7, 1.0) * 0.25;
+    }
+    
     // Swirling accretion disk
     let swirl = blackHoleSwirl(p, globals.black_hole_radius);
     color += swirl;
@@ -866,6 +1064,14 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         color += trail_color * glow;
     }
     
+    // Path preview - faint dotted line where the ball is headed
+    for (var i = 0u; i < globals.preview_count && i < MAX_PREVIEW; i++) {
+        let preview_dot = preview[i];
+        let d = sdCircle(p_dist - preview_dot.pos, 2.0);
+        let dot_mask = 1.0 - smoothstep(-aa, aa, d);
+        color = mix(color, vec3<f32>(0.85, 0.9, 1.0), dot_mask * preview_dot.alpha * 0.35);
+    }
+    
     // Paddle - draw as a simple thick arc
     // Debug: calculate paddle center position
     let paddle_center = vec2<f32>(cos(paddle.theta), sin(paddle.theta)) * paddle.radius;
@@ -901,7 +1107,11 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let paddle_t = (p_radius - (paddle.radius - paddle.thickness * 0.5)) / paddle.thickness;
     let paddle_inner = vec3<f32>(0.1, 1.0, 0.4);  // Bright green
     let paddle_outer = vec3<f32>(0.2, 0.8, 1.0);  // Cyan
-    let paddle_base = mix(paddle_inner, paddle_outer, clamp(paddle_t, 0.0, 1.0));
+    var paddle_base = mix(paddle_inner, paddle_outer, clamp(paddle_t, 0.0, 1.0));
+    if (paddle.color.a > 0.0) {
+        // Custom color: same inner->outer shading, single hue
+        paddle_base = paddle.color.rgb * mix(0.75, 1.0, clamp(paddle_t, 0.0, 1.0));
+    }
     
     // Subtle outer glow
     let paddle_glow = exp(-max(paddle_d, 0.0) * 0.25) * 0.15 * paddle_pulse;
@@ -927,21 +1137,46 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         
         // Ball still visible through translucent portal (bulge + ball = cool effect!)
         let d = sdCircle(p - ball.pos, ball.radius);
-        let ball_color = velocityColor(ball.speed);
+        var ball_color = velocityColor(ball.speed);
+        if (globals.ball_color.a > 0.0) {
+            ball_color = globals.ball_color.rgb;
+        }
         
-        // Subtle glow (reduced)
+        // Subtle glow (reduced) - keeps the speed tint in MultiBall too
         let glow = exp(-max(d, 0.0) * 0.3) * 0.12;
         color += ball_color * glow;
         
+        // MultiBall: each ball's body gets its own color so it can be tracked
+        if (ball.color.a > 0.0) {
+            ball_color = ball.color.rgb;
+        }
+        
         // Solid ball (fully opaque)
         let mask = 1.0 - smoothstep(-aa, aa, d);
         color = mix(color, ball_color, mask);
         
+        // Spin marker - a dark dot orbiting the center shows english
+        if (ball.spin_fade > 0.0) {
+            let marker_pos = ball.pos + vec2<f32>(cos(ball.spin_angle), sin(ball.spin_angle)) * ball.radius * 0.5;
+            let marker_d = sdCircle(p - marker_pos, ball.radius * 0.25);
+            let marker_mask = 1.0 - smoothstep(-aa, aa, marker_d);
+            color = mix(color, ball_color * 0.35, marker_mask * mask * ball.spin_fade);
+        }
+        
         // Stroke (white outline)
         let ball_stroke_d = abs(d) - 1.2;
         let ball_stroke_mask = 1.0 - smoothstep(-aa * 0.5, aa * 0.5, ball_stroke_d);
         color = mix(color, vec3<f32>(1.0, 1.0, 1.0), ball_stroke_mask * mask);
         
+        // Mercy shimmer - a pale halo sweeps around a freshly respawned ball
+        if (ball.mercy > 0.0) {
+            let sweep = sin(atan2(p.y - ball.pos.y, p.x - ball.pos.x) * 3.0 - globals.time * 9.0);
+            let halo_d = abs(d - 3.0) - 1.5;
+            let halo = (1.0 - smoothstep(-aa, aa, halo_d)) * (0.5 + 0.5 * sweep);
+            let fade = min(ball.mercy * 4.0, 1.0);
+            color += vec3<f32>(0.85, 0.95, 1.0) * halo * fade * 0.8;
+        }
+        
         // Electric charge effect! ⚡
         if (ball.electric_charge > 0.01) {
             let charge = ball.electric_charge;
@@ -1009,10 +1244,12 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         else if (part.color_u == 7u) { part_color = vec3<f32>(1.0, 0.9, 0.3); } // Electric - yellow
         else if (part.color_u == 8u) { part_color = vec3<f32>(0.9, 0.3, 0.5); } // Magnet - red-pink
         else if (part.color_u == 9u) { part_color = vec3<f32>(0.7, 0.7, 0.8); } // Ghost - pale
+        else if (part.color_u == 10u) { part_color = vec3<f32>(1.0, 0.35, 0.8); } // Bumper - magenta
         // 🔥 Special effect particles
         else if (part.color_u == 99u) { part_color = vec3<f32>(0.7, 0.95, 1.0); } // Paddle sparks - white/cyan
         else if (part.color_u == 100u) { part_color = vec3<f32>(1.0, 0.85, 0.2); } // Wave clear - gold
         else if (part.color_u == 101u) { part_color = vec3<f32>(1.0, 1.0, 0.95); } // Wave clear - white
+        else if (part.color_u == 102u) { part_color = vec3<f32>(1.0, 0.35, 0.08); } // Scorch - ember
         
         // Simple circular particles - no stretching
         let to_part = p - part.pos;
@@ -1027,6 +1264,11 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
             // 🔥 SHARP paddle sparks
             let spark_core = 1.0 - smoothstep(-aa * 0.5, aa * 0.5, d);
             color += part_color * spark_core * part.life * 1.5 * sparkle;
+        } else if (part.color_u == 102u) {
+            // Scorch marks - faint soft ember that cools from orange to dull red
+            let scorch = exp(-max(d + part.size * 0.5, 0.0) * 0.25) * part.life * part.life;
+            let cooled = mix(vec3<f32>(0.35, 0.05, 0.02), part_color, part.life);
+            color += cooled * scorch * 0.3;
         } else {
             // Disintegration particles - small bright dots
             let glow = exp(-max(d, 0.0) * 0.5) * part.life * sparkle;
@@ -1038,6 +1280,16 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         }
     }
     
+    // Explosion shockwaves - hot rings that carry the blast to the neighbors
+    for (var i = 0u; i < globals.shockwave_count && i < MAX_SHOCKWAVES; i++) {
+        let wave = shockwaves[i];
+        let ring_d = abs(length(p - wave.center) - wave.radius) - 2.0 * wave.fade - 0.5;
+        let ring_glow = exp(-max(ring_d, 0.0) * 0.15) * wave.fade;
+        let ring_core = 1.0 - smoothstep(-aa, aa, ring_d);
+        color += vec3<f32>(1.0, 0.55, 0.15) * ring_glow * 0.9;
+        color = mix(color, vec3<f32>(1.0, 0.9, 0.7), ring_core * wave.fade);
+    }
+    
     // Pickups! 💊 Power-ups with sexy particle effects!
     for (var i = 0u; i < globals.pickup_count && i < MAX_PICKUPS; i++) {
         let pickup = pickups[i];
@@ -1054,6 +1306,10 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         else if (pickup.kind == 2u) { pickup_color = vec3<f32>(1.0, 0.3, 0.3); }  // Piercing - red
         else if (pickup.kind == 3u) { pickup_color = vec3<f32>(0.3, 1.0, 0.3); }  // Widen - green
         else if (pickup.kind == 4u) { pickup_color = vec3<f32>(0.8, 0.3, 1.0); }  // Shield - purple
+        else if (pickup.kind == 5u) { pickup_color = vec3<f32>(0.7, 0.95, 1.0); }  // Freeze - ice
+        else if (pickup.kind == 6u) { pickup_color = vec3<f32>(1.0, 0.55, 0.15); }  // Sticky - amber
+        else if (pickup.kind == 7u) { pickup_color = vec3<f32>(0.6, 0.65, 0.75); }  // Heavy - steel
+        else if (pickup.kind == 8u) { pickup_color = vec3<f32>(0.95, 0.4, 0.85); }  // Time Warp - magenta
         
         // ✨ Orbiting particles (3 particles per pickup) - 20% faster
         let orbit_radius = 20.0 + sin(globals.time * 2.4) * 3.0;
@@ -1112,6 +1368,28 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         color = mix(color, vec3<f32>(1.0, 0.95, 0.8), globals.wave_flash * 0.3);
     }
     
+    // Danger vignette - screen edges redden as a ball nears the black hole
+    if (globals.danger > 0.001) {
+        let still = (globals.danger_style & 1u) != 0u;
+        let high_contrast = (globals.danger_style & 2u) != 0u;
+        var strength = globals.danger * globals.danger;
+        if (still) {
+            strength *= 0.6;
+        } else {
+            // Heartbeat quickens with urgency
+            strength *= 0.75 + 0.25 * sin(globals.time * (5.0 + globals.danger * 9.0));
+        }
+        if (high_contrast) {
+            // Solid red frame, readable against any backdrop
+            let edge = max(abs(in.uv.x), abs(in.uv.y));
+            let band = smoothstep(0.9 - globals.danger * 0.06, 0.92 - globals.danger * 0.06, edge);
+            color = mix(color, vec3<f32>(1.0, 0.05, 0.05), band * (0.5 + strength * 0.5));
+        } else {
+            let edge = smoothstep(0.45, 1.35, length(in.uv));
+            color = mix(color, color * 0.35 + vec3<f32>(0.55, 0.02, 0.03), edge * strength * 0.8);
+        }
+    }
+
     // Vignette
     let vig = 1.0 - length(in.uv) * 0.25;
     color *= vig;
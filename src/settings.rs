@@ -2,7 +2,66 @@
 //!
 //! Persisted separately from game saves in LocalStorage.
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+pub use crate::tuning::Difficulty;
+
+/// Current settings schema version, stored in `Settings::version`
+pub const SETTINGS_VERSION: u32 = 2;
+
+/// Upgrade steps: `SETTINGS_MIGRATIONS[n]` turns a version `n` blob into
+/// version `n + 1`. Renaming or removing a field bumps `SETTINGS_VERSION`
+/// and adds a step here that rewrites the old field, so stored preferences
+/// carry over instead of failing to parse and resetting to defaults.
+const SETTINGS_MIGRATIONS: [fn(&mut Map<String, Value>); SETTINGS_VERSION as usize] =
+    [migrate_v0_to_v1, migrate_v1_to_v2];
+
+/// v0 blobs predate the version field. Every field added since has a serde
+/// default, so nothing needs rewriting.
+fn migrate_v0_to_v1(_fields: &mut Map<String, Value>) {}
+
+/// v2 replaces the `quadrant_audio` on/off toggle with `pan_mode`. On maps
+/// to the default continuous panning, off keeps sounds centered.
+fn migrate_v1_to_v2(fields: &mut Map<String, Value>) {
+    if let Some(on) = fields.remove("quadrant_audio") {
+        let mode = if on.as_bool() == Some(false) {
+            PanMode::Off
+        } else {
+            PanMode::Continuous
+        };
+        fields.insert("pan_mode".into(), mode.as_str().into());
+    }
+}
+
+/// Why stored settings couldn't be loaded
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingsError {
+    /// Stored JSON doesn't parse as settings
+    Parse(String),
+    /// Settings were written by an unsupported schema version
+    Version { found: u32, supported: u32 },
+    /// Storage backend is unavailable or the read failed
+    Storage(String),
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsError::Parse(err) => write!(f, "parse error: {}", err),
+            SettingsError::Version { found, supported } => write!(
+                f,
+                "unsupported settings version {} (supported: {})",
+                found, supported
+            ),
+            SettingsError::Storage(err) => write!(f, "storage error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
 
 /// Quality preset levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -11,14 +70,25 @@ pub enum QualityPreset {
     #[default]
     Medium,
     High,
+    /// Preset-controlled settings were changed individually. Only reported
+    /// by `Settings::detect_preset`; as a render tier it acts like Medium.
+    Custom,
 }
 
 impl QualityPreset {
+    /// Presets the player can pick
+    pub const NAMED: [QualityPreset; 3] = [
+        QualityPreset::Low,
+        QualityPreset::Medium,
+        QualityPreset::High,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             QualityPreset::Low => "Low",
             QualityPreset::Medium => "Medium",
             QualityPreset::High => "High",
+            QualityPreset::Custom => "Custom",
         }
     }
 
@@ -36,7 +106,7 @@ impl QualityPreset {
     pub fn max_particles(&self) -> usize {
         match self {
             QualityPreset::Low => 100,
-            QualityPreset::Medium => 500,
+            QualityPreset::Medium | QualityPreset::Custom => 500,
             QualityPreset::High => 2000,
         }
     }
@@ -45,7 +115,7 @@ impl QualityPreset {
     pub fn trail_quality(&self) -> f32 {
         match self {
             QualityPreset::Low => 0.25,
-            QualityPreset::Medium => 0.6,
+            QualityPreset::Medium | QualityPreset::Custom => 0.6,
             QualityPreset::High => 1.0,
         }
     }
@@ -54,7 +124,7 @@ impl QualityPreset {
     pub fn starfield_enabled(&self) -> bool {
         match self {
             QualityPreset::Low => false,
-            QualityPreset::Medium => true,
+            QualityPreset::Medium | QualityPreset::Custom => true,
             QualityPreset::High => true,
         }
     }
@@ -63,15 +133,350 @@ impl QualityPreset {
     pub fn nebula_enabled(&self) -> bool {
         match self {
             QualityPreset::Low => false,
-            QualityPreset::Medium => false,
+            QualityPreset::Medium | QualityPreset::Custom => false,
             QualityPreset::High => true,
         }
     }
+
+    /// Gravitational lensing around the black hole
+    /// (0 = off, 1 = single warp, 2 = prism split with photon ring)
+    pub fn lensing_level(&self) -> u32 {
+        match self {
+            QualityPreset::Low => 0,
+            QualityPreset::Medium | QualityPreset::Custom => 1,
+            QualityPreset::High => 2,
+        }
+    }
+}
+
+/// Cosmetic color choices for the paddle and ball
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ColorChoice {
+    /// Built-in look (paddle gradient, speed-tinted ball)
+    #[default]
+    Classic,
+    Cyan,
+    Magenta,
+    Gold,
+    Lime,
+    White,
+}
+
+impl ColorChoice {
+    /// All choices, in UI order
+    pub const ALL: [ColorChoice; 6] = [
+        ColorChoice::Classic,
+        ColorChoice::Cyan,
+        ColorChoice::Magenta,
+        ColorChoice::Gold,
+        ColorChoice::Lime,
+        ColorChoice::White,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorChoice::Classic => "Classic",
+            ColorChoice::Cyan => "Cyan",
+            ColorChoice::Magenta => "Magenta",
+            ColorChoice::Gold => "Gold",
+            ColorChoice::Lime => "Lime",
+            ColorChoice::White => "White",
+        }
+    }
+
+    /// Parse a color choice from a string
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_str().eq_ignore_ascii_case(s))
+    }
+
+    /// RGB tint, or `None` for the classic look
+    pub fn rgb(&self) -> Option<[f32; 3]> {
+        match self {
+            ColorChoice::Classic => None,
+            ColorChoice::Cyan => Some([0.2, 0.85, 1.0]),
+            ColorChoice::Magenta => Some([1.0, 0.3, 0.85]),
+            ColorChoice::Gold => Some([1.0, 0.8, 0.2]),
+            ColorChoice::Lime => Some([0.5, 1.0, 0.2]),
+            ColorChoice::White => Some([0.95, 0.95, 1.0]),
+        }
+    }
+
+    /// Packed for the GPU: rgb + alpha flag (0.0 = classic look)
+    pub fn to_gpu(self) -> [f32; 4] {
+        match self.rgb() {
+            Some([r, g, b]) => [r, g, b, 1.0],
+            None => [0.0; 4],
+        }
+    }
+}
+
+/// Camera zoom behaviour
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum ZoomMode {
+    /// Zoom out automatically to fit the arena as it grows
+    #[default]
+    Auto,
+    /// Hold a fixed zoom level (still widened if the arena would clip)
+    Fixed(f32),
+}
+
+impl ZoomMode {
+    /// Closest allowed zoom (base arena fills the viewport)
+    pub const MIN_ZOOM: f32 = 1.0;
+    /// Furthest allowed zoom
+    pub const MAX_ZOOM: f32 = 2.0;
+
+    /// Zoom that fits an arena of the given radius in the base viewport
+    /// (arena radius * 1.1, so the wall keeps a margin at every size)
+    pub fn fit_zoom(arena_radius: f32) -> f32 {
+        let base_viewport = crate::consts::ARENA_OUTER_RADIUS * 1.1;
+        arena_radius * 1.1 / base_viewport
+    }
+
+    /// Zoom the camera should settle at for an arena of the given radius
+    pub fn target_zoom(&self, arena_radius: f32) -> f32 {
+        let fit_zoom = Self::fit_zoom(arena_radius);
+        match *self {
+            ZoomMode::Auto => fit_zoom,
+            // Never zoom in past the fit, or the arena wall would clip off-screen
+            ZoomMode::Fixed(zoom) => zoom.max(fit_zoom),
+        }
+    }
+}
+
+/// Stereo positions of the four quadrant pan zones, left to right
+const QUADRANT_PANS: [f32; 4] = [-0.75, -0.25, 0.25, 0.75];
+
+/// How sound effects are panned by where they happen in the arena
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum PanMode {
+    /// Every sound plays centered
+    Off,
+    /// The arena is split into four vertical slices, each with a fixed
+    /// stereo position - a cheap cue that doesn't track the sound
+    Quadrant,
+    /// Hard left at the left wall, hard right at the right wall, centered
+    /// over the black hole
+    #[default]
+    Continuous,
+}
+
+impl PanMode {
+    /// All modes, in UI order
+    pub const ALL: [PanMode; 3] = [PanMode::Off, PanMode::Quadrant, PanMode::Continuous];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PanMode::Off => "Off",
+            PanMode::Quadrant => "Quadrant",
+            PanMode::Continuous => "Continuous",
+        }
+    }
+
+    /// Parse a pan mode from a string
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|m| m.as_str().eq_ignore_ascii_case(s))
+    }
+
+    /// Stereo pan (-1 left to 1 right) for a sound at arena `x`, or `None`
+    /// to leave it centered
+    pub fn pan(&self, x: f32, arena_radius: f32) -> Option<f32> {
+        let x = (x / arena_radius.max(1.0)).clamp(-1.0, 1.0);
+        match self {
+            PanMode::Off => None,
+            PanMode::Quadrant => {
+                let t = x * 0.5 + 0.5;
+                Some(QUADRANT_PANS[((t * 4.0) as usize).min(3)])
+            }
+            PanMode::Continuous => Some(x),
+        }
+    }
+}
+
+/// Keyboard actions that can be rebound
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    Launch,
+    Catch,
+    Pause,
+    Left,
+    Right,
+    SkipWave,
+    StepTick,
+    IdleMode,
+    Mute,
+}
+
+impl KeyAction {
+    /// All actions, in UI order
+    pub const ALL: [KeyAction; 9] = [
+        KeyAction::Launch,
+        KeyAction::Catch,
+        KeyAction::Pause,
+        KeyAction::Left,
+        KeyAction::Right,
+        KeyAction::SkipWave,
+        KeyAction::StepTick,
+        KeyAction::IdleMode,
+        KeyAction::Mute,
+    ];
+
+    /// Stable identifier (used as `data-action` in the settings UI)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyAction::Launch => "launch",
+            KeyAction::Catch => "catch",
+            KeyAction::Pause => "pause",
+            KeyAction::Left => "left",
+            KeyAction::Right => "right",
+            KeyAction::SkipWave => "skip_wave",
+            KeyAction::StepTick => "step_tick",
+            KeyAction::IdleMode => "idle_mode",
+            KeyAction::Mute => "mute",
+        }
+    }
+
+    /// Parse an action from its identifier
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.as_str() == s)
+    }
+
+    /// Human-readable name
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyAction::Launch => "Launch",
+            KeyAction::Catch => "Catch Ball",
+            KeyAction::Pause => "Pause",
+            KeyAction::Left => "Move Left",
+            KeyAction::Right => "Move Right",
+            KeyAction::SkipWave => "Skip Wave (debug)",
+            KeyAction::StepTick => "Step Tick (debug)",
+            KeyAction::IdleMode => "Idle Mode",
+            KeyAction::Mute => "Mute",
+        }
+    }
+}
+
+/// Keys bound to each action
+///
+/// Keys are `KeyboardEvent.key` values, matched case-insensitively so
+/// letter bindings work with or without Shift.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub launch: Vec<String>,
+    pub catch: Vec<String>,
+    pub pause: Vec<String>,
+    pub left: Vec<String>,
+    pub right: Vec<String>,
+    pub skip_wave: Vec<String>,
+    pub step_tick: Vec<String>,
+    pub idle_mode: Vec<String>,
+    pub mute: Vec<String>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let keys = |ks: &[&str]| ks.iter().map(|k| k.to_string()).collect();
+        Self {
+            launch: keys(&[" ", "Enter"]),
+            catch: keys(&["c"]),
+            pause: keys(&["Escape"]),
+            left: keys(&["ArrowLeft", "a"]),
+            right: keys(&["ArrowRight", "d"]),
+            skip_wave: keys(&["+", "="]),
+            step_tick: keys(&[".", ">"]),
+            idle_mode: keys(&["i"]),
+            mute: keys(&["m"]),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Keys bound to an action
+    pub fn keys(&self, action: KeyAction) -> &[String] {
+        match action {
+            KeyAction::Launch => &self.launch,
+            KeyAction::Catch => &self.catch,
+            KeyAction::Pause => &self.pause,
+            KeyAction::Left => &self.left,
+            KeyAction::Right => &self.right,
+            KeyAction::SkipWave => &self.skip_wave,
+            KeyAction::StepTick => &self.step_tick,
+            KeyAction::IdleMode => &self.idle_mode,
+            KeyAction::Mute => &self.mute,
+        }
+    }
+
+    fn keys_mut(&mut self, action: KeyAction) -> &mut Vec<String> {
+        match action {
+            KeyAction::Launch => &mut self.launch,
+            KeyAction::Catch => &mut self.catch,
+            KeyAction::Pause => &mut self.pause,
+            KeyAction::Left => &mut self.left,
+            KeyAction::Right => &mut self.right,
+            KeyAction::SkipWave => &mut self.skip_wave,
+            KeyAction::StepTick => &mut self.step_tick,
+            KeyAction::IdleMode => &mut self.idle_mode,
+            KeyAction::Mute => &mut self.mute,
+        }
+    }
+
+    /// Action bound to a key, if any
+    pub fn action_for(&self, key: &str) -> Option<KeyAction> {
+        KeyAction::ALL
+            .into_iter()
+            .find(|&a| self.keys(a).iter().any(|k| k.eq_ignore_ascii_case(key)))
+    }
+
+    /// Bind `key` as the only key for `action`.
+    ///
+    /// Fails with the conflicting action if the key is already used elsewhere.
+    pub fn bind(&mut self, action: KeyAction, key: &str) -> Result<(), KeyAction> {
+        match self.action_for(key) {
+            Some(other) if other != action => Err(other),
+            _ => {
+                *self.keys_mut(action) = vec![key.to_string()];
+                Ok(())
+            }
+        }
+    }
+
+    /// Display text for an action's bindings (e.g. "Space / Enter")
+    pub fn display(&self, action: KeyAction) -> String {
+        self.keys(action)
+            .iter()
+            .map(|k| key_display_name(k))
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+/// Display name for a `KeyboardEvent.key` value
+pub fn key_display_name(key: &str) -> String {
+    match key {
+        " " => "Space".to_string(),
+        "ArrowLeft" => "←".to_string(),
+        "ArrowRight" => "→".to_string(),
+        "ArrowUp" => "↑".to_string(),
+        "ArrowDown" => "↓".to_string(),
+        "Escape" => "Esc".to_string(),
+        k if k.chars().count() == 1 => k.to_uppercase(),
+        k => k.to_string(),
+    }
 }
 
 /// Game settings/preferences
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
+    /// Schema version the settings were written with (0 = before versioning)
+    #[serde(default)]
+    pub version: u32,
+
     /// Graphics quality preset
     pub quality: QualityPreset,
 
@@ -86,40 +491,132 @@ pub struct Settings {
     pub wave_flash: bool,
     /// Power-up visual effects (orbiting particles, sparkles)
     pub powerup_effects: bool,
+    /// Faint polar grid behind the arena (motion and distance reference)
+    #[serde(default)]
+    pub background_grid: bool,
+    /// Small pips on multi-hit blocks showing hits left
+    #[serde(default = "default_true")]
+    pub hp_pips: bool,
+    /// Brief slow motion after a save at the paddle edge
+    #[serde(default = "default_true")]
+    pub bullet_time: bool,
+    /// Brief freeze on big impacts (explosions, shield saves, wave clears)
+    #[serde(default = "default_true")]
+    pub hit_stop: bool,
+    /// No black hole gravity. Takes effect on the next run, which isn't ranked
+    #[serde(default)]
+    pub relaxed_mode: bool,
+    /// Difficulty of the next new run
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Fraction of each ball's trail to draw (0.0 - 1.0)
+    #[serde(default = "default_trail_length")]
+    pub trail_length: f32,
+    /// Fraction of the preset's particle cap to draw (0.0 - 1.0)
+    #[serde(default = "default_particle_density")]
+    pub particle_density: f32,
+    /// Camera zoom mode (auto-fit or fixed level)
+    #[serde(default)]
+    pub zoom_mode: ZoomMode,
+
+    // === Appearance ===
+    /// Paddle color
+    #[serde(default)]
+    pub paddle_color: ColorChoice,
+    /// Ball color
+    #[serde(default)]
+    pub ball_color: ColorChoice,
 
     // === HUD ===
     /// Show FPS counter
     pub show_fps: bool,
-
-    // === Audio (prep for later) ===
+    /// Show the run timer
+    #[serde(default = "default_true")]
+    pub show_timer: bool,
+    /// Show lives as ball icons instead of a number
+    #[serde(default)]
+    pub lives_icons: bool,
+    /// Pulse the lives counter and play a heartbeat on the last life
+    #[serde(default = "default_true")]
+    pub low_lives_warning: bool,
+
+    // === Audio ===
     /// Master volume (0.0 - 1.0)
     pub master_volume: f32,
     /// Sound effects volume (0.0 - 1.0)
     pub sfx_volume: f32,
     /// Music volume (0.0 - 1.0)
     pub music_volume: f32,
+    /// Mute all audio (M key). Unlike the blur mute, regaining focus
+    /// doesn't lift it.
+    #[serde(default)]
+    pub muted: bool,
     /// Mute when window loses focus
     pub mute_on_blur: bool,
+    /// Pan sound effects toward where they happen in the arena
+    #[serde(default)]
+    pub pan_mode: PanMode,
+    /// Space out rapid repeats of the same sound (e.g. wall hits in a rally)
+    #[serde(default = "default_true")]
+    pub smooth_audio: bool,
 
     // === Accessibility ===
     /// Reduced motion (minimize shake, flashes)
     pub reduced_motion: bool,
     /// High contrast mode
     pub high_contrast: bool,
+    /// Dotted line showing where each free ball will fly under gravity
+    #[serde(default)]
+    pub path_preview: bool,
 
     // === Controls ===
-    /// Keyboard paddle speed (radians per second, default 6.0)
+    /// Keyboard paddle speed (radians per second, default 6.0). Copied into
+    /// the run's tuning when it starts, so a change applies from the next run.
     #[serde(default = "default_keyboard_sensitivity")]
     pub keyboard_sensitivity: f32,
+    /// Keyboard bindings
+    #[serde(default)]
+    pub key_bindings: KeyBindings,
+    /// Touch drag rotates the paddle relatively; launch via on-screen button
+    #[serde(default)]
+    pub touch_relative: bool,
+    /// Pause when the window loses focus
+    #[serde(default = "default_true")]
+    pub pause_on_blur: bool,
+    /// Pause when the tab is hidden (tab switch, minimize)
+    #[serde(default = "default_true")]
+    pub pause_on_hide: bool,
+    /// Input latency compensation (0 = off, 1 = full): draws the paddle
+    /// this fraction of the way toward the latest pointer target. Render
+    /// only; collisions always use the simulated paddle.
+    #[serde(default)]
+    pub paddle_lead: f32,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 fn default_keyboard_sensitivity() -> f32 {
     6.0
 }
 
+fn default_trail_length() -> f32 {
+    QualityPreset::default().trail_quality()
+}
+
+/// Particle density every preset starts at; the preset's particle cap
+/// already scales with quality
+const DEFAULT_PARTICLE_DENSITY: f32 = 1.0;
+
+fn default_particle_density() -> f32 {
+    DEFAULT_PARTICLE_DENSITY
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
+            version: SETTINGS_VERSION,
             quality: QualityPreset::Medium,
 
             // Visual effects - all on by default
@@ -128,22 +625,47 @@ impl Default for Settings {
             particles: true,
             wave_flash: true,
             powerup_effects: true,
+            background_grid: false,
+            hp_pips: true,
+            bullet_time: true,
+            hit_stop: true,
+            relaxed_mode: false,
+            difficulty: Difficulty::Normal,
+            trail_length: default_trail_length(),
+            particle_density: default_particle_density(),
+            zoom_mode: ZoomMode::Auto,
+
+            // Appearance
+            paddle_color: ColorChoice::Classic,
+            ball_color: ColorChoice::Classic,
 
             // HUD
             show_fps: true,
+            show_timer: true,
+            lives_icons: false,
+            low_lives_warning: true,
 
             // Audio
             master_volume: 0.8,
             sfx_volume: 1.0,
             music_volume: 0.7,
+            muted: false,
             mute_on_blur: true,
+            pan_mode: PanMode::Continuous,
+            smooth_audio: true,
 
             // Accessibility
             reduced_motion: false,
             high_contrast: false,
+            path_preview: false,
 
             // Controls
             keyboard_sensitivity: 6.0,
+            key_bindings: KeyBindings::default(),
+            touch_relative: false,
+            pause_on_blur: true,
+            pause_on_hide: true,
+            paddle_lead: 0.0,
         }
     }
 }
@@ -151,20 +673,44 @@ impl Default for Settings {
 impl Settings {
     /// Create settings from a quality preset (applies preset defaults)
     pub fn from_preset(preset: QualityPreset) -> Self {
-        Self {
-            quality: preset,
-            ..Default::default()
-        }
+        let mut settings = Self::default();
+        settings.apply_preset(preset);
+        settings
     }
 
-    /// Apply a quality preset (updates quality-dependent settings)
+    /// Apply a quality preset: sets the render tier and every setting the
+    /// preset controls (trail length, particle density, power-up effects,
+    /// wave flash). `Custom` is not a preset to apply and is ignored.
     pub fn apply_preset(&mut self, preset: QualityPreset) {
+        if preset == QualityPreset::Custom {
+            return;
+        }
         self.quality = preset;
+        self.trail_length = preset.trail_quality();
+        self.particle_density = DEFAULT_PARTICLE_DENSITY;
 
         // Low preset disables some effects for performance
-        if preset == QualityPreset::Low {
-            self.powerup_effects = false;
-            self.wave_flash = false;
+        let full_effects = preset != QualityPreset::Low;
+        self.powerup_effects = full_effects;
+        self.wave_flash = full_effects;
+    }
+
+    /// The named preset the preset-controlled settings match, or `Custom`
+    /// once any of them has been changed individually
+    pub fn detect_preset(&self) -> QualityPreset {
+        let mut preset = self.clone();
+        preset.apply_preset(self.quality);
+        // Sliders store whole percents, so allow for float rounding
+        let close = |a: f32, b: f32| (a - b).abs() < 0.005;
+        let matches = self.quality != QualityPreset::Custom
+            && close(self.trail_length, preset.trail_length)
+            && close(self.particle_density, preset.particle_density)
+            && self.powerup_effects == preset.powerup_effects
+            && self.wave_flash == preset.wave_flash;
+        if matches {
+            self.quality
+        } else {
+            QualityPreset::Custom
         }
     }
 
@@ -178,12 +724,31 @@ impl Settings {
         self.wave_flash && !self.reduced_motion
     }
 
+    /// Whether fast balls' scorch marks are drawn (respects reduced_motion)
+    pub fn effective_scorch_marks(&self) -> bool {
+        self.particles && !self.reduced_motion
+    }
+
+    /// Effective bullet time (respects reduced_motion)
+    pub fn effective_bullet_time(&self) -> bool {
+        self.bullet_time && !self.reduced_motion
+    }
+
     /// Effective particle count cap
     pub fn max_particles(&self) -> usize {
         if !self.particles {
             0
         } else {
-            self.quality.max_particles()
+            (self.quality.max_particles() as f32 * self.particle_density.clamp(0.0, 1.0)) as usize
+        }
+    }
+
+    /// Number of trail points to draw per ball, out of `max_len` recorded
+    pub fn trail_points(&self, max_len: usize) -> usize {
+        if !self.trails {
+            0
+        } else {
+            (max_len as f32 * self.trail_length.clamp(0.0, 1.0)).round() as usize
         }
     }
 
@@ -191,24 +756,56 @@ impl Settings {
     #[allow(dead_code)]
     const STORAGE_KEY: &'static str = "roto_pong_settings";
 
-    /// Load settings from LocalStorage (WASM only)
+    /// Parse stored settings JSON, upgrading older schema versions
+    pub fn from_json(json: &str) -> Result<Self, SettingsError> {
+        let parse_error = |e: serde_json::Error| SettingsError::Parse(e.to_string());
+        let mut value: Value = serde_json::from_str(json).map_err(parse_error)?;
+        let Some(fields) = value.as_object_mut() else {
+            return Err(SettingsError::Parse("settings are not an object".into()));
+        };
+
+        let found = fields.get("version").and_then(Value::as_u64).unwrap_or(0);
+        let found = u32::try_from(found).unwrap_or(u32::MAX);
+        if found > SETTINGS_VERSION {
+            return Err(SettingsError::Version {
+                found,
+                supported: SETTINGS_VERSION,
+            });
+        }
+        for version in found..SETTINGS_VERSION {
+            SETTINGS_MIGRATIONS[version as usize](fields);
+            fields.insert("version".into(), (version + 1).into());
+        }
+        if found < SETTINGS_VERSION {
+            log::info!("Migrated settings v{} -> v{}", found, SETTINGS_VERSION);
+        }
+
+        serde_json::from_value(value).map_err(parse_error)
+    }
+
+    /// Load settings from LocalStorage (WASM only). Nothing stored yet is
+    /// not an error and gives the defaults; anything else is reported so
+    /// the caller can decide how to fall back.
     #[cfg(target_arch = "wasm32")]
-    pub fn load() -> Self {
+    pub fn load() -> Result<Self, SettingsError> {
         let storage = web_sys::window()
-            .and_then(|w| w.local_storage().ok())
-            .flatten();
-
-        if let Some(storage) = storage {
-            if let Ok(Some(json)) = storage.get_item(Self::STORAGE_KEY) {
-                if let Ok(settings) = serde_json::from_str(&json) {
-                    log::info!("Loaded settings from LocalStorage");
-                    return settings;
-                }
+            .ok_or_else(|| SettingsError::Storage("no window".into()))?
+            .local_storage()
+            .map_err(|e| SettingsError::Storage(format!("{:?}", e)))?
+            .ok_or_else(|| SettingsError::Storage("LocalStorage unavailable".into()))?;
+
+        match storage.get_item(Self::STORAGE_KEY) {
+            Ok(Some(json)) => {
+                let settings = Self::from_json(&json)?;
+                log::info!("Loaded settings from LocalStorage");
+                Ok(settings)
+            }
+            Ok(None) => {
+                log::info!("Using default settings");
+                Ok(Self::default())
             }
+            Err(e) => Err(SettingsError::Storage(format!("{:?}", e))),
         }
-
-        log::info!("Using default settings");
-        Self::default()
     }
 
     /// Save settings to LocalStorage (WASM only)
@@ -228,8 +825,8 @@ impl Settings {
 
     /// Native stubs
     #[cfg(not(target_arch = "wasm32"))]
-    pub fn load() -> Self {
-        Self::default()
+    pub fn load() -> Result<Self, SettingsError> {
+        Ok(Self::default())
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -237,3 +834,162 @@ impl Settings {
         // No-op for native
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_preset() {
+        assert_eq!(Settings::default().detect_preset(), QualityPreset::Medium);
+        for preset in QualityPreset::NAMED {
+            let mut settings = Settings::from_preset(preset);
+            assert_eq!(settings.detect_preset(), preset);
+
+            // Any preset-controlled tweak makes it custom...
+            settings.trail_length = 0.33;
+            assert_eq!(settings.detect_preset(), QualityPreset::Custom);
+            settings.apply_preset(preset);
+            settings.wave_flash = !settings.wave_flash;
+            assert_eq!(settings.detect_preset(), QualityPreset::Custom);
+
+            // ...but other settings don't
+            settings.apply_preset(preset);
+            settings.show_fps = !settings.show_fps;
+            assert_eq!(settings.detect_preset(), preset);
+        }
+
+        // Applying Custom keeps the current configuration
+        let mut settings = Settings::from_preset(QualityPreset::Low);
+        settings.particle_density = 0.9;
+        settings.apply_preset(QualityPreset::Custom);
+        assert_eq!(settings.quality, QualityPreset::Low);
+        assert_eq!(settings.particle_density, 0.9);
+
+        // Presets keep their full particle caps until the slider is moved
+        let medium = Settings::from_preset(QualityPreset::Medium);
+        assert_eq!(medium.max_particles(), 500);
+    }
+
+    #[test]
+    fn test_pan_mode() {
+        let radius = 400.0;
+        assert_eq!(PanMode::Off.pan(-350.0, radius), None);
+        assert_eq!(PanMode::Continuous.pan(-200.0, radius), Some(-0.5));
+        assert_eq!(PanMode::Continuous.pan(900.0, radius), Some(1.0));
+        // Quadrants snap to fixed zones
+        assert_eq!(PanMode::Quadrant.pan(-350.0, radius), Some(-0.75));
+        assert_eq!(PanMode::Quadrant.pan(-50.0, radius), Some(-0.25));
+        assert_eq!(PanMode::Quadrant.pan(50.0, radius), Some(0.25));
+        assert_eq!(PanMode::Quadrant.pan(radius, radius), Some(0.75));
+
+        for mode in PanMode::ALL {
+            assert_eq!(PanMode::parse(mode.as_str()), Some(mode));
+        }
+    }
+
+    #[test]
+    fn test_v1_pan_toggle_migrates() {
+        // Settings saved while panning was an on/off toggle
+        let v1 = |on: bool| {
+            let mut fields = serde_json::to_value(Settings::default()).unwrap();
+            let fields = fields.as_object_mut().unwrap();
+            fields.remove("pan_mode");
+            fields.insert("quadrant_audio".into(), on.into());
+            fields.insert("version".into(), 1.into());
+            serde_json::to_string(fields).unwrap()
+        };
+        let settings = Settings::from_json(&v1(true)).unwrap();
+        assert_eq!(settings.pan_mode, PanMode::Continuous);
+        let settings = Settings::from_json(&v1(false)).unwrap();
+        assert_eq!(settings.pan_mode, PanMode::Off);
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(!json.contains("quadrant_audio"));
+    }
+
+    #[test]
+    fn test_v0_settings_migrate() {
+        // Settings as first shipped, before the version field existed
+        let v0 = r#"{
+            "quality": "High",
+            "screen_shake": false,
+            "trails": true,
+            "particles": true,
+            "wave_flash": false,
+            "powerup_effects": true,
+            "show_fps": false,
+            "master_volume": 0.3,
+            "sfx_volume": 0.9,
+            "music_volume": 0.2,
+            "mute_on_blur": false,
+            "reduced_motion": true,
+            "high_contrast": true,
+            "keyboard_sensitivity": 4.5
+        }"#;
+        let settings = Settings::from_json(v0).unwrap();
+        assert_eq!(settings.version, SETTINGS_VERSION);
+        assert_eq!(settings.quality, QualityPreset::High);
+        assert!(!settings.screen_shake && !settings.wave_flash && !settings.show_fps);
+        assert_eq!(settings.master_volume, 0.3);
+        assert!(settings.reduced_motion && settings.high_contrast);
+        assert_eq!(settings.keyboard_sensitivity, 4.5);
+        // Fields added later take their defaults
+        let defaults = Settings::default();
+        assert_eq!(settings.bullet_time, defaults.bullet_time);
+        assert_eq!(settings.trail_length, defaults.trail_length);
+        assert_eq!(settings.zoom_mode, defaults.zoom_mode);
+        assert_eq!(settings.difficulty, Difficulty::Normal);
+        assert!(!settings.muted);
+
+        // Current settings round-trip unchanged
+        let json = serde_json::to_string(&defaults).unwrap();
+        assert_eq!(
+            Settings::from_json(&json).unwrap().version,
+            SETTINGS_VERSION
+        );
+
+        // Settings from a newer build are reported, not misread
+        let future = json.replace(
+            &format!("\"version\":{}", SETTINGS_VERSION),
+            "\"version\":99",
+        );
+        assert_eq!(
+            Settings::from_json(&future).unwrap_err(),
+            SettingsError::Version {
+                found: 99,
+                supported: SETTINGS_VERSION
+            }
+        );
+        assert!(matches!(
+            Settings::from_json("[1, 2]"),
+            Err(SettingsError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_key_bindings_conflicts() {
+        let mut bindings = KeyBindings::default();
+        assert_eq!(bindings.action_for("A"), Some(KeyAction::Left));
+        assert_eq!(bindings.action_for("Enter"), Some(KeyAction::Launch));
+
+        // Key already used by another action is refused
+        assert_eq!(bindings.bind(KeyAction::Launch, "m"), Err(KeyAction::Mute));
+        assert_eq!(bindings.display(KeyAction::Launch), "Space / Enter");
+
+        // Free key replaces the action's bindings
+        assert_eq!(bindings.bind(KeyAction::SkipWave, "n"), Ok(()));
+        assert_eq!(bindings.action_for("N"), Some(KeyAction::SkipWave));
+        assert_eq!(bindings.action_for("+"), None);
+    }
+
+    #[test]
+    fn test_zoom_fits_max_arena() {
+        use crate::sim::{BASE_ARENA_RADIUS, MAX_ARENA_RADIUS};
+
+        assert_eq!(ZoomMode::fit_zoom(BASE_ARENA_RADIUS), ZoomMode::MIN_ZOOM);
+        // The camera clamp must not cut the wall off at the largest arena
+        let max_fit = ZoomMode::Auto.target_zoom(MAX_ARENA_RADIUS);
+        assert!(max_fit <= ZoomMode::MAX_ZOOM);
+        assert!(ZoomMode::Fixed(1.0).target_zoom(MAX_ARENA_RADIUS) >= max_fit);
+    }
+}
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use crate::{normalize_angle, polar_to_cartesian};
 
 /// A thickened arc segment in polar space
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ArcSegment {
     /// Centerline radius from arena center
     pub radius: f32,
@@ -0,0 +1,153 @@
+//! Block collision broadphase
+//!
+//! Blocks are arcs at known radii and angles, so a ball can only touch
+//! blocks whose (padded) angular extent covers the ball's angle and whose
+//! radial band covers its distance from the center. `BlockGrid` bins block
+//! indices into fixed angular buckets so the narrowphase (`sd_arc`) only
+//! runs on those candidates instead of every block.
+
+use glam::Vec2;
+use std::f32::consts::{PI, TAU};
+
+/// Number of angular buckets around the arena
+pub const ANGLE_BUCKETS: usize = 32;
+
+/// Extra reach added to the query radius to absorb float error
+const REACH_PAD: f32 = 1.0;
+
+/// A block as seen by the broadphase
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    /// Index into the block list the grid was built from
+    index: usize,
+    /// Radial band the query point must fall in to be a candidate
+    r_min: f32,
+    r_max: f32,
+}
+
+/// Angular bucket grid over block arcs, rebuilt whenever the blocks change
+#[derive(Debug, Clone)]
+pub struct BlockGrid {
+    buckets: Vec<Vec<Entry>>,
+}
+
+impl BlockGrid {
+    /// Bin arcs given as `(theta_start, theta_end, radius, thickness)`.
+    ///
+    /// `reach` is the largest distance from a query point at which a block
+    /// must still be returned (normally the ball radius). Candidates in
+    /// each bucket keep the input order, so iterating them visits blocks
+    /// in the same order as a brute-force pass over all blocks.
+    pub fn build(arcs: impl IntoIterator<Item = (f32, f32, f32, f32)>, reach: f32) -> Self {
+        let reach = reach + REACH_PAD;
+        let mut buckets = vec![Vec::new(); ANGLE_BUCKETS];
+
+        for (index, (theta_start, theta_end, radius, thickness)) in arcs.into_iter().enumerate() {
+            let half_thick = thickness * 0.5;
+            let entry = Entry {
+                index,
+                r_min: radius - half_thick - reach,
+                r_max: radius + half_thick + reach,
+            };
+
+            // Span as measured by sd_arc (a zero span is a full ring)
+            let mut span = theta_end - theta_start;
+            span -= (span / TAU).round() * TAU;
+            if span <= 0.0 {
+                span += TAU;
+            }
+
+            // Angle a point within `reach` of the arc (or its round end caps)
+            // can be from the arc's angular extent
+            let inner = radius - half_thick;
+            let ratio = (half_thick + reach) / inner.max(f32::EPSILON);
+            let margin = if ratio < 1.0 { ratio.asin() } else { PI };
+
+            let covered = span + 2.0 * margin;
+            if covered >= TAU {
+                for bucket in &mut buckets {
+                    bucket.push(entry);
+                }
+                continue;
+            }
+
+            let first = Self::bucket_index(theta_start - margin);
+            let count = (covered / TAU * ANGLE_BUCKETS as f32).ceil() as usize + 1;
+            for i in 0..count.min(ANGLE_BUCKETS) {
+                buckets[(first + i) % ANGLE_BUCKETS].push(entry);
+            }
+        }
+
+        Self { buckets }
+    }
+
+    /// Bucket containing an angle (any range, wrapped to the circle)
+    fn bucket_index(theta: f32) -> usize {
+        let t = (theta + PI).rem_euclid(TAU) / TAU;
+        (t * ANGLE_BUCKETS as f32) as usize % ANGLE_BUCKETS
+    }
+
+    /// Indices of blocks that may be within `reach` of `pos`, in input order
+    pub fn candidates(&self, pos: Vec2) -> impl Iterator<Item = usize> + '_ {
+        let r = pos.length();
+        let bucket = &self.buckets[Self::bucket_index(pos.y.atan2(pos.x))];
+        bucket
+            .iter()
+            .filter(move |e| r >= e.r_min && r <= e.r_max)
+            .map(|e| e.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::{GameState, generate_wave, sd_arc};
+
+    #[test]
+    fn test_candidates_match_brute_force() {
+        let mut state = GameState::new(7);
+        state.wave_index = 12;
+        state.arena_radius = crate::sim::tick::arena_radius_for_wave(12, &state.tuning);
+        generate_wave(&mut state);
+        assert!(!state.blocks.is_empty());
+
+        let ball_radius = crate::consts::BALL_RADIUS;
+        let arcs: Vec<_> = state
+            .blocks
+            .iter()
+            .map(|b| {
+                (
+                    b.arc.theta_start,
+                    b.arc.theta_end,
+                    b.arc.radius,
+                    b.arc.thickness,
+                )
+            })
+            .collect();
+        let grid = BlockGrid::build(arcs.iter().copied(), ball_radius);
+
+        // Sweep points across the whole arena
+        for ri in 0..120 {
+            let r = 30.0 + ri as f32 * (state.arena_radius - 30.0) / 120.0;
+            for ai in 0..360 {
+                let theta = ai as f32 / 360.0 * TAU;
+                let pos = Vec2::new(theta.cos(), theta.sin()) * r;
+
+                let brute: Vec<usize> = arcs
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &(ts, te, rad, th))| sd_arc(pos, ts, te, rad, th) < ball_radius)
+                    .map(|(i, _)| i)
+                    .collect();
+                let grid_hits: Vec<usize> = grid
+                    .candidates(pos)
+                    .filter(|&i| {
+                        let (ts, te, rad, th) = arcs[i];
+                        sd_arc(pos, ts, te, rad, th) < ball_radius
+                    })
+                    .collect();
+                assert_eq!(brute, grid_hits, "mismatch at r={} theta={}", r, theta);
+            }
+        }
+    }
+}
@@ -7,17 +7,30 @@
 //! - No rendering or platform dependencies
 
 pub mod arc;
+pub mod broadphase;
 pub mod collision;
+pub mod predict;
+pub mod replay;
+pub mod rng;
 pub mod sdf;
 pub mod state;
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
 pub mod tick;
 
 pub use arc::ArcSegment;
+pub use broadphase::BlockGrid;
 pub use collision::{CollisionResult, ball_arc_collision};
-pub use sdf::{check_sdf_collision, raymarch_collision, reflect, sd_arc, sd_arena_wall, sd_circle};
+pub use predict::predict_path;
+pub use replay::{Recording, ReplayError, ReplayPlayer, ReplayRecorder};
+pub use sdf::{
+    check_sdf_collision, raymarch_collision, reflect, sd_arc, sd_arena_wall, sd_circle,
+    sd_inner_wall,
+};
 pub use state::{
     ARENA_GROWTH_PER_WAVE, ARENA_GROWTH_START_WAVE, BASE_ARENA_RADIUS, Ball, BallState, Block,
-    BlockKind, GameEvent, GamePhase, GameState, INNER_MARGIN, LAYER_SPACING, MAX_ARENA_RADIUS,
-    Paddle, PickupKind, WALL_MARGIN,
+    BlockKind, GameEvent, GamePhase, GameState, INNER_MARGIN, INNER_WALL_GAP_WIDTH,
+    INNER_WALL_RADIUS, INNER_WALL_THICKNESS, InnerWall, LAYER_SPACING, MAX_ARENA_RADIUS, Paddle,
+    PickupKind, Shockwave, TRAIL_LENGTH, WALL_MARGIN, WaveModifiers,
 };
-pub use tick::{TickInput, generate_wave, tick};
+pub use tick::{TickInput, generate_wave, preview_wave, tick, wave_modifiers_for};
@@ -0,0 +1,143 @@
+//! Trajectory preview
+//!
+//! Projects a free ball forward under the black hole's pull alone, for the
+//! optional predicted-path overlay. Works on a copy of the ball's position
+//! and velocity, so the game state is never touched. The path stops at the
+//! first thing the ball would reach: a block, the inner wall, the paddle
+//! ring, the arena wall or the black hole.
+//!
+//! The idle AI uses the same integration to lead a ball along its curve.
+
+use glam::Vec2;
+
+use super::state::{Ball, BallState, GameState};
+use super::tick::{black_hole_pull, gravity_accel};
+use crate::consts::*;
+
+/// Predicted positions of ball `ball_id`, one per tick for up to `ticks`
+/// ticks. The last point is where the ball first touches something. Empty
+/// if the ball doesn't exist or isn't in free flight.
+pub fn predict_path(state: &GameState, ball_id: u32, ticks: u32) -> Vec<Vec2> {
+    let Some(ball) = state
+        .balls
+        .iter()
+        .find(|b| b.id == ball_id && matches!(b.state, BallState::Free))
+    else {
+        return Vec::new();
+    };
+
+    let pull = black_hole_pull(state);
+    let paddle_outer = PADDLE_RADIUS + PADDLE_THICKNESS / 2.0;
+    let (mut pos, mut vel) = (ball.pos, ball.vel);
+    let mut path = Vec::with_capacity(ticks as usize);
+
+    for _ in 0..ticks {
+        vel += gravity_accel(pos, pull, &state.tuning.gravity) / ball.mass * SIM_DT;
+        pos += vel * SIM_DT;
+        path.push(pos);
+
+        let dist = pos.length();
+        let blocked = dist + ball.radius >= state.arena_radius
+            || dist <= BLACK_HOLE_LOSS_RADIUS
+            // Inbound balls meet the paddle ring (wherever the paddle is)
+            || (dist - ball.radius <= paddle_outer && vel.dot(pos) < 0.0)
+            || state
+                .inner_wall
+                .as_ref()
+                .is_some_and(|w| w.distance(pos) < ball.radius)
+            || state.blocks.iter().any(|b| {
+                super::sd_arc(
+                    pos,
+                    b.arc.theta_start,
+                    b.arc.theta_end,
+                    b.arc.radius,
+                    b.arc.thickness,
+                ) < ball.radius
+            });
+        if blocked {
+            break;
+        }
+    }
+    path
+}
+
+/// Where `ball` will be after `ticks` ticks under the black hole's pull,
+/// or where it first reaches the paddle ring if that's sooner. Ignores
+/// blocks and walls, so it's only good for short lookaheads.
+pub(super) fn curved_lead(state: &GameState, ball: &Ball, ticks: u32) -> Vec2 {
+    let pull = black_hole_pull(state);
+    let paddle_outer = PADDLE_RADIUS + PADDLE_THICKNESS / 2.0;
+    let (mut pos, mut vel) = (ball.pos, ball.vel);
+
+    for _ in 0..ticks {
+        vel += gravity_accel(pos, pull, &state.tuning.gravity) / ball.mass * SIM_DT;
+        pos += vel * SIM_DT;
+        if pos.length() - ball.radius <= paddle_outer {
+            break;
+        }
+    }
+    pos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::{ArcSegment, BlockKind, GamePhase};
+
+    #[test]
+    fn test_predict_path_matches_free_flight() {
+        let mut state = GameState::new(3)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, 200.0), Vec2::new(180.0, 0.0))
+            // Far block so the wave doesn't clear
+            .with_block(ArcSegment::new(380.0, 20.0, 3.0, 3.3), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        let ball_id = state.balls[0].id;
+        let before = serde_json::to_string(&state).unwrap();
+
+        let path = predict_path(&state, ball_id, 30);
+        assert_eq!(path.len(), 30);
+        // Preview never touches the state
+        assert_eq!(serde_json::to_string(&state).unwrap(), before);
+
+        // Gravity-only flight matches the sim while nothing is in the way
+        for expected in &path[..10] {
+            crate::sim::tick(&mut state, &crate::sim::TickInput::default(), SIM_DT);
+            let actual = state.balls[0].pos;
+            assert!(
+                actual.distance(*expected) < 0.5,
+                "{:?} vs {:?}",
+                actual,
+                expected
+            );
+        }
+
+        assert!(predict_path(&state, ball_id + 100, 30).is_empty());
+    }
+
+    #[test]
+    fn test_curved_lead_follows_gravity() {
+        let mut state = GameState::new(3)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, 150.0), Vec2::new(200.0, 0.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 3.0, 3.3), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        let ball = state.balls[0].clone();
+
+        // Bends inward of the straight-line guess, matching the sim
+        let lead = curved_lead(&state, &ball, 30);
+        let straight = ball.pos + ball.vel * SIM_DT * 30.0;
+        assert!(lead.length() < straight.length() - 5.0);
+        for _ in 0..30 {
+            crate::sim::tick(&mut state, &crate::sim::TickInput::default(), SIM_DT);
+        }
+        assert!(lead.distance(state.balls[0].pos) < 1.0);
+
+        // Stops at the paddle ring for a ball diving in
+        let diving = GameState::new(3)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, 80.0), Vec2::new(0.0, -300.0));
+        let lead = curved_lead(&diving, &diving.balls[0], 30);
+        assert!(lead.length() > BLACK_HOLE_LOSS_RADIUS);
+    }
+}
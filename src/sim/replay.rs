@@ -0,0 +1,256 @@
+//! Replay recording
+//!
+//! The sim is deterministic, so a run is fully described by its seed, its
+//! tuning and the input fed to every tick. A recording keeps only the ticks
+//! where the input changed, plus state hashes at regular checkpoints: a
+//! replay whose inputs were edited, or that was recorded by a different
+//! build of the sim, drifts away from those hashes and is refused.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::state::GameState;
+use super::tick::{TickInput, generate_wave, tick};
+use crate::consts::SIM_DT;
+use crate::tuning::TuningConfig;
+
+/// Ticks between state hash checkpoints (5 s)
+pub const REPLAY_CHECKPOINT_TICKS: u64 = 600;
+
+/// A recorded run: everything needed to play it back tick for tick
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    pub seed: u64,
+    pub tuning: TuningConfig,
+    /// `(tick, input)` for each tick whose input differs from the one before
+    pub inputs: Vec<(u64, TickInput)>,
+    /// `(tick, state hash after that tick)`; the last entry is the final tick
+    pub checkpoints: Vec<(u64, u64)>,
+    /// Number of ticks recorded
+    pub ticks: u64,
+}
+
+/// Why a recording can't be played back faithfully
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayError {
+    /// State hash after `tick` differs from the recorded one
+    Desync { tick: u64 },
+    /// Recording doesn't end on a checkpoint, so its end can't be verified
+    Unverified,
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Desync { tick } => write!(f, "replay diverged at tick {}", tick),
+            ReplayError::Unverified => write!(f, "replay has no final checkpoint"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl Recording {
+    /// Empty recording of a run started from `seed`
+    pub fn new(seed: u64, tuning: &TuningConfig) -> Self {
+        Self {
+            seed,
+            tuning: tuning.clone(),
+            inputs: Vec::new(),
+            checkpoints: Vec::new(),
+            ticks: 0,
+        }
+    }
+
+    /// The fresh run the recording starts from
+    pub fn start_state(&self) -> GameState {
+        let mut state = GameState::new(self.seed).with_tuning(self.tuning.clone());
+        generate_wave(&mut state);
+        state
+    }
+
+    /// Readable JSON form, for attaching to bug reports
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Parse a recording from `to_json` output. Call `verify` before
+    /// trusting it.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Play the whole recording headless and check every checkpoint
+    pub fn verify(&self) -> Result<(), ReplayError> {
+        if self.checkpoints.last().map(|&(tick, _)| tick + 1) != Some(self.ticks) {
+            return Err(ReplayError::Unverified);
+        }
+        let mut state = self.start_state();
+        let mut player = ReplayPlayer::new(self.clone());
+        while player.step(&mut state)? {}
+        Ok(())
+    }
+}
+
+/// Records a run as it's played. Call `record` after every tick, starting
+/// from the state `Recording::start_state` would build.
+#[derive(Debug, Clone)]
+pub struct ReplayRecorder {
+    recording: Recording,
+    last_input: Option<TickInput>,
+}
+
+impl ReplayRecorder {
+    pub fn new(seed: u64, tuning: &TuningConfig) -> Self {
+        Self {
+            recording: Recording::new(seed, tuning),
+            last_input: None,
+        }
+    }
+
+    /// Note the input of the tick just run and the state it left behind
+    pub fn record(&mut self, input: &TickInput, state: &GameState) {
+        let tick = self.recording.ticks;
+        if self.last_input.as_ref() != Some(input) {
+            self.recording.inputs.push((tick, input.clone()));
+            self.last_input = Some(input.clone());
+        }
+        self.recording.ticks += 1;
+        if self.recording.ticks.is_multiple_of(REPLAY_CHECKPOINT_TICKS) {
+            self.recording.checkpoints.push((tick, state.state_hash()));
+        }
+    }
+
+    /// End the recording with a checkpoint on its final state
+    pub fn finish(mut self, state: &GameState) -> Recording {
+        let last_tick = self.recording.ticks.saturating_sub(1);
+        if self.recording.checkpoints.last().map(|&(tick, _)| tick) != Some(last_tick) {
+            self.recording
+                .checkpoints
+                .push((last_tick, state.state_hash()));
+        }
+        self.recording
+    }
+}
+
+/// Feeds a recording's inputs back into `tick`, checking checkpoints as it
+/// passes them
+#[derive(Debug, Clone)]
+pub struct ReplayPlayer {
+    recording: Recording,
+    tick: u64,
+    input: TickInput,
+    next_input: usize,
+    next_checkpoint: usize,
+}
+
+impl ReplayPlayer {
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            recording,
+            tick: 0,
+            input: TickInput::default(),
+            next_input: 0,
+            next_checkpoint: 0,
+        }
+    }
+
+    /// Advance `state` by one recorded tick. Returns `Ok(false)` once the
+    /// recording has run out.
+    pub fn step(&mut self, state: &mut GameState) -> Result<bool, ReplayError> {
+        if self.tick >= self.recording.ticks {
+            return Ok(false);
+        }
+        if let Some((at, input)) = self.recording.inputs.get(self.next_input)
+            && *at == self.tick
+        {
+            self.input = input.clone();
+            self.next_input += 1;
+        }
+
+        tick(state, &self.input, SIM_DT);
+
+        if let Some(&(at, hash)) = self.recording.checkpoints.get(self.next_checkpoint)
+            && at == self.tick
+        {
+            self.next_checkpoint += 1;
+            if state.state_hash() != hash {
+                return Err(ReplayError::Desync { tick: at });
+            }
+        }
+        self.tick += 1;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plays `ticks` ticks of a fresh run, steering and launching now and
+    /// then, and returns the recording with the final state
+    fn record_session(ticks: u64) -> (Recording, GameState) {
+        let tuning = TuningConfig::default();
+        let mut recorder = ReplayRecorder::new(5, &tuning);
+        let mut state = Recording::new(5, &tuning).start_state();
+        for i in 0..ticks {
+            let input = TickInput {
+                target_theta: Some((i / 40) as f32 * 0.3),
+                launch: i % 300 == 10,
+                ..Default::default()
+            };
+            tick(&mut state, &input, SIM_DT);
+            recorder.record(&input, &state);
+        }
+        (recorder.finish(&state), state)
+    }
+
+    #[test]
+    fn test_replay_verifies_and_refuses_edits() {
+        let (recording, original) = record_session(1300);
+        assert_eq!(recording.ticks, 1300);
+        // Sparse: inputs change every 40 ticks, not every tick
+        assert!(recording.inputs.len() < 100, "{}", recording.inputs.len());
+        assert_eq!(recording.checkpoints.len(), 3);
+        recording.verify().unwrap();
+
+        // Playback lands on the same state
+        let mut state = recording.start_state();
+        let mut player = ReplayPlayer::new(recording.clone());
+        while player.step(&mut state).unwrap() {}
+        assert_eq!(state.state_hash(), original.state_hash());
+
+        // An input edited after the fact no longer matches the hashes
+        let mut edited = recording.clone();
+        assert_eq!(edited.inputs[1].0, 10);
+        edited.inputs[1].1.launch = false;
+        assert!(matches!(edited.verify(), Err(ReplayError::Desync { .. })));
+
+        let mut cut = recording;
+        cut.checkpoints.pop();
+        assert_eq!(cut.verify(), Err(ReplayError::Unverified));
+    }
+
+    #[test]
+    fn test_replay_json_matches_original_run() {
+        let (recording, original) = record_session(500);
+        assert!(original.score > 0);
+
+        let json = recording.to_json().unwrap();
+        let loaded = Recording::from_json(&json).unwrap();
+        assert_eq!(loaded, recording);
+
+        let mut state = loaded.start_state();
+        let mut player = ReplayPlayer::new(loaded);
+        while player.step(&mut state).unwrap() {}
+        assert_eq!(state.score, original.score);
+        assert_eq!(state.wave_index, original.wave_index);
+        assert_eq!(
+            state.paddle.theta.to_bits(),
+            original.paddle.theta.to_bits()
+        );
+
+        assert!(Recording::from_json(&json[..json.len() / 2]).is_err());
+    }
+}
@@ -0,0 +1,63 @@
+//! Deterministic hashing for sim "randomness"
+//!
+//! The sim never draws from a stateful RNG. `GameState::rng_state` is
+//! saved with the run but nothing advances it; every random-looking value
+//! is a hash of something already in the state (tick, block id, wave
+//! seed), so a run replays identically from its seed and inputs. Effects draw through
+//! `hash_u32`/`rand_f32`; gameplay rolls (wave seed, chaos, inner walls,
+//! pickup drops, portal exits) keep their own mixing on top so existing
+//! seeds still lay out and play the same waves.
+
+/// Multiplicative hash constant (2^32 / golden ratio)
+pub const GOLDEN: u32 = 2654435761;
+
+/// Prime stride between consecutive salts
+pub const SALT_STRIDE: u32 = 7919;
+
+/// Hash `seed` for the `salt`-th draw: `seed * GOLDEN + salt * SALT_STRIDE`
+pub fn hash_u32(seed: u32, salt: u32) -> u32 {
+    seed.wrapping_mul(GOLDEN)
+        .wrapping_add(salt.wrapping_mul(SALT_STRIDE))
+}
+
+/// Avalanche finalizer (MurmurHash3 fmix32): every input bit flips about
+/// half the output bits, so ids that differ by a fixed step stop producing
+/// hashes that differ by a fixed step
+pub fn fmix32(mut hash: u32) -> u32 {
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^ (hash >> 16)
+}
+
+/// Value in [0, 1) from the low digits of a hash (1/1000 steps). Shift or
+/// divide the hash to draw further values from the same one.
+pub fn rand_f32(hash: u32) -> f32 {
+    (hash % 1000) as f32 / 1000.0
+}
+
+/// Three more hashes derived from `hash` by successive LCG steps, for
+/// drawing several independent values (angle, speed, size) from one draw
+pub fn rehash3(hash: u32) -> [u32; 3] {
+    let a = hash.wrapping_mul(1664525).wrapping_add(1013904223);
+    let b = a.wrapping_mul(22695477).wrapping_add(1);
+    let c = b.wrapping_mul(69069).wrapping_add(1);
+    [a, b, c]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_matches_inline_formula() {
+        // Particle and layer hashes were written inline before this module;
+        // the helper must keep producing the same values
+        for (seed, salt) in [(0, 0), (7, 3), (123_456, 40), (u32::MAX, 59)] {
+            let inline = seed.wrapping_mul(2654435761).wrapping_add(salt * 7919);
+            assert_eq!(hash_u32(seed, salt), inline);
+        }
+        assert!((0.0..1.0).contains(&rand_f32(u32::MAX)));
+    }
+}
@@ -11,10 +11,8 @@ pub fn sd_circle(p: Vec2, center: Vec2, radius: f32) -> f32 {
     (p - center).length() - radius
 }
 
-/// Signed distance to an arc segment
-/// Returns distance to the arc band (inner to outer radius, theta_start to theta_end)
-pub fn sd_arc(p: Vec2, theta_start: f32, theta_end: f32, radius: f32, thickness: f32) -> f32 {
-    let r = p.length();
+/// Whether the angle of `p` falls within the arc's angular span
+fn in_arc_span(p: Vec2, theta_start: f32, theta_end: f32) -> bool {
     let angle = p.y.atan2(p.x);
 
     // Normalize angle difference
@@ -33,7 +31,14 @@ pub fn sd_arc(p: Vec2, theta_start: f32, theta_end: f32, radius: f32, thickness:
         angle_diff += TAU;
     }
 
-    let in_arc = angle_diff <= span;
+    angle_diff <= span
+}
+
+/// Signed distance to an arc segment
+/// Returns distance to the arc band (inner to outer radius, theta_start to theta_end)
+pub fn sd_arc(p: Vec2, theta_start: f32, theta_end: f32, radius: f32, thickness: f32) -> f32 {
+    let r = p.length();
+    let in_arc = in_arc_span(p, theta_start, theta_end);
     let half_thick = thickness * 0.5;
 
     if in_arc {
@@ -49,12 +54,82 @@ pub fn sd_arc(p: Vec2, theta_start: f32, theta_end: f32, radius: f32, thickness:
     }
 }
 
+/// Outward surface normal of `sd_arc` at `p`, computed analytically.
+///
+/// Central differences straddle the seam between the band and the rounded
+/// end caps, so near an arc tip they blend the radial and cap normals and a
+/// grazing ball can be pushed into the block. Here the band gives a radial
+/// normal and each cap the direction from its center.
+pub fn sd_arc_normal(
+    p: Vec2,
+    theta_start: f32,
+    theta_end: f32,
+    radius: f32,
+    thickness: f32,
+) -> Vec2 {
+    let radial = p.normalize_or_zero();
+    if in_arc_span(p, theta_start, theta_end) {
+        return if p.length() >= radius {
+            radial
+        } else {
+            -radial
+        };
+    }
+
+    let p1 = Vec2::new(theta_start.cos(), theta_start.sin()) * radius;
+    let p2 = Vec2::new(theta_end.cos(), theta_end.sin()) * radius;
+    let d1 = (p - p1).length() - thickness * 0.5;
+    let d2 = (p - p2).length() - thickness * 0.5;
+    let (cap, away) = if d1 <= d2 {
+        // Past the start: away from the arc is clockwise
+        (p1, Vec2::new(theta_start.sin(), -theta_start.cos()))
+    } else {
+        (p2, Vec2::new(-theta_end.sin(), theta_end.cos()))
+    };
+    // At the cap center the direction is undefined; push off the tip
+    (p - cap).try_normalize().unwrap_or(away)
+}
+
 /// Signed distance to arena outer wall
 #[inline]
 pub fn sd_arena_wall(p: Vec2, arena_radius: f32) -> f32 {
     p.length() - arena_radius
 }
 
+/// Signed distance to a ring wall of `thickness` centered on `radius`, with
+/// `gaps` evenly spaced openings of `gap_width` radians. The first opening
+/// is centered on `rotation`. Opening edges are rounded like arc end caps.
+pub fn sd_inner_wall(
+    p: Vec2,
+    radius: f32,
+    thickness: f32,
+    gaps: u32,
+    rotation: f32,
+    gap_width: f32,
+) -> f32 {
+    let half_thick = thickness * 0.5;
+    let ring = sd_circle(p, Vec2::ZERO, radius).abs() - half_thick;
+    if gaps == 0 {
+        return ring;
+    }
+
+    // Angle relative to the nearest opening center
+    let sector = TAU / gaps as f32;
+    let angle = p.y.atan2(p.x) - rotation;
+    let nearest = (angle / sector).round() * sector;
+    let offset = angle - nearest;
+
+    let half_gap = gap_width * 0.5;
+    if offset.abs() >= half_gap {
+        return ring;
+    }
+
+    // Inside an opening: distance to the wall end on the nearer side
+    let edge = rotation + nearest + if offset < 0.0 { -half_gap } else { half_gap };
+    let cap = Vec2::new(edge.cos(), edge.sin()) * radius;
+    (p - cap).length() - half_thick
+}
+
 /// Compute SDF gradient (surface normal) using central differences
 pub fn sdf_gradient<F>(p: Vec2, sdf: F) -> Vec2
 where
@@ -9,6 +9,11 @@ use serde::{Deserialize, Serialize};
 
 use super::arc::ArcSegment;
 use crate::consts::*;
+use crate::tuning::TuningConfig;
+use crate::tuning::params::{
+    HEAVY_BALL_DAMAGE, HEAVY_BALL_MASS, MERCY_TICKS, MUSIC_COMBO_WEIGHT, MUSIC_INTENSITY_COMBO,
+    MUSIC_INTENSITY_WAVES, MUSIC_WAVE_WEIGHT, TIME_WARP_SCALE,
+};
 use crate::{normalize_angle, polar_to_cartesian};
 
 /// Current phase of gameplay
@@ -27,26 +32,60 @@ pub enum GamePhase {
 }
 
 /// Game events for audio/visual feedback (not serialized)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GameEvent {
-    /// Ball hit paddle
-    PaddleHit,
-    /// Ball hit wall
-    WallHit,
-    /// Ball hit block (didn't break)
-    BlockHit,
-    /// Block destroyed
-    BlockBreak(BlockKind),
-    /// Pickup collected
-    PickupCollect,
+    /// Ball hit paddle (ball position)
+    PaddleHit(Vec2),
+    /// Ball saved right at the paddle edge (also sends PaddleHit)
+    NearDeathSave,
+    /// Ball hit wall (ball position)
+    WallHit(Vec2),
+    /// Ball hit block without breaking it (ball position)
+    BlockHit(Vec2),
+    /// Block destroyed (kind, arc center)
+    BlockBreak(BlockKind, Vec2),
+    /// Ball kicked off a bumper (contact point)
+    BumperHit(Vec2),
+    /// Pickup collected (pickup position)
+    PickupCollect(Vec2),
     /// Ball lost to black hole
     BallLost,
+    /// Shield bounced a ball back out of the black hole
+    ShieldSave,
+    /// Points awarded together, e.g. a whole explosion chain (for a floater)
+    Score { amount: u64, pos: Vec2 },
+    /// Combo of at least `COMBO_BREAK_MIN` reset by a lost ball
+    ComboBreak { previous: u32 },
+    /// Combo reached one of `COMBO_MILESTONES` (once until the combo resets)
+    ComboMilestone(u32),
+    /// Every clearable block on a ring destroyed (bonus points)
+    RingClear { ring_id: u32 },
     /// Wave cleared
     WaveClear,
+    /// Wave cleared without collecting a pickup (bonus points)
+    PuristBonus { amount: u64 },
     /// Ball launched
     Launch,
     /// Game over
     GameOver,
+    /// Achievement unlocked (id from the achievements table)
+    Achievement(&'static str),
+}
+
+impl GameEvent {
+    /// Where in the arena the event happened, if it has a place
+    pub fn pos(&self) -> Option<Vec2> {
+        match *self {
+            GameEvent::PaddleHit(pos)
+            | GameEvent::WallHit(pos)
+            | GameEvent::BlockHit(pos)
+            | GameEvent::BlockBreak(_, pos)
+            | GameEvent::BumperHit(pos)
+            | GameEvent::PickupCollect(pos)
+            | GameEvent::Score { pos, .. } => Some(pos),
+            _ => None,
+        }
+    }
 }
 
 /// Ball state - attached to paddle or free-moving
@@ -79,7 +118,7 @@ pub struct TrailPoint {
     pub speed: f32,
 }
 
-/// Maximum number of trail points to store
+/// Default number of trail points to store (see `TuningConfig::trail_length`)
 pub const TRAIL_LENGTH: usize = 40;
 
 /// A ball entity
@@ -104,6 +143,33 @@ pub struct Ball {
     /// Electric charge (0.0 = none, 1.0 = fully charged, decays over ~3 seconds)
     #[serde(default)]
     pub electric_charge: f32,
+    /// Curve from a sweeping paddle hit (signed, + bends CCW, decays over time)
+    #[serde(default)]
+    pub curve: f32,
+    /// Visual spin from paddle english (rad/s, + is CCW); cosmetic only
+    #[serde(skip)]
+    pub spin: f32,
+    /// Rotation of the spin marker (radians)
+    #[serde(skip)]
+    pub spin_angle: f32,
+    /// Bumper kicks since the ball last hit the paddle or a breakable block
+    #[serde(default)]
+    pub bumper_chain: u8,
+    /// Mass (1.0 = normal). Heavy balls are larger, pulled less by gravity
+    /// and hit harder.
+    #[serde(default = "default_mass")]
+    pub mass: f32,
+    /// Passes through the next breakable block it hits instead of bouncing
+    #[serde(default)]
+    pub plow_ready: bool,
+    /// Block being plowed through; it isn't bounced off or hit again until
+    /// the ball is clear of it
+    #[serde(default)]
+    pub plowing: Option<u32>,
+}
+
+fn default_mass() -> f32 {
+    1.0
 }
 
 impl Ball {
@@ -119,11 +185,50 @@ impl Ball {
             inside_portals: Vec::new(),
             trail: Vec::with_capacity(TRAIL_LENGTH),
             electric_charge: 0.0,
+            curve: 0.0,
+            spin: 0.0,
+            spin_angle: 0.0,
+            bumper_chain: 0,
+            mass: 1.0,
+            plow_ready: false,
+            plowing: None,
+        }
+    }
+
+    /// Resting radius for the ball's mass
+    pub fn base_radius(&self) -> f32 {
+        BALL_RADIUS * self.mass.sqrt()
+    }
+
+    /// Rough seconds until the ball reaches the loss radius at its current
+    /// inward speed. Infinite for a ball that isn't heading inward.
+    pub fn time_to_impact(&self) -> f32 {
+        let inward_speed = self.vel.dot(-self.pos.normalize_or_zero());
+        if inward_speed <= 0.0 {
+            return f32::INFINITY;
+        }
+        (self.pos.length() - BLACK_HOLE_LOSS_RADIUS).max(0.0) / inward_speed
+    }
+
+    /// HP a block loses when this ball hits it
+    pub fn block_damage(&self) -> u8 {
+        if self.mass > 1.0 {
+            HEAVY_BALL_DAMAGE
+        } else {
+            1
         }
     }
 
-    /// Record current position to trail (call each tick when free)
-    pub fn record_trail(&mut self) {
+    /// Turn into a heavy ball that plows through the next block it hits
+    pub fn make_heavy(&mut self) {
+        self.mass = HEAVY_BALL_MASS;
+        self.radius = self.base_radius();
+        self.plow_ready = true;
+    }
+
+    /// Record current position to trail (call each tick when free),
+    /// keeping at most `max_len` points
+    pub fn record_trail(&mut self, max_len: usize) {
         let speed = self.vel.length();
         self.trail.insert(
             0,
@@ -132,9 +237,7 @@ impl Ball {
                 speed,
             },
         );
-        if self.trail.len() > TRAIL_LENGTH {
-            self.trail.pop();
-        }
+        self.trail.truncate(max_len);
     }
 
     /// Clear trail (on respawn/attach)
@@ -162,14 +265,14 @@ impl Ball {
             let tangent = Vec2::new(-launch_theta.sin(), launch_theta.cos());
             let english = (paddle.angular_vel * english_factor).clamp(-0.3, 0.3);
 
-            self.vel = (radial_dir + tangent * english).normalize() * base_speed;
+            self.vel = (radial_dir + tangent * english).normalize_or(radial_dir) * base_speed;
             self.state = BallState::Free;
         }
     }
 }
 
 /// The player's paddle
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Paddle {
     /// Current angle (radians, center of paddle arc)
     pub theta: f32,
@@ -221,9 +324,15 @@ impl Paddle {
         let max_delta = max_speed * dt;
         let clamped_delta = delta.clamp(-max_delta, max_delta);
 
-        self.angular_vel = clamped_delta / dt;
+        self.angular_vel = if dt > 0.0 { clamped_delta / dt } else { 0.0 };
         self.theta = normalize_angle(self.theta + clamped_delta);
     }
+
+    /// Rotate at a fixed angular speed (rad/s, positive = counter-clockwise)
+    pub fn spin(&mut self, angular_vel: f32, dt: f32) {
+        self.angular_vel = angular_vel;
+        self.theta = normalize_angle(self.theta + angular_vel * dt);
+    }
 }
 
 /// Block types
@@ -246,10 +355,37 @@ pub enum BlockKind {
     Magnet,
     /// Ghost - fades in/out, only hittable when visible
     Ghost,
+    /// Bumper - kicks the ball away faster, never breaks, doesn't count for
+    /// wave clear
+    Bumper,
+}
+
+impl BlockKind {
+    /// Can't be damaged (and so never breaks)
+    pub fn is_indestructible(self) -> bool {
+        matches!(self, BlockKind::Invincible | BlockKind::Bumper)
+    }
+
+    /// Index into the shader's block and particle palettes
+    pub fn color_index(self) -> u32 {
+        match self {
+            BlockKind::Glass => 0,
+            BlockKind::Armored => 1,
+            BlockKind::Explosive => 2,
+            BlockKind::Invincible => 3,
+            BlockKind::Portal { .. } => 4,
+            BlockKind::Jello => 5,
+            BlockKind::Crystal => 6,
+            BlockKind::Electric => 7,
+            BlockKind::Magnet => 8,
+            BlockKind::Ghost => 9,
+            BlockKind::Bumper => 10,
+        }
+    }
 }
 
 /// A block entity (curved arc)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Block {
     pub id: u32,
     pub kind: BlockKind,
@@ -270,19 +406,32 @@ pub struct Block {
     /// Ring/layer index (for electric arc connections)
     #[serde(default)]
     pub ring_id: u32,
+    /// Active magnet poles (`POLE_RED | POLE_SILVER`), refreshed every tick
+    #[serde(default = "default_pole_flags")]
+    pub pole_flags: u32,
+    /// Angle still to travel in the current shuffle (radians, Shuffle waves)
+    #[serde(default)]
+    pub shuffle_remaining: f32,
 }
 
 fn default_visibility() -> f32 {
     1.0
 }
 
+fn default_pole_flags() -> u32 {
+    Block::POLE_RED | Block::POLE_SILVER
+}
+
 impl Block {
+    /// Red pole (theta_start end) is active and pulls the ball
+    pub const POLE_RED: u32 = 1;
+    /// Silver pole (theta_end end) is active and pushes the ball
+    pub const POLE_SILVER: u32 = 2;
+
     /// Rotate the block by its rotation speed * dt, decay wobble, update ghost visibility
     pub fn rotate(&mut self, dt: f32, time: f32) {
         if self.rotation_speed != 0.0 {
-            let delta = self.rotation_speed * dt;
-            self.arc.theta_start = normalize_angle(self.arc.theta_start + delta);
-            self.arc.theta_end = normalize_angle(self.arc.theta_end + delta);
+            self.turn(self.rotation_speed * dt);
         }
         // Decay wobble over time (fast decay for snappy feel)
         if self.wobble > 0.0 {
@@ -297,6 +446,12 @@ impl Block {
         }
     }
 
+    /// Move the block around the arena by `delta` radians
+    pub fn turn(&mut self, delta: f32) {
+        self.arc.theta_start = normalize_angle(self.arc.theta_start + delta);
+        self.arc.theta_end = normalize_angle(self.arc.theta_end + delta);
+    }
+
     /// Check if this ghost block is solid enough to be hit
     pub fn is_hittable(&self) -> bool {
         if self.kind == BlockKind::Ghost {
@@ -315,7 +470,110 @@ impl Block {
 
     /// Returns true if this block must be destroyed to clear the wave
     pub fn counts_for_clear(&self) -> bool {
-        self.kind != BlockKind::Invincible
+        !self.kind.is_indestructible()
+    }
+}
+
+/// Blast ring from a broken explosive block. The neighbors caught in the
+/// blast are fixed when it goes off; each takes its damage once the ring
+/// grows past it, so a chain reaction ripples outward instead of landing
+/// all at once.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shockwave {
+    pub center: Vec2,
+    pub radius: f32,
+    /// Growth rate (pixels/s)
+    pub expand_rate: f32,
+    /// IDs of blocks the ring hasn't reached yet
+    pub pending: Vec<u32>,
+    /// Score from blocks this ring has broken, not yet announced
+    #[serde(default)]
+    pub scored: u64,
+}
+
+/// Optional inner ring wall between the paddle band and the innermost
+/// blocks. Balls bounce off both faces; evenly spaced openings rotate
+/// around the ring and are the only way through to the paddle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InnerWall {
+    /// Ring center-line radius
+    pub radius: f32,
+    /// Number of evenly spaced openings
+    pub gaps: u32,
+    /// Angle of the first opening's center (radians)
+    pub rotation: f32,
+    /// Rotation speed (rad/s, sign = direction)
+    pub spin: f32,
+}
+
+impl InnerWall {
+    pub fn new(gaps: u32, spin: f32) -> Self {
+        Self {
+            radius: INNER_WALL_RADIUS,
+            gaps,
+            rotation: 0.0,
+            spin,
+        }
+    }
+
+    /// Advance the openings
+    pub fn rotate(&mut self, dt: f32) {
+        self.rotation = normalize_angle(self.rotation + self.spin * dt);
+    }
+
+    /// Signed distance from a point to the wall surface
+    pub fn distance(&self, p: Vec2) -> f32 {
+        super::sdf::sd_inner_wall(
+            p,
+            self.radius,
+            INNER_WALL_THICKNESS,
+            self.gaps,
+            self.rotation,
+            INNER_WALL_GAP_WIDTH,
+        )
+    }
+}
+
+/// Chaos modifiers active for a wave (bit flags)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct WaveModifiers(pub u32);
+
+impl WaveModifiers {
+    pub const NONE: Self = Self(0);
+    /// Black hole pushes balls outward instead of pulling them in
+    pub const REVERSED_GRAVITY: Self = Self(1);
+    /// Ball speed limits doubled
+    pub const DOUBLE_SPEED: Self = Self(2);
+    /// Blocks only drawn near a ball (bit value shared with `sdf_shader.wgsl`)
+    pub const INVISIBLE_BLOCKS: Self = Self(4);
+    /// Slowly shifting tangential wind pushes balls around the arena
+    pub const WIND: Self = Self(8);
+    /// Rings periodically glide to new angles
+    pub const SHUFFLE: Self = Self(16);
+
+    /// Every modifier with its display name
+    pub const ALL: [(Self, &'static str); 5] = [
+        (Self::REVERSED_GRAVITY, "Reversed Gravity"),
+        (Self::DOUBLE_SPEED, "Double Speed"),
+        (Self::INVISIBLE_BLOCKS, "Invisible Blocks"),
+        (Self::WIND, "Wind"),
+        (Self::SHUFFLE, "Shuffle"),
+    ];
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Display names of the active modifiers
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        Self::ALL
+            .into_iter()
+            .filter(move |&(modifier, _)| self.contains(modifier))
+            .map(|(_, name)| name)
     }
 }
 
@@ -327,10 +585,19 @@ pub enum PickupKind {
     Piercing,
     WidenPaddle,
     Shield,
+    /// Halts block rotation and black hole gravity for a few seconds
+    Freeze,
+    /// The next ball to hit the paddle sticks to it until relaunched
+    Sticky,
+    /// Makes the balls in play heavy for the rest of their life
+    HeavyBall,
+    /// Slows the whole world (balls, blocks, particles) for a few seconds
+    /// while the paddle keeps full speed
+    TimeWarp,
 }
 
 /// A pickup entity
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Pickup {
     pub id: u32,
     pub kind: PickupKind,
@@ -340,13 +607,31 @@ pub struct Pickup {
 }
 
 /// Active power-up effects
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ActiveEffects {
     pub slow_ticks: u32,
     pub piercing_ticks: u32,
     pub widen_ticks: u32,
     pub widen_stacks: u32, // Number of stacked widen powerups (+50% each)
     pub shield_active: bool,
+    #[serde(default)]
+    pub freeze_ticks: u32,
+    /// Next paddle hit catches the ball mid-wave (used up by the catch)
+    #[serde(default)]
+    pub sticky_active: bool,
+    #[serde(default)]
+    pub time_warp_ticks: u32,
+}
+
+impl ActiveEffects {
+    /// How fast the world runs this tick (1.0 normal, slower under Time Warp)
+    pub fn time_scale(&self) -> f32 {
+        if self.time_warp_ticks > 0 {
+            TIME_WARP_SCALE
+        } else {
+            1.0
+        }
+    }
 }
 
 /// A particle for visual effects
@@ -359,8 +644,93 @@ pub struct Particle {
     pub size: f32,
 }
 
+impl Particle {
+    /// Color code for scorch marks left by fast balls
+    pub const SCORCH: u32 = 102;
+
+    /// Lingering scorch mark (stays put and fades slowly)
+    pub fn is_scorch(&self) -> bool {
+        self.color == Self::SCORCH
+    }
+
+    /// Advance one step: drift toward the black hole and fade out
+    pub fn update(&mut self, dt: f32) {
+        if self.is_scorch() {
+            self.life -= dt * SCORCH_FADE_RATE;
+            return;
+        }
+        // Apply velocity
+        self.pos += self.vel * dt;
+        // Gravity toward black hole (weaker than ball)
+        let to_center = -self.pos.normalize_or_zero();
+        self.vel += to_center * 50.0 * dt;
+        // Drag to slow down
+        self.vel *= 0.98;
+        // Decay life
+        self.life -= dt * 1.5; // ~0.67 second lifetime
+        // Shrink as they die
+        self.size *= 0.995;
+    }
+}
+
 /// Maximum particles
 pub const MAX_PARTICLES: usize = 256;
+/// Fixed-size particle store. Once full, each new particle overwrites the
+/// oldest one, so big chain reactions never shift the whole buffer.
+#[derive(Debug, Clone, Default)]
+pub struct ParticlePool {
+    particles: Vec<Particle>,
+    /// Index of the oldest particle once the pool is full
+    oldest: usize,
+}
+
+impl ParticlePool {
+    /// Add a particle, replacing the oldest if the pool is full
+    pub fn push(&mut self, particle: Particle) {
+        if self.particles.len() < MAX_PARTICLES {
+            self.particles.push(particle);
+        } else {
+            self.particles[self.oldest] = particle;
+            self.oldest = (self.oldest + 1) % MAX_PARTICLES;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    /// Particles from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &Particle> {
+        let (newer, older) = self.particles.split_at(self.oldest);
+        older.iter().chain(newer)
+    }
+
+    /// Particles from oldest to newest
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Particle> {
+        let (newer, older) = self.particles.split_at_mut(self.oldest);
+        older.iter_mut().chain(newer)
+    }
+
+    /// Keep only the particles `keep` returns true for, in age order
+    pub fn retain(&mut self, keep: impl FnMut(&Particle) -> bool) {
+        self.particles.rotate_left(self.oldest);
+        self.oldest = 0;
+        self.particles.retain(keep);
+    }
+}
+
+/// Most scorch marks alive at once (leaves room for block debris)
+pub const MAX_SCORCH_PARTICLES: usize = MAX_PARTICLES / 4;
+/// Fraction of BALL_MAX_SPEED above which balls leave scorch marks
+pub const SCORCH_SPEED_FRACTION: f32 = 0.85;
+/// Ticks between scorch marks along a fast ball's trail
+pub const SCORCH_INTERVAL_TICKS: u64 = 4;
+/// Scorch life lost per second (~2.5 second lifetime)
+pub const SCORCH_FADE_RATE: f32 = 0.4;
 
 /// RNG state wrapper for serialization
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -393,6 +763,23 @@ pub const LAYER_SPACING: f32 = 55.0;
 pub const WALL_MARGIN: f32 = 25.0;
 /// Minimum distance from black hole for innermost blocks
 pub const INNER_MARGIN: f32 = 120.0;
+/// Inner ring wall radius (clear of the paddle band and the innermost blocks)
+pub const INNER_WALL_RADIUS: f32 = 80.0;
+/// Inner ring wall thickness
+pub const INNER_WALL_THICKNESS: f32 = 6.0;
+/// Angular width of each inner wall opening (radians)
+pub const INNER_WALL_GAP_WIDTH: f32 = 0.6;
+
+// The inner wall must leave room for a ball on both sides: above the paddle
+// (which orbits just outside the black hole) and below the thickest
+// innermost block
+const _: () = {
+    let half = INNER_WALL_THICKNESS * 0.5;
+    let paddle_outer = PADDLE_RADIUS + PADDLE_THICKNESS * 0.5;
+    let block_inner = INNER_MARGIN - BLOCK_THICKNESS * 1.5 * 0.5;
+    assert!(INNER_WALL_RADIUS - half - paddle_outer > BALL_RADIUS * 2.0);
+    assert!(block_inner - (INNER_WALL_RADIUS + half) > BALL_RADIUS * 2.0);
+};
 
 /// Complete game state (deterministic, serializable)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -409,6 +796,9 @@ pub struct GameState {
     pub score: u64,
     /// Combo counter
     pub combo: u32,
+    /// Highest combo milestone announced since the combo last reset
+    #[serde(default)]
+    pub combo_milestone: u32,
     /// Simulation tick counter
     pub time_ticks: u64,
     /// Current phase
@@ -418,6 +808,45 @@ pub struct GameState {
     /// Current arena outer radius (grows with waves)
     #[serde(default = "default_arena_radius")]
     pub arena_radius: f32,
+    /// Clearable blocks the current wave started with (for progress display)
+    #[serde(default)]
+    pub wave_total_blocks: u32,
+    /// The run's single revive has been spent
+    #[serde(default)]
+    pub revive_used: bool,
+    /// Playing ticks left in the post-respawn mercy window
+    #[serde(default)]
+    pub mercy_ticks: u32,
+    /// Layers past the generator's cap this wave (see `endless_scaling`)
+    #[serde(default)]
+    pub endless_level: u32,
+    /// Pacing parameters for this run
+    #[serde(default)]
+    pub tuning: TuningConfig,
+    /// Inner ring wall for the current wave, if any
+    #[serde(default)]
+    pub inner_wall: Option<InnerWall>,
+    /// Chaos modifiers for the current wave
+    #[serde(default)]
+    pub wave_modifiers: WaveModifiers,
+    /// Peak tangential wind acceleration this wave (pixels/s², 0 = calm)
+    #[serde(default)]
+    pub wind_strength: f32,
+    /// Wind cycle phase (radians); the wind blows `wind_strength * sin(phase)`
+    #[serde(default)]
+    pub wind_phase: f32,
+    /// Playing ticks into the current Shuffle wave (drives the shuffle timer)
+    #[serde(default)]
+    pub shuffle_ticks: u32,
+    /// Pickups collected since the current wave started (purist bonus)
+    #[serde(default)]
+    pub pickups_collected_this_wave: u32,
+    /// The current life's ball catch hasn't been used yet
+    #[serde(default = "default_true")]
+    pub catch_available: bool,
+    /// A catch is armed and waits for the next paddle hit
+    #[serde(default)]
+    pub catch_armed: bool,
     /// Player paddle
     pub paddle: Paddle,
     /// Active balls (sorted by id for determinism)
@@ -428,9 +857,12 @@ pub struct GameState {
     pub pickups: Vec<Pickup>,
     /// Active power-up effects
     pub effects: ActiveEffects,
+    /// Expanding explosion rings with damage still to deliver
+    #[serde(default)]
+    pub shockwaves: Vec<Shockwave>,
     /// Visual particles (not gameplay-affecting)
     #[serde(skip)]
-    pub particles: Vec<Particle>,
+    pub particles: ParticlePool,
     /// Screen shake intensity (0.0-1.0, decays over time)
     #[serde(skip)]
     pub screen_shake: f32,
@@ -448,6 +880,10 @@ fn default_arena_radius() -> f32 {
     BASE_ARENA_RADIUS
 }
 
+fn default_true() -> bool {
+    true
+}
+
 impl GameState {
     /// Create a new game state with the given seed
     pub fn new(seed: u64) -> Self {
@@ -458,16 +894,31 @@ impl GameState {
             lives: 3,
             score: 0,
             combo: 0,
+            combo_milestone: 0,
             time_ticks: 0,
             phase: GamePhase::Serve,
             breather_ticks: 0,
             arena_radius: BASE_ARENA_RADIUS,
+            wave_total_blocks: 0,
+            revive_used: false,
+            mercy_ticks: 0,
+            endless_level: 0,
+            tuning: TuningConfig::default(),
+            inner_wall: None,
+            wave_modifiers: WaveModifiers::NONE,
+            wind_strength: 0.0,
+            wind_phase: 0.0,
+            shuffle_ticks: 0,
+            pickups_collected_this_wave: 0,
+            catch_available: true,
+            catch_armed: false,
             paddle: Paddle::default(),
             balls: Vec::new(),
             blocks: Vec::new(),
             pickups: Vec::new(),
             effects: ActiveEffects::default(),
-            particles: Vec::new(),
+            shockwaves: Vec::new(),
+            particles: ParticlePool::default(),
             screen_shake: 0.0,
             wave_flash: 0.0,
             events: Vec::new(),
@@ -480,6 +931,21 @@ impl GameState {
         state
     }
 
+    /// Parse a player-entered run seed (decimal; spaces, commas and
+    /// underscores used as digit separators are ignored)
+    pub fn parse_seed(input: &str) -> Option<u64> {
+        let digits: String = input
+            .chars()
+            .filter(|c| !matches!(c, ' ' | ',' | '_'))
+            .collect();
+        digits.parse().ok()
+    }
+
+    /// ID the next allocated entity will get (every live ID is below it)
+    pub fn next_id(&self) -> u32 {
+        self.next_id
+    }
+
     /// Allocate a new entity ID
     pub fn next_entity_id(&mut self) -> u32 {
         let id = self.next_id;
@@ -487,6 +953,11 @@ impl GameState {
         id
     }
 
+    /// Mark the next `count` IDs as taken by entities numbered ahead of time
+    pub(crate) fn claim_ids(&mut self, count: u32) {
+        self.next_id += count;
+    }
+
     /// Spawn a ball attached to the paddle
     pub fn spawn_ball_attached(&mut self) {
         let id = self.next_entity_id();
@@ -496,6 +967,170 @@ impl GameState {
         self.balls.push(ball);
     }
 
+    /// Iterate over free-moving balls (not attached, sliding, or dying)
+    pub fn free_balls(&self) -> impl Iterator<Item = &Ball> {
+        self.balls
+            .iter()
+            .filter(|b| matches!(b.state, BallState::Free))
+    }
+
+    /// Iterate mutably over free-moving balls
+    pub fn free_balls_mut(&mut self) -> impl Iterator<Item = &mut Ball> {
+        self.balls
+            .iter_mut()
+            .filter(|b| matches!(b.state, BallState::Free))
+    }
+
+    /// The free ball that will reach the black hole soonest. A close ball
+    /// flying outward is no threat; among balls not heading inward the
+    /// closest wins. Ties go to the lowest ID.
+    pub fn dangerous_ball(&self) -> Option<&Ball> {
+        self.free_balls().min_by(|a, b| {
+            a.time_to_impact()
+                .total_cmp(&b.time_to_impact())
+                .then(a.pos.length().total_cmp(&b.pos.length()))
+        })
+    }
+
+    /// How heated the run is (0.0 calm - 1.0 frantic), blended from how far
+    /// in the wave is and the current combo. Drives the adaptive music.
+    pub fn music_intensity(&self) -> f32 {
+        let wave = (self.wave_index as f32 / MUSIC_INTENSITY_WAVES).min(1.0);
+        let combo = (self.combo as f32 / MUSIC_INTENSITY_COMBO).min(1.0);
+        (wave * MUSIC_WAVE_WEIGHT + combo * MUSIC_COMBO_WEIGHT).min(1.0)
+    }
+
+    /// Second chance: continue a finished run once.
+    ///
+    /// Only valid on `GameOver` and only once per run. Restores one life,
+    /// removes any leftover (dying) balls and respawns one on the paddle in
+    /// `Serve`. Score, wave, blocks and timers are untouched and no RNG is
+    /// consumed, so the continued run stays deterministic: replaying the
+    /// same inputs with the revive at the same tick reproduces it exactly.
+    /// Returns whether the revive was applied.
+    pub fn revive(&mut self) -> bool {
+        if self.phase != GamePhase::GameOver || self.revive_used {
+            return false;
+        }
+        self.revive_used = true;
+        self.lives = 1;
+        self.combo = 0;
+        self.balls.clear();
+        self.spawn_ball_attached();
+        self.mercy_ticks = MERCY_TICKS;
+        self.reset_catch();
+        self.phase = GamePhase::Serve;
+        true
+    }
+
+    /// Start the run with `tuning`, including its starting lives
+    pub fn with_tuning(mut self, tuning: TuningConfig) -> Self {
+        self.lives = tuning.starting_lives;
+        self.tuning = tuning;
+        self
+    }
+
+    /// Give a new life its ball catch back
+    pub fn reset_catch(&mut self) {
+        self.catch_available = true;
+        self.catch_armed = false;
+    }
+
+    /// Current tangential wind acceleration (pixels/s², positive = counter-clockwise)
+    pub fn wind(&self) -> f32 {
+        self.wind_strength * self.wind_phase.sin()
+    }
+
+    /// FNV-1a hash of the serialized state, for comparing runs tick by tick
+    ///
+    /// Covers everything a save covers; particles, shake and events are
+    /// cosmetic and left out.
+    pub fn state_hash(&self) -> u64 {
+        let json = serde_json::to_vec(self).expect("game state always serializes");
+        json.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+
+    /// Elapsed run time in seconds (only advances while the sim ticks)
+    pub fn elapsed_secs(&self) -> f64 {
+        self.time_ticks as f64 * SIM_DT as f64
+    }
+
+    /// Blocks that still have to be destroyed to clear the wave
+    pub fn clearable_blocks(&self) -> u32 {
+        self.blocks.iter().filter(|b| b.counts_for_clear()).count() as u32
+    }
+
+    /// Ring ids that still hold clearable blocks, ascending (outer first)
+    pub fn clearable_rings(&self) -> Vec<u32> {
+        let mut rings: Vec<u32> = self
+            .blocks
+            .iter()
+            .filter(|b| b.counts_for_clear())
+            .map(|b| b.ring_id)
+            .collect();
+        rings.sort_unstable();
+        rings.dedup();
+        rings
+    }
+
+    /// Fraction of the wave's clearable blocks destroyed so far (0-1)
+    pub fn wave_progress(&self) -> f32 {
+        if self.wave_total_blocks == 0 {
+            return 0.0;
+        }
+        let remaining = self.clearable_blocks().min(self.wave_total_blocks);
+        1.0 - remaining as f32 / self.wave_total_blocks as f32
+    }
+
+    /// Whether any ball is still attached to the paddle
+    pub fn any_attached(&self) -> bool {
+        self.balls
+            .iter()
+            .any(|b| matches!(b.state, BallState::Attached { .. }))
+    }
+
+    /// Recompute magnet pole activity for all blocks.
+    ///
+    /// Magnets touching end-to-end on the same ring form a chain; only the
+    /// free ends of a chain keep an active pole, so the middle blocks are
+    /// inert. Called once per tick; physics and rendering both read the
+    /// cached `pole_flags`.
+    pub fn update_magnet_poles(&mut self) {
+        let angle_tolerance = 0.15; // ~8.5 degrees
+        let radius_tolerance = 5.0;
+        let tau = std::f32::consts::TAU;
+        let wrapped = |diff: f32| (diff - tau).abs().min(diff);
+
+        let magnets: Vec<(u32, ArcSegment)> = self
+            .blocks
+            .iter()
+            .filter(|b| b.kind == BlockKind::Magnet)
+            .map(|b| (b.id, b.arc.clone()))
+            .collect();
+
+        for block in &mut self.blocks {
+            let mut flags = Block::POLE_RED | Block::POLE_SILVER;
+            if block.kind == BlockKind::Magnet {
+                for (id, arc) in &magnets {
+                    if *id == block.id || (arc.radius - block.arc.radius).abs() > radius_tolerance {
+                        continue;
+                    }
+                    // Other's silver end touches our red end
+                    if wrapped((arc.theta_end - block.arc.theta_start).abs()) < angle_tolerance {
+                        flags &= !Block::POLE_RED;
+                    }
+                    // Other's red end touches our silver end
+                    if wrapped((arc.theta_start - block.arc.theta_end).abs()) < angle_tolerance {
+                        flags &= !Block::POLE_SILVER;
+                    }
+                }
+            }
+            block.pole_flags = flags;
+        }
+    }
+
     /// Ensure balls are sorted by ID for deterministic iteration
     pub fn normalize_order(&mut self) {
         self.balls.sort_by_key(|b| b.id);
@@ -504,5 +1139,354 @@ impl GameState {
     }
 }
 
-/// Breather phase duration in ticks (2 seconds at 120 Hz)
+/// Default breather phase duration in ticks (2 seconds at 120 Hz)
 pub const BREATHER_DURATION_TICKS: u32 = 2 * 120;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One of every block kind
+    fn all_block_kinds() -> [BlockKind; 11] {
+        let kinds = [
+            BlockKind::Glass,
+            BlockKind::Armored,
+            BlockKind::Explosive,
+            BlockKind::Invincible,
+            BlockKind::Portal { pair_id: 7 },
+            BlockKind::Jello,
+            BlockKind::Crystal,
+            BlockKind::Electric,
+            BlockKind::Magnet,
+            BlockKind::Ghost,
+            BlockKind::Bumper,
+        ];
+        for kind in kinds {
+            // Adding a BlockKind variant breaks this match: add it to `kinds` too
+            match kind {
+                BlockKind::Glass
+                | BlockKind::Armored
+                | BlockKind::Explosive
+                | BlockKind::Invincible
+                | BlockKind::Portal { .. }
+                | BlockKind::Jello
+                | BlockKind::Crystal
+                | BlockKind::Electric
+                | BlockKind::Magnet
+                | BlockKind::Ghost
+                | BlockKind::Bumper => {}
+            }
+        }
+        kinds
+    }
+
+    #[test]
+    fn test_block_kind_color_indices() {
+        // Every kind gets its own palette slot, with no gaps
+        let mut indices: Vec<u32> = all_block_kinds().map(BlockKind::color_index).to_vec();
+        indices.sort_unstable();
+        assert_eq!(indices, (0..indices.len() as u32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_ball_queries() {
+        let state = GameState::new(1)
+            .with_ball_free(Vec2::new(200.0, 0.0), Vec2::new(0.0, 200.0))
+            .with_ball_free(Vec2::new(0.0, 90.0), Vec2::new(200.0, 0.0));
+
+        assert!(state.any_attached());
+        assert_eq!(state.free_balls().count(), 2);
+        // Neither is heading inward: the closer one
+        let closest = state.dangerous_ball().expect("free ball");
+        assert_eq!(closest.pos, Vec2::new(0.0, 90.0));
+
+        // A far ball diving in beats a near one flying out
+        let state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, 90.0), Vec2::new(0.0, 150.0))
+            .with_ball_free(Vec2::new(250.0, 0.0), Vec2::new(-300.0, 0.0));
+        let threat = state.dangerous_ball().expect("free ball");
+        assert_eq!(threat.pos, Vec2::new(250.0, 0.0));
+        assert!((threat.time_to_impact() - 215.0 / 300.0).abs() < 1e-4);
+
+        let empty = GameState::new(1).without_balls();
+        assert!(!empty.any_attached());
+        assert!(empty.dangerous_ball().is_none());
+    }
+
+    #[test]
+    fn test_event_positions() {
+        let pos = Vec2::new(-120.0, 40.0);
+        assert_eq!(GameEvent::WallHit(pos).pos(), Some(pos));
+        assert_eq!(
+            GameEvent::BlockBreak(BlockKind::Glass, pos).pos(),
+            Some(pos)
+        );
+        assert_eq!(GameEvent::WaveClear.pos(), None);
+    }
+
+    #[test]
+    fn test_music_intensity() {
+        let mut state = GameState::new(1);
+        state.wave_index = 0;
+        state.combo = 0;
+        assert_eq!(state.music_intensity(), 0.0);
+
+        // Either alone builds up partway
+        state.wave_index = 10;
+        let wave_only = state.music_intensity();
+        assert!(wave_only > 0.0 && wave_only < 1.0);
+        state.combo = 5;
+        assert!(state.music_intensity() > wave_only);
+
+        // Both high max it out
+        state.wave_index = 40;
+        state.combo = 40;
+        assert_eq!(state.music_intensity(), 1.0);
+    }
+
+    #[test]
+    fn test_inner_wall_openings() {
+        let wall = InnerWall::new(2, 0.0);
+        // Solid at 90 degrees, open at 0 and 180
+        let solid = Vec2::new(0.0, INNER_WALL_RADIUS);
+        assert!(wall.distance(solid) < 0.0);
+        let gap = Vec2::new(INNER_WALL_RADIUS, 0.0);
+        assert!(wall.distance(gap) > BALL_RADIUS);
+        let opposite_gap = Vec2::new(-INNER_WALL_RADIUS, 0.0);
+        assert!(wall.distance(opposite_gap) > BALL_RADIUS);
+    }
+
+    #[test]
+    fn test_serde_round_trip_all_block_kinds() {
+        let mut state = GameState::new(99)
+            .with_ball_free(Vec2::new(120.0, -30.0), Vec2::new(-80.0, 210.0))
+            .with_phase(GamePhase::Playing);
+        for (i, kind) in all_block_kinds().into_iter().enumerate() {
+            let theta = i as f32 * 0.6;
+            state.add_block(ArcSegment::new(250.0, 20.0, theta, theta + 0.4), kind);
+        }
+        let block = &mut state.blocks[3];
+        block.rotation_speed = 0.5;
+        block.wobble = 0.3;
+        block.visibility = 0.4;
+        block.ghost_phase = 1.1;
+        block.ring_id = 2;
+        block.pole_flags = Block::POLE_SILVER;
+
+        let ball = state.balls.last_mut().unwrap();
+        ball.piercing = true;
+        ball.paddle_cooldown = 3;
+        ball.inside_portals = vec![5];
+        ball.electric_charge = 0.7;
+        ball.curve = -0.25;
+        ball.make_heavy();
+        ball.plowing = Some(4);
+        ball.record_trail(TRAIL_LENGTH);
+
+        let id = state.next_entity_id();
+        state.pickups.push(Pickup {
+            id,
+            kind: PickupKind::Shield,
+            pos: Vec2::new(10.0, 20.0),
+            vel: Vec2::new(-1.0, 2.0),
+            ttl_ticks: 600,
+        });
+        state.effects = ActiveEffects {
+            slow_ticks: 10,
+            piercing_ticks: 20,
+            widen_ticks: 30,
+            widen_stacks: 2,
+            shield_active: true,
+            freeze_ticks: 40,
+            sticky_active: true,
+            time_warp_ticks: 50,
+        };
+        state.inner_wall = Some(InnerWall::new(3, -0.3));
+        state.tuning.breather_ticks = 42;
+        state.score = 12345;
+        state.combo = 6;
+        state.time_ticks = 777;
+        state.wave_total_blocks = state.clearable_blocks();
+        state.revive_used = true;
+        state.paddle.arc_width_vel = 1.5;
+
+        // Cosmetic / per-tick data that is intentionally not saved
+        state.screen_shake = 0.5;
+        state.wave_flash = 0.5;
+        state.events.push(GameEvent::WallHit(Vec2::ZERO));
+        state.particles.push(Particle {
+            pos: Vec2::ZERO,
+            vel: Vec2::ZERO,
+            color: 0,
+            life: 1.0,
+            size: 1.0,
+        });
+
+        let json = serde_json::to_string(&state).unwrap();
+        let loaded: GameState = serde_json::from_str(&json).unwrap();
+
+        // Gameplay state survives
+        assert_eq!(loaded.seed, state.seed);
+        assert_eq!(loaded.rng_state.seed, state.rng_state.seed);
+        assert_eq!(loaded.rng_state.stream, state.rng_state.stream);
+        assert_eq!(loaded.wave_index, state.wave_index);
+        assert_eq!(loaded.lives, state.lives);
+        assert_eq!(loaded.score, state.score);
+        assert_eq!(loaded.combo, state.combo);
+        assert_eq!(loaded.time_ticks, state.time_ticks);
+        assert_eq!(loaded.phase, state.phase);
+        assert_eq!(loaded.breather_ticks, state.breather_ticks);
+        assert_eq!(loaded.arena_radius, state.arena_radius);
+        assert_eq!(loaded.wave_total_blocks, state.wave_total_blocks);
+        assert_eq!(loaded.revive_used, state.revive_used);
+        assert_eq!(loaded.tuning, state.tuning);
+        assert_eq!(loaded.inner_wall, state.inner_wall);
+        assert_eq!(loaded.paddle, state.paddle);
+        assert_eq!(loaded.blocks, state.blocks);
+        assert_eq!(loaded.pickups, state.pickups);
+        assert_eq!(loaded.effects, state.effects);
+        assert_eq!(loaded.next_id, state.next_id);
+        assert_eq!(loaded.balls.len(), state.balls.len());
+        for (a, b) in loaded.balls.iter().zip(&state.balls) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.pos, b.pos);
+            assert_eq!(a.vel, b.vel);
+            assert_eq!(a.radius, b.radius);
+            assert_eq!(a.state, b.state);
+            assert_eq!(a.piercing, b.piercing);
+            assert_eq!(a.paddle_cooldown, b.paddle_cooldown);
+            assert_eq!(a.inside_portals, b.inside_portals);
+            assert_eq!(a.electric_charge, b.electric_charge);
+            assert_eq!(a.curve, b.curve);
+            assert_eq!(a.mass, b.mass);
+            assert_eq!(a.plow_ready, b.plow_ready);
+            assert_eq!(a.plowing, b.plowing);
+            // Skipped: the trail is rebuilt from movement after loading
+            assert!(a.trail.is_empty());
+        }
+
+        // Skipped: visuals and per-tick events start fresh
+        assert!(loaded.particles.is_empty());
+        assert!(loaded.events.is_empty());
+        assert_eq!(loaded.screen_shake, 0.0);
+        assert_eq!(loaded.wave_flash, 0.0);
+
+        // Nothing else is lost: saving the loaded state gives the same JSON
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+    }
+
+    #[test]
+    fn test_revive_once() {
+        let mut state = GameState::new(1);
+        assert!(!state.revive(), "can't revive a live run");
+
+        state.score = 1234;
+        state.wave_index = 4;
+        state.lives = 0;
+        state.phase = GamePhase::GameOver;
+        assert!(state.revive());
+        assert_eq!(state.phase, GamePhase::Serve);
+        assert_eq!(state.lives, 1);
+        assert_eq!(state.score, 1234);
+        assert_eq!(state.wave_index, 4);
+        assert!(state.any_attached());
+
+        state.lives = 0;
+        state.phase = GamePhase::GameOver;
+        assert!(!state.revive());
+        assert_eq!(state.phase, GamePhase::GameOver);
+    }
+
+    #[test]
+    fn test_state_hash_tracks_sim_state() {
+        let mut a = GameState::new(5);
+        let b = GameState::new(5);
+        assert_eq!(a.state_hash(), b.state_hash());
+        assert_ne!(a.state_hash(), GameState::new(6).state_hash());
+
+        // Cosmetic fields don't count
+        a.screen_shake = 1.0;
+        assert_eq!(a.state_hash(), b.state_hash());
+        a.paddle.theta += 0.1;
+        assert_ne!(a.state_hash(), b.state_hash());
+    }
+
+    #[test]
+    fn test_parse_seed() {
+        assert_eq!(GameState::parse_seed("12345"), Some(12345));
+        assert_eq!(
+            GameState::parse_seed(" 1,700,000_000 "),
+            Some(1_700_000_000)
+        );
+        assert_eq!(GameState::parse_seed(""), None);
+        assert_eq!(GameState::parse_seed("-3"), None);
+        assert_eq!(GameState::parse_seed("abc"), None);
+    }
+
+    #[test]
+    fn test_wave_progress() {
+        let mut state = GameState::new(1)
+            .with_block(ArcSegment::new(200.0, 20.0, 0.0, 0.3), BlockKind::Glass)
+            .with_block(ArcSegment::new(200.0, 20.0, 0.5, 0.8), BlockKind::Glass)
+            .with_block(
+                ArcSegment::new(250.0, 20.0, 0.0, 0.3),
+                BlockKind::Invincible,
+            );
+        assert_eq!(state.wave_progress(), 0.0);
+
+        state.wave_total_blocks = state.clearable_blocks();
+        assert_eq!(state.wave_total_blocks, 2);
+        state.blocks.remove(0);
+        assert_eq!(state.wave_progress(), 0.5);
+    }
+
+    #[test]
+    fn test_magnet_chain_poles() {
+        // Three magnets end to end, plus a lone one on another ring
+        let mut state = GameState::new(1)
+            .with_block(ArcSegment::new(200.0, 20.0, 0.0, 0.3), BlockKind::Magnet)
+            .with_block(ArcSegment::new(200.0, 20.0, 0.3, 0.6), BlockKind::Magnet)
+            .with_block(ArcSegment::new(200.0, 20.0, 0.6, 0.9), BlockKind::Magnet)
+            .with_block(ArcSegment::new(300.0, 20.0, 0.3, 0.6), BlockKind::Magnet);
+        state.update_magnet_poles();
+
+        let flags: Vec<u32> = state.blocks.iter().map(|b| b.pole_flags).collect();
+        assert_eq!(
+            flags,
+            vec![
+                Block::POLE_RED,
+                0,
+                Block::POLE_SILVER,
+                Block::POLE_RED | Block::POLE_SILVER
+            ]
+        );
+    }
+
+    #[test]
+    fn test_particle_pool_overwrites_oldest() {
+        let particle = |i: usize| Particle {
+            pos: Vec2::new(i as f32, 0.0),
+            vel: Vec2::ZERO,
+            color: 0,
+            life: 1.0,
+            size: 2.0,
+        };
+        let mut pool = ParticlePool::default();
+        for i in 0..2000 {
+            pool.push(particle(i));
+            assert!(pool.len() <= MAX_PARTICLES);
+        }
+        assert_eq!(pool.len(), MAX_PARTICLES);
+        // The newest survive, oldest first
+        let order: Vec<usize> = pool.iter().map(|p| p.pos.x as usize).collect();
+        assert_eq!(order, (2000 - MAX_PARTICLES..2000).collect::<Vec<_>>());
+
+        // Pruning keeps age order, and the next push lands at the end
+        pool.retain(|p| (p.pos.x as usize).is_multiple_of(2));
+        pool.push(particle(2000));
+        let order: Vec<usize> = pool.iter().map(|p| p.pos.x as usize).collect();
+        assert_eq!(order.len(), MAX_PARTICLES / 2 + 1);
+        assert!(order.is_sorted());
+    }
+}
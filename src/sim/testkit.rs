@@ -0,0 +1,134 @@
+//! Test helpers for building game states
+//!
+//! Builder methods that fill sensible defaults so tests and tools don't have
+//! to spell out every `Block`/`Ball` field (and break when fields are added).
+//! Only compiled for tests or with the `testkit` feature.
+
+use glam::Vec2;
+
+use super::arc::ArcSegment;
+use super::state::{
+    Ball, BallState, Block, BlockKind, GamePhase, GameState, MAX_PARTICLES, Particle,
+};
+use super::tick::generate_wave;
+
+/// Wave the heavy state is generated for (a full set of rings)
+pub const HEAVY_WAVE: u32 = 12;
+/// Balls in flight in the heavy state
+pub const HEAVY_BALLS: usize = 6;
+
+impl GameState {
+    /// Add a stationary block with the run's tuned HP for its kind
+    pub fn with_block(mut self, arc: ArcSegment, kind: BlockKind) -> Self {
+        self.add_block(arc, kind);
+        self
+    }
+
+    /// Add a free-moving ball at `pos` with velocity `vel`
+    pub fn with_ball_free(mut self, pos: Vec2, vel: Vec2) -> Self {
+        self.add_ball_free(pos, vel);
+        self
+    }
+
+    /// Remove all balls (including the initial attached one)
+    pub fn without_balls(mut self) -> Self {
+        self.balls.clear();
+        self
+    }
+
+    /// Set the current phase
+    pub fn with_phase(mut self, phase: GamePhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// A representative late-wave run for benchmarks: every ring filled,
+    /// `HEAVY_BALLS` balls in flight between the paddle and the blocks, and
+    /// a full particle buffer. The shield is up so no ball is lost early.
+    pub fn heavy(seed: u64) -> Self {
+        let mut state = GameState::new(seed).without_balls();
+        state.wave_index = HEAVY_WAVE;
+        generate_wave(&mut state);
+        state.phase = GamePhase::Playing;
+        state.effects.shield_active = true;
+
+        for i in 0..HEAVY_BALLS {
+            let angle = i as f32 * std::f32::consts::TAU / HEAVY_BALLS as f32;
+            let dir = Vec2::from_angle(angle);
+            // Outward and a little sideways, so balls spread over the rings
+            state.add_ball_free(dir * 90.0, dir.rotate(Vec2::from_angle(0.4)) * 300.0);
+        }
+        for i in 0..MAX_PARTICLES {
+            let angle = i as f32 * 0.37;
+            state.particles.push(Particle {
+                pos: Vec2::from_angle(angle) * (150.0 + (i % 7) as f32 * 40.0),
+                vel: Vec2::from_angle(angle * 3.0) * 80.0,
+                color: (i % 10) as u32,
+                life: 1.0,
+                size: 3.0,
+            });
+        }
+        state
+    }
+
+    /// Add a stationary block with the HP `generate_wave` would give it,
+    /// returning its entity ID
+    pub fn add_block(&mut self, arc: ArcSegment, kind: BlockKind) -> u32 {
+        let id = self.next_entity_id();
+        self.blocks.push(Block {
+            id,
+            kind,
+            hp: self.tuning.blocks.hp(kind, self.wave_index),
+            arc,
+            rotation_speed: 0.0,
+            wobble: 0.0,
+            visibility: 1.0,
+            ghost_phase: 0.0,
+            ring_id: 0,
+            pole_flags: Block::POLE_RED | Block::POLE_SILVER,
+            shuffle_remaining: 0.0,
+        });
+        id
+    }
+
+    /// Add a free-moving ball, returning its entity ID
+    pub fn add_ball_free(&mut self, pos: Vec2, vel: Vec2) -> u32 {
+        let id = self.next_entity_id();
+        let mut ball = Ball::new(id);
+        ball.pos = pos;
+        ball.vel = vel;
+        ball.state = BallState::Free;
+        self.balls.push(ball);
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_defaults() {
+        let state = GameState::new(1)
+            .without_balls()
+            .with_block(ArcSegment::new(200.0, 20.0, 0.0, 0.5), BlockKind::Armored)
+            .with_ball_free(Vec2::new(100.0, 0.0), Vec2::new(0.0, 200.0))
+            .with_phase(GamePhase::Playing);
+
+        assert_eq!(state.blocks.len(), 1);
+        assert_eq!(state.blocks[0].hp, 2);
+        assert_eq!(state.balls.len(), 1);
+        assert!(matches!(state.balls[0].state, BallState::Free));
+        assert_ne!(state.blocks[0].id, state.balls[0].id);
+        assert_eq!(state.phase, GamePhase::Playing);
+    }
+
+    #[test]
+    fn test_heavy_state() {
+        let state = GameState::heavy(3);
+        assert!(state.blocks.len() > 100, "{}", state.blocks.len());
+        assert_eq!(state.free_balls().count(), HEAVY_BALLS);
+        assert_eq!(state.particles.len(), MAX_PARTICLES);
+        assert_eq!(state.validate(), Ok(()));
+    }
+}
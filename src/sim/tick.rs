@@ -5,15 +5,25 @@
 use glam::Vec2;
 
 use super::ball_arc_collision;
-use super::state::{BREATHER_DURATION_TICKS, BallState, GamePhase, GameState, Pickup, PickupKind};
+use super::broadphase::BlockGrid;
+use super::rng;
+use super::state::{
+    BallState, GamePhase, GameState, Pickup, PickupKind, Shockwave, TrailPoint, WaveModifiers,
+};
 use crate::consts::*;
+use crate::tuning::params::*;
+use crate::tuning::{GravityProfile, TuningConfig};
 // use crate::{cartesian_to_polar, normalize_angle, polar_to_cartesian};
 
 /// Input commands for a single tick (deterministic)
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct TickInput {
     /// Target paddle angle (from mouse/touch position)
     pub target_theta: Option<f32>,
+    /// Keyboard rotation while `target_theta` is None: +1 counter-clockwise,
+    /// -1 clockwise, 0 still
+    pub paddle_spin: f32,
     /// Launch ball (click/tap/space)
     pub launch: bool,
     /// Pause toggle
@@ -22,10 +32,16 @@ pub struct TickInput {
     pub skip_wave: bool,
     /// Idle/demo mode - AI plays the game
     pub idle_mode: bool,
+    /// Arm a ball catch: the next paddle hit re-attaches the ball (once per life)
+    pub catch: bool,
 }
 
 /// Advance the game state by one fixed timestep
 pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
+    // Events only describe this tick, even one that returns early while
+    // paused or after game over
+    state.events.clear();
+
     // Handle pause toggle
     if input.pause {
         match state.phase {
@@ -34,11 +50,7 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                 return;
             }
             GamePhase::Paused => {
-                state.phase = if state
-                    .balls
-                    .iter()
-                    .any(|b| matches!(b.state, BallState::Attached { .. }))
-                {
+                state.phase = if state.any_attached() {
                     GamePhase::Serve
                 } else {
                     GamePhase::Playing
@@ -54,12 +66,13 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
         _ => {}
     }
 
-    // Clear events from previous tick
-    state.events.clear();
+    // Copied out so ball loops can record trails while borrowing balls
+    let trail_length = state.tuning.trail_length;
 
-    // Decay screen shake
-    state.screen_shake *= 0.9; // Fast decay
-    if state.screen_shake < 0.01 {
+    // Decay screen shake (time-based, so it fades the same for any dt)
+    state.screen_shake =
+        (state.screen_shake * (-SCREEN_SHAKE_DECAY_RATE * dt).exp()).clamp(0.0, 1.0);
+    if state.screen_shake < SCREEN_SHAKE_CUTOFF {
         state.screen_shake = 0.0;
     }
 
@@ -72,41 +85,27 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
     // Idle/demo mode - AI plays the game
     let mut input = input.clone();
     if input.idle_mode {
-        // Auto-launch ball in serve phase
-        if matches!(state.phase, GamePhase::Serve) {
+        // Auto-launch ball in serve phase, or one held by a sticky paddle
+        if matches!(state.phase, GamePhase::Serve)
+            || state
+                .balls
+                .iter()
+                .any(|b| matches!(b.state, BallState::Attached { .. }))
+        {
             input.launch = true;
         }
 
-        // Find the most dangerous ball (closest to black hole)
-        let maybe_ball = state
-            .balls
-            .iter()
-            .filter(|b| matches!(b.state, BallState::Free))
-            .min_by(|a, b| {
-                a.pos
-                    .length()
-                    .partial_cmp(&b.pos.length())
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+        // Defend the ball that will reach the black hole soonest
+        let maybe_ball = state.dangerous_ball();
 
         // Check if ALL balls are "safe" (far from paddle, moving away)
-        let all_balls_safe = state
-            .balls
-            .iter()
-            .filter(|b| matches!(b.state, BallState::Free))
-            .all(|ball| {
-                let ball_dist = ball.pos.length();
-                let moving_outward = ball.vel.dot(ball.pos.normalize_or_zero()) > 0.0;
-                // Safe if ball is far out OR moving away from center
-                ball_dist > 200.0 || (ball_dist > 100.0 && moving_outward)
-            });
-        let ball_is_safe = state
-            .balls
-            .iter()
-            .filter(|b| matches!(b.state, BallState::Free))
-            .count()
-            == 0
-            || all_balls_safe;
+        // (vacuously true when there are no free balls)
+        let ball_is_safe = state.free_balls().all(|ball| {
+            let ball_dist = ball.pos.length();
+            let moving_outward = ball.vel.dot(ball.pos.normalize_or_zero()) > 0.0;
+            // Safe if ball is far out OR moving away from center
+            ball_dist > 200.0 || (ball_dist > 100.0 && moving_outward)
+        });
 
         // If safe, go grab the nearest pickup
         let target_pickup = if ball_is_safe && !state.pickups.is_empty() {
@@ -134,11 +133,10 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
             let time_factor = state.time_ticks as f32 * 0.01;
             let offset = (time_factor.sin() * 0.3) + (time_factor * 0.7).sin() * 0.15;
 
-            // Predict where ball is heading (lead the target slightly)
-            let ball_future = ball.pos + ball.vel.normalize_or_zero() * 30.0;
+            // Lead the ball along its gravity-bent path
+            let ball_future = super::predict::curved_lead(state, ball, IDLE_AI_LOOKAHEAD_TICKS);
             let future_angle = ball_future.y.atan2(ball_future.x);
 
-            // Blend between current ball angle and predicted, add offset
             let target = future_angle + offset;
             input.target_theta = Some(target);
         }
@@ -159,10 +157,16 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
 
     state.time_ticks += 1;
 
-    // Update paddle position
-    if let Some(target) = input.target_theta {
-        let max_speed = 9.6; // radians per second (reduced 20%)
+    // Update paddle position from the pointer (a NaN target from a bad pointer
+    // event is ignored), or else from held rotate keys
+    if let Some(target) = input.target_theta.filter(|t| t.is_finite()) {
+        let max_speed = state.tuning.paddle_max_speed;
         state.paddle.move_toward(target, dt, max_speed);
+    } else if input.paddle_spin.is_finite() && input.paddle_spin != 0.0 {
+        let speed = input.paddle_spin.clamp(-1.0, 1.0) * state.tuning.keyboard_speed;
+        state.paddle.spin(speed, dt);
+    } else {
+        state.paddle.angular_vel = 0.0;
     }
 
     // Time in seconds for animations
@@ -195,8 +199,7 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
             if input.launch {
                 for ball in &mut state.balls {
                     if matches!(ball.state, BallState::Attached { .. }) {
-                        let speed = BALL_START_SPEED; // TODO: from tuning
-                        ball.launch(&state.paddle, speed, 0.5);
+                        ball.launch(&state.paddle, state.tuning.ball_start_speed, 0.5);
                     }
                 }
                 state.phase = GamePhase::Playing;
@@ -205,13 +208,33 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
         }
 
         GamePhase::Playing => {
-            // Rotate blocks and update ghost visibility
+            // Rings with blocks left, to spot the ones this tick empties
+            let rings_before = state.clearable_rings();
+
+            // Time Warp slows the world from here on. The paddle has already
+            // moved at real speed, and effect timers count real ticks.
+            let real_dt = dt;
+            let dt = dt * state.effects.time_scale();
+
+            // Rotate blocks and update ghost visibility (Freeze holds the rings still)
+            let frozen = state.effects.freeze_ticks > 0;
+            let spin_dt = if frozen { 0.0 } else { dt };
             for block in &mut state.blocks {
-                block.rotate(dt, time_secs);
+                block.rotate(spin_dt, time_secs);
+            }
+            if let Some(wall) = &mut state.inner_wall {
+                wall.rotate(spin_dt);
+            }
+            if !frozen {
+                advance_shuffle(state, dt);
+            }
+            if state.wind_strength != 0.0 {
+                state.wind_phase =
+                    (state.wind_phase + WIND_PHASE_RATE * dt) % std::f32::consts::TAU;
             }
 
             // Update sliding balls (portal traversal)
-            let portal_slide_speed = 0.75; // radians per second - 50% slower slide through portal
+            let portal_slide_speed = state.tuning.portal_slide_speed;
             let mut portal_exits: Vec<(usize, u32)> = Vec::new(); // (ball_idx, block_id) for damage
 
             // Collect portal block info for chaining detection
@@ -336,7 +359,7 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                     }
 
                     // Record trail while sliding
-                    ball.record_trail();
+                    ball.record_trail(trail_length);
                 }
             }
 
@@ -358,15 +381,10 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                     let particle_seed = state.time_ticks as u32 + block.id;
 
                     for i in 0..particle_count {
-                        if state.particles.len() >= super::state::MAX_PARTICLES {
-                            state.particles.remove(0);
-                        }
-                        let hash = particle_seed
-                            .wrapping_mul(2654435761)
-                            .wrapping_add(i as u32 * 7919);
-                        let angle_offset = ((hash % 1000) as f32 / 1000.0 - 0.5) * arc_span * 1.2;
+                        let hash = rng::hash_u32(particle_seed, i as u32);
+                        let angle_offset = (rng::rand_f32(hash) - 0.5) * arc_span * 1.2;
                         let radius_offset =
-                            ((hash / 1000 % 1000) as f32 / 1000.0 - 0.5) * block.arc.thickness;
+                            (rng::rand_f32(hash / 1000) - 0.5) * block.arc.thickness;
                         let spawn_angle = mid_angle + angle_offset;
                         let spawn_radius = block.arc.radius + radius_offset;
                         let pos = Vec2::new(
@@ -374,12 +392,10 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                             spawn_angle.sin() * spawn_radius,
                         );
 
-                        let vel_hash = hash.wrapping_mul(1664525).wrapping_add(1013904223);
+                        let [vel_hash, speed_hash, size_hash] = rng::rehash3(hash);
                         let vel_angle = (vel_hash % 10000) as f32 / 10000.0 * std::f32::consts::TAU;
-                        let speed_hash = vel_hash.wrapping_mul(22695477).wrapping_add(1);
                         let base_speed = 80.0 + (speed_hash % 200) as f32;
                         let vel = Vec2::new(vel_angle.cos(), vel_angle.sin()) * base_speed;
-                        let size_hash = speed_hash.wrapping_mul(69069).wrapping_add(1);
                         let size = 1.5 + (size_hash % 250) as f32 / 100.0;
 
                         state.particles.push(super::state::Particle {
@@ -395,13 +411,60 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
             state.blocks.retain(|b| b.hp > 0);
 
             // Collision detection and response
+            // Paddle geometry is snapshotted once per tick, after the paddle has
+            // moved toward its target. Ball collisions and pickup collection
+            // both use this snapshot; the widen spring runs afterwards, so a
+            // width change takes effect on the next tick.
             let paddle_arc = state.paddle.as_arc();
+            let paddle_half_arc = state.paddle.arc_width / 2.0;
+            let paddle_cooldown_ticks = state.tuning.paddle_cooldown_ticks;
             let paddle_outer = PADDLE_RADIUS + PADDLE_THICKNESS / 2.0;
             let _paddle_inner = PADDLE_RADIUS - PADDLE_THICKNESS / 2.0;
 
+            // Ball catch: arming needs a catch left this life. Only a lone ball
+            // can be caught, since catching returns the run to Serve.
+            if input.catch && state.catch_available {
+                state.catch_armed = true;
+            }
+            let mut can_catch = state.catch_armed && state.balls.len() == 1;
+            let mut caught = false;
+
+            // Sticky paddle: balls it holds ride along until the next launch.
+            // Only one ball sticks per pickup, even if several hit this tick.
+            for ball in &mut state.balls {
+                ball.update_attached(&state.paddle);
+            }
+            if input.launch {
+                for ball in &mut state.balls {
+                    if matches!(ball.state, BallState::Attached { .. }) {
+                        ball.launch(&state.paddle, state.tuning.ball_start_speed, 0.5);
+                        state.events.push(super::state::GameEvent::Launch);
+                    }
+                }
+            }
+
             // Collect pickups to spawn (deferred to avoid borrow issues)
             let mut pickups_to_spawn: Vec<(PickupKind, Vec2)> = Vec::new();
 
+            // Chaos modifiers: double speed doubles the limits
+            let speed_scale = if state.wave_modifiers.contains(WaveModifiers::DOUBLE_SPEED) {
+                2.0
+            } else {
+                1.0
+            };
+            let endless = endless_scaling(state.endless_level);
+            let base_max_speed = state.tuning.ball_max_speed;
+            let min_speed = state.tuning.ball_min_speed * speed_scale;
+            let max_speed = base_max_speed * speed_scale * endless.speed;
+            let paddle_boost = state.tuning.paddle_boost;
+            let gravity = black_hole_pull(state);
+            let gravity_profile = state.tuning.gravity;
+            let wind = state.wind();
+
+            // Block broadphase, shared by all balls this tick (keyed by block count)
+            let max_ball_radius = state.balls.iter().map(|b| b.radius).fold(0.0, f32::max);
+            let mut block_grid: Option<(usize, BlockGrid)> = None;
+
             for ball in &mut state.balls {
                 if !matches!(ball.state, BallState::Free) {
                     continue;
@@ -413,12 +476,27 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                 }
 
                 // --- BLACK HOLE GRAVITY ---
-                // Apply gravitational pull toward center (stronger when closer)
-                let dist_to_center = ball.pos.length();
+                // Heavier balls hold their line better
+                ball.vel += gravity_accel(ball.pos, gravity, &gravity_profile) / ball.mass * dt;
                 let to_center = -ball.pos.normalize_or_zero();
-                // Inverse distance scaling: much stronger near the hole
-                let gravity_multiplier = (200.0 / dist_to_center.max(50.0)).min(4.0);
-                ball.vel += to_center * BLACK_HOLE_GRAVITY * gravity_multiplier * dt;
+
+                // --- WIND ---
+                // Tangential push around the arena (zero unless a Wind wave)
+                if wind != 0.0 {
+                    let ccw = Vec2::new(to_center.y, -to_center.x);
+                    ball.vel += ccw * wind * dt;
+                }
+
+                // --- CURVE BALL ---
+                // Sideways push from a sweeping paddle hit, decaying over time
+                if ball.curve != 0.0 {
+                    let side = ball.vel.perp().normalize_or_zero();
+                    ball.vel += side * ball.curve * CURVE_ACCEL * dt;
+                    ball.curve *= (-CURVE_DECAY_RATE * dt).exp();
+                    if ball.curve.abs() < 0.01 {
+                        ball.curve = 0.0;
+                    }
+                }
 
                 // Magnet blocks: red end (theta_start) pulls, silver end (theta_end) pushes
                 // Chain detection: only endpoints of adjacent magnet chains have active polarity
@@ -431,44 +509,11 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                         let dist_to_magnet = to_magnet.length();
 
                         if dist_to_magnet > 10.0 && dist_to_magnet < 150.0 {
-                            // Check if this block's ends are connected to other magnets (chain detection)
-                            let angle_tolerance = 0.15; // ~8.5 degrees
-                            let radius_tolerance = 5.0;
-
-                            let mut red_end_is_endpoint = true;
-                            let mut silver_end_is_endpoint = true;
-
-                            for other in &state.blocks {
-                                if other.id == block.id {
-                                    continue;
-                                }
-                                if other.kind != super::state::BlockKind::Magnet {
-                                    continue;
-                                }
-                                if (other.arc.radius - block.arc.radius).abs() > radius_tolerance {
-                                    continue;
-                                }
-
-                                // Check if other's theta_end connects to our theta_start (red end)
-                                let diff_to_red =
-                                    (other.arc.theta_end - block.arc.theta_start).abs();
-                                let diff_to_red_wrapped =
-                                    (diff_to_red - std::f32::consts::TAU).abs().min(diff_to_red);
-                                if diff_to_red_wrapped < angle_tolerance {
-                                    red_end_is_endpoint = false;
-                                }
-
-                                // Check if other's theta_start connects to our theta_end (silver end)
-                                let diff_to_silver =
-                                    (other.arc.theta_start - block.arc.theta_end).abs();
-                                let diff_to_silver_wrapped = (diff_to_silver
-                                    - std::f32::consts::TAU)
-                                    .abs()
-                                    .min(diff_to_silver);
-                                if diff_to_silver_wrapped < angle_tolerance {
-                                    silver_end_is_endpoint = false;
-                                }
-                            }
+                            // Chain endpoints are cached by update_magnet_poles
+                            let poles = block.pole_flags;
+                            let red_end_is_endpoint = poles & super::state::Block::POLE_RED != 0;
+                            let silver_end_is_endpoint =
+                                poles & super::state::Block::POLE_SILVER != 0;
 
                             // Only apply force if near an active endpoint
                             let red_end =
@@ -499,10 +544,10 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
 
                 // Clamp speed to min/max (gravity can slow but not stop the ball)
                 let speed = ball.vel.length();
-                if speed < BALL_MIN_SPEED {
-                    ball.vel = ball.vel.normalize_or_zero() * BALL_MIN_SPEED;
-                } else if speed > BALL_MAX_SPEED {
-                    ball.vel = ball.vel.normalize_or_zero() * BALL_MAX_SPEED;
+                if speed < min_speed {
+                    ball.vel = ball.vel.normalize_or_zero() * min_speed;
+                } else if speed > max_speed {
+                    ball.vel = ball.vel.normalize_or_zero() * max_speed;
                 }
 
                 let displacement = ball.vel * dt;
@@ -533,14 +578,28 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
 
                         // Check if crossing point is within paddle arc
                         if paddle_arc.contains_angle(crossing_angle) {
+                            if can_catch {
+                                catch_ball(ball, &state.paddle, crossing_angle);
+                                can_catch = false;
+                                caught = true;
+                                continue;
+                            }
+                            if state.effects.sticky_active {
+                                catch_ball(ball, &state.paddle, crossing_angle);
+                                state.effects.sticky_active = false;
+                                state
+                                    .events
+                                    .push(super::state::GameEvent::PaddleHit(ball.pos));
+                                continue;
+                            }
+
                             // HIT! Reflect at the crossing point
                             let ball_angle = crossing_angle;
                             let paddle_center = state.paddle.theta;
-                            let half_arc = state.paddle.arc_width / 2.0;
 
                             // Normalize hit position: 0 = center, -1/+1 = edges
                             let mut hit_offset = crate::normalize_angle(ball_angle - paddle_center);
-                            hit_offset = (hit_offset / half_arc).clamp(-1.0, 1.0);
+                            hit_offset = (hit_offset / paddle_half_arc).clamp(-1.0, 1.0);
 
                             // Normal pointing outward from paddle
                             let normal = Vec2::new(ball_angle.cos(), ball_angle.sin());
@@ -557,9 +616,10 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                             let english = tangent * state.paddle.angular_vel * PADDLE_RADIUS * 0.15;
 
                             // Apply paddle boost to help escape gravity
-                            let boosted_speed = (speed * PADDLE_BOOST).min(BALL_MAX_SPEED);
-                            ball.vel =
-                                (base_reflect + deflection + english).normalize() * boosted_speed;
+                            let boosted_speed = (speed * paddle_boost).min(max_speed);
+                            // Terms can cancel exactly; fall back to a straight bounce
+                            ball.vel = (base_reflect + deflection + english).normalize_or(normal)
+                                * boosted_speed;
 
                             // Position ball exactly at the reflection point (just outside paddle)
                             let safe_dist = paddle_outer + ball.radius + 1.0;
@@ -569,20 +629,26 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                             );
 
                             // Set cooldown to prevent immediate re-collision
-                            ball.paddle_cooldown = 8;
-                            state.events.push(super::state::GameEvent::PaddleHit);
+                            ball.paddle_cooldown = paddle_cooldown_ticks;
+                            ball.bumper_chain = 0;
+                            ball.curve = paddle_curve(state.paddle.angular_vel);
+                            ball.spin = paddle_spin(state.paddle.angular_vel, ball.radius);
+                            state
+                                .events
+                                .push(super::state::GameEvent::PaddleHit(ball.pos));
+                            if hit_offset.abs() >= NEAR_DEATH_EDGE {
+                                state.events.push(super::state::GameEvent::NearDeathSave);
+                            }
 
                             // 🔥 Paddle hit sparks - emit from contact point, spread around normal
                             let spark_count = 8;
                             let normal_angle = normal.y.atan2(normal.x);
                             let spread = std::f32::consts::FRAC_PI_2; // 90 degree cone (±45°)
                             for j in 0..spark_count {
-                                let hash = (state.time_ticks as u32)
-                                    .wrapping_mul(2654435761)
-                                    .wrapping_add(j * 7919);
-                                let rand1 = (hash % 1000) as f32 / 1000.0 - 0.5; // -0.5 to 0.5
-                                let rand2 = ((hash >> 10) % 1000) as f32 / 1000.0;
-                                let rand3 = ((hash >> 20) % 1000) as f32 / 1000.0;
+                                let hash = rng::hash_u32(state.time_ticks as u32, j);
+                                let rand1 = rng::rand_f32(hash) - 0.5; // -0.5 to 0.5
+                                let rand2 = rng::rand_f32(hash >> 10);
+                                let rand3 = rng::rand_f32(hash >> 20);
 
                                 // Spread sparks in cone around normal
                                 let spark_angle = normal_angle + rand1 * spread;
@@ -614,11 +680,24 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
 
                         if moving_toward {
                             let ball_angle = ball.pos.y.atan2(ball.pos.x);
+                            if can_catch {
+                                catch_ball(ball, &state.paddle, ball_angle);
+                                can_catch = false;
+                                caught = true;
+                                continue;
+                            }
+                            if state.effects.sticky_active {
+                                catch_ball(ball, &state.paddle, ball_angle);
+                                state.effects.sticky_active = false;
+                                state
+                                    .events
+                                    .push(super::state::GameEvent::PaddleHit(ball.pos));
+                                continue;
+                            }
                             let paddle_center = state.paddle.theta;
-                            let half_arc = state.paddle.arc_width / 2.0;
 
                             let mut hit_offset = crate::normalize_angle(ball_angle - paddle_center);
-                            hit_offset = (hit_offset / half_arc).clamp(-1.0, 1.0);
+                            hit_offset = (hit_offset / paddle_half_arc).clamp(-1.0, 1.0);
 
                             let base_reflect =
                                 super::collision::reflect_velocity(ball.vel, paddle_result.normal);
@@ -629,9 +708,10 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                             let english = tangent * state.paddle.angular_vel * PADDLE_RADIUS * 0.15;
 
                             // Apply paddle boost to help escape gravity
-                            let boosted_speed = (speed * PADDLE_BOOST).min(BALL_MAX_SPEED);
-                            ball.vel =
-                                (base_reflect + deflection + english).normalize() * boosted_speed;
+                            let boosted_speed = (speed * paddle_boost).min(max_speed);
+                            ball.vel = (base_reflect + deflection + english)
+                                .normalize_or(paddle_result.normal)
+                                * boosted_speed;
 
                             let safe_dist = paddle_outer + ball.radius + 1.0;
                             let ball_angle_rad = ball.pos.y.atan2(ball.pos.x);
@@ -640,20 +720,26 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                                 safe_dist * ball_angle_rad.sin(),
                             );
 
-                            ball.paddle_cooldown = 8;
-                            state.events.push(super::state::GameEvent::PaddleHit);
+                            ball.paddle_cooldown = paddle_cooldown_ticks;
+                            ball.bumper_chain = 0;
+                            ball.curve = paddle_curve(state.paddle.angular_vel);
+                            ball.spin = paddle_spin(state.paddle.angular_vel, ball.radius);
+                            state
+                                .events
+                                .push(super::state::GameEvent::PaddleHit(ball.pos));
+                            if hit_offset.abs() >= NEAR_DEATH_EDGE {
+                                state.events.push(super::state::GameEvent::NearDeathSave);
+                            }
 
                             // 🔥 Paddle hit sparks - emit from contact, spread around normal
                             let spark_count = 8;
                             let normal_angle = paddle_result.normal.y.atan2(paddle_result.normal.x);
                             let spread = std::f32::consts::FRAC_PI_2; // 90 degree cone
                             for j in 0..spark_count {
-                                let hash = (state.time_ticks as u32)
-                                    .wrapping_mul(2654435761)
-                                    .wrapping_add(j * 7919);
-                                let rand1 = (hash % 1000) as f32 / 1000.0 - 0.5;
-                                let rand2 = ((hash >> 10) % 1000) as f32 / 1000.0;
-                                let rand3 = ((hash >> 20) % 1000) as f32 / 1000.0;
+                                let hash = rng::hash_u32(state.time_ticks as u32, j);
+                                let rand1 = rng::rand_f32(hash) - 0.5;
+                                let rand2 = rng::rand_f32(hash >> 10);
+                                let rand3 = rng::rand_f32(hash >> 20);
 
                                 let spark_angle = normal_angle + rand1 * spread;
                                 let spark_speed = 100.0 + rand2 * 150.0;
@@ -680,6 +766,7 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                 let step_dt = dt / num_steps as f32;
 
                 let mut blocks_to_damage = Vec::new();
+                let damage = ball.block_damage();
 
                 // Clone block arcs for SDF closure (needed for borrow checker)
                 let block_arcs: Vec<_> = state
@@ -697,6 +784,21 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                     })
                     .collect();
 
+                // Angular broadphase: only blocks near the ball's angle get the SDF test.
+                // Blocks only move between ticks, so rebuild only after some were destroyed.
+                let grid = match block_grid.take() {
+                    Some((len, grid)) if len == block_arcs.len() => grid,
+                    _ => BlockGrid::build(
+                        block_arcs.iter().map(
+                            |&(_, theta_start, theta_end, radius, thickness, _)| {
+                                (theta_start, theta_end, radius, thickness)
+                            },
+                        ),
+                        max_ball_radius,
+                    ),
+                };
+                let block_grid_ref = block_grid.insert((block_arcs.len(), grid));
+
                 for _step in 0..num_steps {
                     // Move ball by one substep
                     ball.pos += ball.vel * step_dt;
@@ -704,18 +806,42 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                     // --- SDF Wall Collision ---
                     let wall_dist = ball.pos.length() - state.arena_radius;
                     if wall_dist > -ball.radius {
-                        // Hit outer wall
+                        // Hit outer wall. Only reflect (and report the hit) when
+                        // heading out, so a ball still touching the wall after
+                        // the bounce isn't flipped back into it.
                         let normal = -ball.pos.normalize_or_zero();
-                        ball.vel = reflect_velocity(ball.vel, normal);
+                        if ball.vel.dot(normal) < 0.0 {
+                            ball.vel = reflect_velocity(ball.vel, normal);
+                            state
+                                .events
+                                .push(super::state::GameEvent::WallHit(ball.pos));
+                        }
                         let penetration = wall_dist + ball.radius;
                         ball.pos += normal * (penetration + 1.0);
-                        state.events.push(super::state::GameEvent::WallHit);
+                    }
+
+                    // --- SDF Inner Ring Wall Collision ---
+                    if let Some(wall) = &state.inner_wall {
+                        let hit = super::sdf::check_sdf_collision(ball.pos, ball.radius, |p| {
+                            wall.distance(p)
+                        });
+                        if hit.hit {
+                            // Only reflect when heading into the wall, so a ball
+                            // still overlapping after the push isn't flipped back
+                            if ball.vel.dot(hit.normal) < 0.0 {
+                                ball.vel = reflect_velocity(ball.vel, hit.normal);
+                                state
+                                    .events
+                                    .push(super::state::GameEvent::WallHit(ball.pos));
+                            }
+                            ball.pos += hit.normal * (hit.penetration + 1.0);
+                        }
                     }
 
                     // --- SDF Block Collisions ---
-                    for (idx, &(block_id, theta_start, theta_end, radius, thickness, kind)) in
-                        block_arcs.iter().enumerate()
-                    {
+                    for idx in block_grid_ref.1.candidates(ball.pos) {
+                        let (block_id, theta_start, theta_end, radius, thickness, kind) =
+                            block_arcs[idx];
                         // Ghost blocks: check if visible enough to be hittable
                         if kind == super::state::BlockKind::Ghost
                             && idx < state.blocks.len()
@@ -760,7 +886,7 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                                     .wrapping_mul(31337)
                                     .wrapping_add(state.time_ticks as u32)
                                     .wrapping_mul(7919);
-                                let rand_t = (hash % 1000) as f32 / 1000.0; // 0.0 to 1.0
+                                let rand_t = rng::rand_f32(hash); // 0.0 to 1.0
                                 let random_max = 0.5 + rand_t * (std::f32::consts::TAU - 0.5);
 
                                 ball.state = BallState::Sliding {
@@ -775,46 +901,56 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                                     max_travel: random_max, // Random exit point
                                 };
                                 // Store velocity direction for later
-                                ball.vel = ball.vel.normalize() * ball.vel.length();
+                                ball.vel = ball.vel.normalize_or_zero() * ball.vel.length();
                             }
                             continue;
                         }
 
                         if inside_block {
-                            // Compute normal via SDF gradient
-                            let eps = 1.0;
-                            let dx = super::sdf::sd_arc(
-                                ball.pos + Vec2::new(eps, 0.0),
-                                theta_start,
-                                theta_end,
-                                radius,
-                                thickness,
-                            ) - super::sdf::sd_arc(
-                                ball.pos - Vec2::new(eps, 0.0),
-                                theta_start,
-                                theta_end,
-                                radius,
-                                thickness,
-                            );
-                            let dy = super::sdf::sd_arc(
-                                ball.pos + Vec2::new(0.0, eps),
-                                theta_start,
-                                theta_end,
-                                radius,
-                                thickness,
-                            ) - super::sdf::sd_arc(
-                                ball.pos - Vec2::new(0.0, eps),
+                            // Analytic normal: stable at the rounded arc tips
+                            let normal = super::sdf::sd_arc_normal(
+                                ball.pos,
                                 theta_start,
                                 theta_end,
                                 radius,
                                 thickness,
                             );
-                            let normal = Vec2::new(dx, dy).normalize_or_zero();
 
-                            if !ball.piercing {
+                            // A heavy ball's first block doesn't bounce it; it keeps
+                            // going until it's out the other side
+                            let already_plowing = ball.plowing == Some(block_id);
+                            if !already_plowing && ball.plow_ready && !kind.is_indestructible() {
+                                ball.plowing = Some(block_id);
+                                ball.plow_ready = false;
+                            }
+                            let plowing = ball.plowing == Some(block_id);
+
+                            if !ball.piercing && !plowing {
                                 // Only reflect if moving toward the surface
                                 if ball.vel.dot(normal) < 0.0 {
                                     ball.vel = reflect_velocity(ball.vel, normal);
+                                    if kind == super::state::BlockKind::Bumper {
+                                        bumper_kick(
+                                            ball,
+                                            normal,
+                                            max_speed,
+                                            &mut state.particles,
+                                            state.time_ticks,
+                                        );
+                                        state.screen_shake =
+                                            (state.screen_shake + BUMPER_SHAKE).min(1.0);
+                                        state
+                                            .events
+                                            .push(super::state::GameEvent::BumperHit(ball.pos));
+                                        if idx < state.blocks.len() {
+                                            state.blocks[idx].wobble = 1.0;
+                                        }
+                                    } else if kind == super::state::BlockKind::Invincible {
+                                        // Never damaged, so the damage pass won't report it
+                                        state
+                                            .events
+                                            .push(super::state::GameEvent::BlockHit(ball.pos));
+                                    }
                                 }
                                 // Push out
                                 let penetration = ball.radius - block_dist;
@@ -823,10 +959,12 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
 
                             // Damage block (check original state.blocks)
                             if idx < state.blocks.len()
-                                && state.blocks[idx].kind != super::state::BlockKind::Invincible
+                                && !state.blocks[idx].kind.is_indestructible()
+                                && !already_plowing
                                 && !blocks_to_damage.contains(&idx)
                             {
                                 blocks_to_damage.push(idx);
+                                ball.bumper_chain = 0;
                                 state.combo += 1;
 
                                 // Electric blocks give speed boost and charge!
@@ -841,34 +979,37 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                     }
                 }
 
+                // Done plowing once the ball is clear of the block
+                if let Some(id) = ball.plowing
+                    && !block_arcs
+                        .iter()
+                        .any(|&(block_id, start, end, radius, thickness, _)| {
+                            block_id == id
+                                && super::sdf::sd_arc(ball.pos, start, end, radius, thickness)
+                                    < ball.radius
+                        })
+                {
+                    ball.plowing = None;
+                }
+
                 // Apply block damage
                 for idx in blocks_to_damage.into_iter().rev() {
                     // Trigger wobble on jello blocks
                     state.blocks[idx].trigger_wobble();
                     let block_kind = state.blocks[idx].kind;
 
-                    state.blocks[idx].hp = state.blocks[idx].hp.saturating_sub(1);
+                    state.blocks[idx].hp = state.blocks[idx].hp.saturating_sub(damage);
                     if state.blocks[idx].hp == 0 {
                         let block = state.blocks.remove(idx);
-                        state
-                            .events
-                            .push(super::state::GameEvent::BlockBreak(block_kind));
+                        state.events.push(super::state::GameEvent::BlockBreak(
+                            block_kind,
+                            block.arc.center(),
+                        ));
 
                         // SPAWN PARTICLES! 🎆
                         let mid_angle = (block.arc.theta_start + block.arc.theta_end) / 2.0;
                         let arc_span = block.arc.theta_end - block.arc.theta_start;
-                        let color = match block.kind {
-                            super::state::BlockKind::Glass => 0,
-                            super::state::BlockKind::Armored => 1,
-                            super::state::BlockKind::Explosive => 2,
-                            super::state::BlockKind::Invincible => 3,
-                            super::state::BlockKind::Portal { .. } => 4,
-                            super::state::BlockKind::Jello => 5,
-                            super::state::BlockKind::Crystal => 6,
-                            super::state::BlockKind::Electric => 7,
-                            super::state::BlockKind::Magnet => 8,
-                            super::state::BlockKind::Ghost => 9,
-                        };
+                        let color = block.kind.color_index();
 
                         // Crystal blocks shatter with extra sparkles!
                         let particle_bonus = if block.kind == super::state::BlockKind::Crystal {
@@ -883,23 +1024,16 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                         let particle_count =
                             ((30.0 + arc_span * 40.0).min(60.0) as usize).max(25) + particle_bonus;
                         // Include block ID in seed so each block gets unique particles
-                        let particle_seed =
-                            state.time_ticks as u32 ^ block.id.wrapping_mul(2654435761);
+                        let particle_seed = state.time_ticks as u32 ^ rng::hash_u32(block.id, 0);
 
                         for i in 0..particle_count {
-                            if state.particles.len() >= super::state::MAX_PARTICLES {
-                                state.particles.remove(0);
-                            }
                             // Deterministic "random" spread using hash
-                            let hash = particle_seed
-                                .wrapping_mul(2654435761)
-                                .wrapping_add(i as u32 * 7919);
+                            let hash = rng::hash_u32(particle_seed, i as u32);
 
                             // Spawn along the block arc
-                            let angle_offset =
-                                ((hash % 1000) as f32 / 1000.0 - 0.5) * arc_span * 1.2;
+                            let angle_offset = (rng::rand_f32(hash) - 0.5) * arc_span * 1.2;
                             let radius_offset =
-                                ((hash / 1000 % 1000) as f32 / 1000.0 - 0.5) * block.arc.thickness;
+                                (rng::rand_f32(hash / 1000) - 0.5) * block.arc.thickness;
                             let spawn_angle = mid_angle + angle_offset;
                             let spawn_radius = block.arc.radius + radius_offset;
 
@@ -910,15 +1044,13 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
 
                             // Velocity: BURST in ALL directions (full 360°)
                             // Re-hash for velocity to get independent random values
-                            let vel_hash = hash.wrapping_mul(1664525).wrapping_add(1013904223);
+                            let [vel_hash, speed_hash, size_hash] = rng::rehash3(hash);
                             let vel_angle =
                                 (vel_hash % 10000) as f32 / 10000.0 * std::f32::consts::TAU;
-                            let speed_hash = vel_hash.wrapping_mul(22695477).wrapping_add(1);
                             let base_speed = 80.0 + (speed_hash % 200) as f32;
                             let vel = Vec2::new(vel_angle.cos(), vel_angle.sin()) * base_speed;
 
                             // Small particles - use rehashed value for size variety
-                            let size_hash = speed_hash.wrapping_mul(69069).wrapping_add(1);
                             let size = 1.5 + (size_hash % 250) as f32 / 100.0;
 
                             state.particles.push(super::state::Particle {
@@ -932,15 +1064,18 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
 
                         // PICKUP SPAWN! Thick blocks ALWAYS drop, others ~8% chance
                         let is_powerup_block = block.arc.thickness > BLOCK_THICKNESS * 1.2;
-                        let pickup_hash =
-                            particle_seed.wrapping_mul(31337).wrapping_add(idx as u32);
+                        let pickup_hash = pickup_roll(state.wave_index, state.seed, block.id);
                         if is_powerup_block || pickup_hash.is_multiple_of(12) {
-                            let pickup_kind = match pickup_hash / 10 % 5 {
+                            let pickup_kind = match pickup_hash / 10 % 9 {
                                 0 => PickupKind::MultiBall,
                                 1 => PickupKind::Slow,
                                 2 => PickupKind::Piercing,
                                 3 => PickupKind::WidenPaddle,
-                                _ => PickupKind::Shield,
+                                4 => PickupKind::Shield,
+                                5 => PickupKind::Sticky,
+                                6 => PickupKind::HeavyBall,
+                                7 => PickupKind::TimeWarp,
+                                _ => PickupKind::Freeze,
                             };
                             let spawn_pos = Vec2::new(
                                 mid_angle.cos() * block.arc.radius,
@@ -982,21 +1117,18 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                             }
 
                             // EXPLOSION: damage ALL neighbors (except invincible)
-                            if is_explosive
-                                && is_neighbor
-                                && neighbor.kind != super::state::BlockKind::Invincible
-                            {
+                            if is_explosive && is_neighbor && !neighbor.kind.is_indestructible() {
                                 explosion_victims.push(n_idx);
                             }
                         }
 
-                        // Apply explosion damage to neighbors with VISIBLE CHAIN REACTION
+                        // VISIBLE CHAIN REACTION: fireballs fly to every neighbor in the blast
                         let explosion_center = Vec2::new(
                             destroyed_mid_angle.cos() * destroyed_radius,
                             destroyed_mid_angle.sin() * destroyed_radius,
                         );
 
-                        for victim_idx in explosion_victims.into_iter().rev() {
+                        for &victim_idx in explosion_victims.iter().rev() {
                             if victim_idx < state.blocks.len() {
                                 let victim = &state.blocks[victim_idx];
                                 let v_mid = (victim.arc.theta_start + victim.arc.theta_end) / 2.0;
@@ -1010,18 +1142,16 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                                 let distance = (victim_center - explosion_center).length();
 
                                 for i in 0..8 {
-                                    if state.particles.len() >= super::state::MAX_PARTICLES {
-                                        state.particles.remove(0);
-                                    }
-                                    let hash = (state.time_ticks as u32)
-                                        .wrapping_mul(7919)
-                                        .wrapping_add(victim_idx as u32 * 1000 + i);
+                                    let hash = rng::hash_u32(
+                                        state.time_ticks as u32,
+                                        victim_idx as u32 * 1000 + i,
+                                    );
 
                                     // Start at explosion, travel toward victim
-                                    let spread = ((hash % 1000) as f32 / 1000.0 - 0.5) * 0.3;
+                                    let spread = (rng::rand_f32(hash) - 0.5) * 0.3;
                                     let perpendicular = Vec2::new(-direction.y, direction.x);
                                     let fireball_dir =
-                                        (direction + perpendicular * spread).normalize();
+                                        (direction + perpendicular * spread).normalize_or_zero();
 
                                     // Speed based on distance so they arrive at similar times
                                     let speed =
@@ -1035,128 +1165,33 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                                         size: 6.0 + ((hash / 10000 % 100) as f32 / 100.0) * 4.0,
                                     });
                                 }
-
-                                // Impact particles AT the victim
-                                for i in 0..6 {
-                                    if state.particles.len() >= super::state::MAX_PARTICLES {
-                                        state.particles.remove(0);
-                                    }
-                                    let hash = (state.time_ticks as u32)
-                                        .wrapping_add(i * 3571 + victim_idx as u32);
-                                    let angle = v_mid + ((hash % 1000) as f32 / 1000.0 - 0.5) * 0.8;
-                                    let pos =
-                                        Vec2::new(angle.cos() * v_radius, angle.sin() * v_radius);
-                                    let vel = Vec2::new(angle.cos(), angle.sin())
-                                        * (80.0 + (hash / 1000 % 80) as f32);
-                                    state.particles.push(super::state::Particle {
-                                        pos,
-                                        vel,
-                                        color: 2, // Orange
-                                        life: 0.5,
-                                        size: 4.0,
-                                    });
-                                }
-
-                                // Now damage the victim
-                                state.blocks[victim_idx].hp =
-                                    state.blocks[victim_idx].hp.saturating_sub(2);
-                                state.blocks[victim_idx].trigger_wobble();
                             }
                         }
 
-                        // Spawn particles for blocks killed by explosion BEFORE removing them
-                        for block in state.blocks.iter() {
-                            if block.hp == 0 {
-                                let mid_angle = (block.arc.theta_start + block.arc.theta_end) / 2.0;
-                                let arc_span = block.arc.theta_end - block.arc.theta_start;
-                                let color = match block.kind {
-                                    super::state::BlockKind::Glass => 0,
-                                    super::state::BlockKind::Armored => 1,
-                                    super::state::BlockKind::Explosive => 2,
-                                    super::state::BlockKind::Invincible => 3,
-                                    super::state::BlockKind::Portal { .. } => 4,
-                                    super::state::BlockKind::Jello => 5,
-                                    super::state::BlockKind::Crystal => 6,
-                                    super::state::BlockKind::Electric => 7,
-                                    super::state::BlockKind::Magnet => 8,
-                                    super::state::BlockKind::Ghost => 9,
-                                };
-                                // Disintegration burst for explosion victims
-                                let particle_count = (25.0 + arc_span * 30.0).min(45.0) as usize;
-                                let particle_seed = state.time_ticks as u32 + block.id;
-
-                                for i in 0..particle_count {
-                                    if state.particles.len() >= super::state::MAX_PARTICLES {
-                                        state.particles.remove(0);
-                                    }
-                                    let hash = particle_seed
-                                        .wrapping_mul(2654435761)
-                                        .wrapping_add(i as u32 * 7919);
-                                    let angle_offset =
-                                        ((hash % 1000) as f32 / 1000.0 - 0.5) * arc_span * 1.2;
-                                    let radius_offset = ((hash / 1000 % 1000) as f32 / 1000.0
-                                        - 0.5)
-                                        * block.arc.thickness;
-                                    let spawn_angle = mid_angle + angle_offset;
-                                    let spawn_radius = block.arc.radius + radius_offset;
-                                    let pos = Vec2::new(
-                                        spawn_angle.cos() * spawn_radius,
-                                        spawn_angle.sin() * spawn_radius,
-                                    );
-                                    // Burst in all directions - re-hash for independent random values
-                                    let vel_hash =
-                                        hash.wrapping_mul(1664525).wrapping_add(1013904223);
-                                    let vel_angle =
-                                        (vel_hash % 10000) as f32 / 10000.0 * std::f32::consts::TAU;
-                                    let speed_hash =
-                                        vel_hash.wrapping_mul(22695477).wrapping_add(1);
-                                    let base_speed = 70.0 + (speed_hash % 180) as f32;
-                                    let vel =
-                                        Vec2::new(vel_angle.cos(), vel_angle.sin()) * base_speed;
-                                    let size_hash = speed_hash.wrapping_mul(69069).wrapping_add(1);
-                                    let size = 1.5 + (size_hash % 200) as f32 / 100.0;
-
-                                    state.particles.push(super::state::Particle {
-                                        pos,
-                                        vel,
-                                        color,
-                                        life: 0.32, // Quick disintegration
-                                        size,
-                                    });
-                                }
-
-                                // Score for explosion kills too
-                                let base_score = match block.kind {
-                                    super::state::BlockKind::Glass => 10,
-                                    super::state::BlockKind::Armored => 25,
-                                    super::state::BlockKind::Jello => 20,
-                                    _ => 15,
-                                };
-                                state.score += base_score;
-                            }
+                        // The blast ring delivers the damage as it spreads
+                        if is_explosive {
+                            let pending: Vec<u32> = explosion_victims
+                                .iter()
+                                .map(|&idx| state.blocks[idx].id)
+                                .collect();
+                            state.shockwaves.push(Shockwave {
+                                center: explosion_center,
+                                radius: 0.0,
+                                expand_rate: SHOCKWAVE_SPEED,
+                                pending,
+                                scored: 0,
+                            });
                         }
 
-                        // Remove dead blocks from explosion
-                        state.blocks.retain(|b| b.hp > 0);
-
-                        // Score with combo multiplier! (1.1x at combo 2, up to 3.0x at 21)
-                        let base_score = match block.kind {
-                            super::state::BlockKind::Glass => 10,
-                            super::state::BlockKind::Armored => 25,
-                            super::state::BlockKind::Explosive => 50,
-                            super::state::BlockKind::Jello => 20,
-                            super::state::BlockKind::Invincible => 0, // Should never happen
-                            _ => 15,
-                        };
-                        let multiplier = if state.combo > 1 {
-                            (1.0 + (state.combo - 1) as f32 * 0.1).min(3.0)
-                        } else {
-                            1.0
-                        };
+                        // Score with combo multiplier!
+                        let base_score = state.tuning.blocks.score(block.kind);
+                        let multiplier = state.tuning.combo_multiplier(state.combo);
                         state.score += (base_score as f32 * multiplier) as u64;
                     } else {
                         // Block hit but not destroyed
-                        state.events.push(super::state::GameEvent::BlockHit);
+                        state
+                            .events
+                            .push(super::state::GameEvent::BlockHit(ball.pos));
                     }
                 }
 
@@ -1240,8 +1275,40 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                     ball.electric_charge = (ball.electric_charge - dt / 3.0).max(0.0);
                 }
 
+                // Spin the marker and let the spin die down
+                if ball.spin != 0.0 {
+                    ball.spin_angle =
+                        (ball.spin_angle + ball.spin * dt).rem_euclid(std::f32::consts::TAU);
+                    ball.spin *= (-SPIN_DECAY_RATE * dt).exp();
+                    if ball.spin.abs() < 0.1 {
+                        ball.spin = 0.0;
+                    }
+                }
+
                 // Record trail position every tick
-                ball.record_trail();
+                ball.record_trail(trail_length);
+
+                // Near max speed, drop a scorch mark at the newest trail point.
+                // Scorch never evicts other particles and has its own cap.
+                let scorch_speed = base_max_speed * super::state::SCORCH_SPEED_FRACTION;
+                if let Some(point) = ball.trail.first()
+                    && point.speed >= scorch_speed
+                    && state
+                        .time_ticks
+                        .is_multiple_of(super::state::SCORCH_INTERVAL_TICKS)
+                    && state.particles.len() < super::state::MAX_PARTICLES
+                    && state.particles.iter().filter(|p| p.is_scorch()).count()
+                        < super::state::MAX_SCORCH_PARTICLES
+                {
+                    let heat = (point.speed - scorch_speed) / (base_max_speed - scorch_speed);
+                    state.particles.push(super::state::Particle {
+                        pos: point.pos,
+                        vel: Vec2::ZERO,
+                        color: super::state::Particle::SCORCH,
+                        life: 0.6 + 0.4 * heat.clamp(0.0, 1.0),
+                        size: ball.radius * 0.8,
+                    });
+                }
             }
 
             // Spawn collected pickups (deferred from block destruction)
@@ -1256,19 +1323,11 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                 });
             }
 
+            advance_shockwaves(state, dt);
+
             // Update particles
             for particle in state.particles.iter_mut() {
-                // Apply velocity
-                particle.pos += particle.vel * dt;
-                // Gravity toward black hole (weaker than ball)
-                let to_center = -particle.pos.normalize_or_zero();
-                particle.vel += to_center * 50.0 * dt;
-                // Drag to slow down
-                particle.vel *= 0.98;
-                // Decay life
-                particle.life -= dt * 1.5; // ~0.67 second lifetime
-                // Shrink as they die
-                particle.size *= 0.995;
+                particle.update(dt);
             }
             // Remove dead particles
             state.particles.retain(|p| p.life > 0.0);
@@ -1294,9 +1353,8 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                 // No TTL countdown - pickups live until collected or sucked into black hole
             }
 
-            // Check pickup collection by paddle
+            // Check pickup collection by paddle (same arc snapshot as collisions)
             let paddle_theta = state.paddle.theta;
-            let paddle_half_arc = state.paddle.arc_width / 2.0;
             let paddle_inner = PADDLE_RADIUS - PADDLE_THICKNESS / 2.0;
             let paddle_outer = PADDLE_RADIUS + PADDLE_THICKNESS / 2.0;
 
@@ -1316,7 +1374,9 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
 
                 if in_arc && in_radius {
                     collected_effects.push(pickup.kind);
-                    state.events.push(super::state::GameEvent::PickupCollect);
+                    state
+                        .events
+                        .push(super::state::GameEvent::PickupCollect(pickup.pos));
                     false // Remove collected pickup
                 } else if pickup_dist < BLACK_HOLE_RADIUS {
                     false // Remove when sucked into black hole
@@ -1324,19 +1384,19 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                     true // Keep
                 }
             });
+            state.pickups_collected_this_wave += collected_effects.len() as u32;
 
             // Apply collected effects
             for kind in collected_effects {
                 match kind {
                     PickupKind::MultiBall => {
-                        // Spawn 2 extra balls
-                        if let Some(ball) = state
-                            .balls
-                            .iter()
-                            .find(|b| matches!(b.state, BallState::Free))
-                            .cloned()
-                        {
+                        // Spawn 2 extra balls, up to the ball cap
+                        let parent = state.free_balls().next().cloned();
+                        if let Some(ball) = parent {
                             for i in 0..2 {
+                                if state.balls.len() >= MAX_BALLS {
+                                    break;
+                                }
                                 let angle_offset: f32 = if i == 0 { 0.5 } else { -0.5 };
                                 let new_vel = Vec2::new(
                                     ball.vel.x * angle_offset.cos()
@@ -1344,7 +1404,7 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                                     ball.vel.x * angle_offset.sin()
                                         + ball.vel.y * angle_offset.cos(),
                                 )
-                                .normalize()
+                                .normalize_or_zero()
                                     * ball.vel.length();
                                 let id = state.next_entity_id();
                                 state.balls.push(super::state::Ball {
@@ -1355,26 +1415,54 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                                     state: BallState::Free,
                                     piercing: ball.piercing,
                                     paddle_cooldown: 0,
-                                    trail: ball.trail.clone(), // Copy parent's trail
+                                    // Start from the spawn point; the parent's history would snap
+                                    trail: vec![TrailPoint {
+                                        pos: ball.pos,
+                                        speed: new_vel.length(),
+                                    }],
                                     inside_portals: Vec::new(),
                                     electric_charge: ball.electric_charge, // Inherit parent's charge!
+                                    curve: 0.0,
+                                    spin: ball.spin,
+                                    spin_angle: ball.spin_angle,
+                                    bumper_chain: 0,
+                                    // Splinters are light; the heavy ball stays one of a kind
+                                    mass: 1.0,
+                                    plow_ready: false,
+                                    plowing: None,
                                 });
                             }
                         }
                     }
                     PickupKind::Slow => {
-                        state.effects.slow_ticks = 600; // 5 seconds at 120Hz
+                        state.effects.slow_ticks = state.tuning.pickups.slow_ticks;
                     }
                     PickupKind::Piercing => {
-                        state.effects.piercing_ticks = 480; // 4 seconds
+                        state.effects.piercing_ticks = state.tuning.pickups.piercing_ticks;
                     }
                     PickupKind::WidenPaddle => {
-                        state.effects.widen_ticks = 720; // 6 seconds per stack
+                        state.effects.widen_ticks = state.tuning.pickups.widen_ticks;
                         state.effects.widen_stacks += 1; // Stack additively!
                     }
                     PickupKind::Shield => {
                         state.effects.shield_active = true;
                     }
+                    PickupKind::Freeze => {
+                        state.effects.freeze_ticks = state.tuning.pickups.freeze_ticks;
+                    }
+                    PickupKind::Sticky => {
+                        state.effects.sticky_active = true;
+                    }
+                    PickupKind::TimeWarp => {
+                        state.effects.time_warp_ticks = state.tuning.pickups.time_warp_ticks;
+                    }
+                    PickupKind::HeavyBall => {
+                        for ball in &mut state.balls {
+                            if matches!(ball.state, BallState::Free | BallState::Attached { .. }) {
+                                ball.make_heavy();
+                            }
+                        }
+                    }
                 }
                 // Visual feedback - particles
                 state.screen_shake = (state.screen_shake + 0.15).min(1.0);
@@ -1383,6 +1471,8 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
             // Decay timed effects
             state.effects.slow_ticks = state.effects.slow_ticks.saturating_sub(1);
             state.effects.piercing_ticks = state.effects.piercing_ticks.saturating_sub(1);
+            state.effects.freeze_ticks = state.effects.freeze_ticks.saturating_sub(1);
+            state.effects.time_warp_ticks = state.effects.time_warp_ticks.saturating_sub(1);
 
             // Widen stacks decay one at a time
             if state.effects.widen_ticks > 0 {
@@ -1391,7 +1481,7 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                 // Timer expired, remove one stack and reset timer if more stacks remain
                 state.effects.widen_stacks -= 1;
                 if state.effects.widen_stacks > 0 {
-                    state.effects.widen_ticks = 720; // Reset timer for next stack
+                    state.effects.widen_ticks = state.tuning.pickups.widen_ticks; // Next stack
                 }
             }
 
@@ -1402,16 +1492,20 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
             }
 
             // Calculate target paddle width (+50% per stack, capped at 3x)
+            let max_width = PADDLE_ARC_WIDTH * 3.0;
             let target_width = if state.effects.widen_stacks > 0 {
-                (PADDLE_ARC_WIDTH * (1.0 + 0.5 * state.effects.widen_stacks as f32))
-                    .min(PADDLE_ARC_WIDTH * 3.0)
+                (PADDLE_ARC_WIDTH * (1.0 + 0.5 * state.effects.widen_stacks as f32)).min(max_width)
             } else {
                 PADDLE_ARC_WIDTH
             };
 
             // Spring-damper physics for bouncy overshoot
-            let spring_k = 150.0; // Spring stiffness (higher = faster)
-            let damping = 8.0; // Damping (lower = more bouncy/overshoot)
+            let spring_k: f32 = 150.0; // Spring stiffness (higher = faster)
+            let damping = if state.effects.widen_stacks > 0 {
+                8.0 // Damping (lower = more bouncy/overshoot)
+            } else {
+                2.0 * spring_k.sqrt() // Critically damped: shrink back without wobble
+            };
             let diff = target_width - state.paddle.arc_width;
 
             // F = -kx - bv (spring force - damping force)
@@ -1419,29 +1513,38 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
             let damping_force = damping * state.paddle.arc_width_vel;
             let acceleration = spring_force - damping_force;
 
-            state.paddle.arc_width_vel += acceleration * dt;
-            state.paddle.arc_width += state.paddle.arc_width_vel * dt;
+            state.paddle.arc_width_vel += acceleration * real_dt;
+            state.paddle.arc_width += state.paddle.arc_width_vel * real_dt;
+
+            // Overshoot stays within [base, 3x] so collisions are never unfair
+            let clamped = state.paddle.arc_width.clamp(PADDLE_ARC_WIDTH, max_width);
+            if clamped != state.paddle.arc_width {
+                state.paddle.arc_width = clamped;
+                state.paddle.arc_width_vel = 0.0;
+            }
 
             // Apply slow effect - reduce ball speed by 40%
             if state.effects.slow_ticks > 0 {
-                for ball in state.balls.iter_mut() {
-                    if matches!(ball.state, BallState::Free) {
-                        let speed = ball.vel.length();
-                        let slowed_max = BALL_MAX_SPEED * 0.6;
-                        if speed > slowed_max {
-                            ball.vel = ball.vel.normalize() * slowed_max;
-                        }
+                let slowed_max = state.tuning.ball_max_speed * 0.6;
+                for ball in state.free_balls_mut() {
+                    let speed = ball.vel.length();
+                    if speed > slowed_max {
+                        ball.vel = ball.vel.normalize() * slowed_max;
                     }
                 }
             }
 
-            // Black hole check - start death animation (or bounce if shield active)
+            // Black hole check - start death animation (or bounce if shield active).
+            // During the mercy window after a respawn it always bounces, without
+            // spending the shield.
+            let mercy = state.mercy_ticks > 0;
+            state.mercy_ticks = state.mercy_ticks.saturating_sub(1);
             let mut shield_used = false;
             for ball in state.balls.iter_mut() {
                 if matches!(ball.state, BallState::Free)
                     && ball.pos.length() <= BLACK_HOLE_LOSS_RADIUS + ball.radius
                 {
-                    if state.effects.shield_active && !shield_used {
+                    if mercy || (state.effects.shield_active && !shield_used) {
                         // Shield saves the ball! Bounce it away
                         // Use velocity direction if position is too close to center
                         let outward = if ball.pos.length() > 1.0 {
@@ -1451,15 +1554,23 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                         } else {
                             Vec2::new(0.0, -1.0) // Default: shoot downward toward paddle
                         };
-                        ball.vel = outward * BALL_MAX_SPEED * 0.8;
+                        ball.vel = outward * state.tuning.ball_max_speed * 0.8;
                         ball.pos = outward * (BLACK_HOLE_LOSS_RADIUS + ball.radius + 10.0);
-                        shield_used = true;
+                        shield_used = !mercy;
+                        if shield_used {
+                            state.events.push(super::state::GameEvent::ShieldSave);
+                        }
                         state.screen_shake = (state.screen_shake + 0.5).min(1.0);
                     } else {
                         ball.state = BallState::Dying {
                             timer: 0.0,
                             start_pos: (ball.pos.x, ball.pos.y),
                         };
+                        if state.combo >= COMBO_BREAK_MIN {
+                            state.events.push(super::state::GameEvent::ComboBreak {
+                                previous: state.combo,
+                            });
+                        }
                         state.combo = 0;
                     }
                 }
@@ -1488,7 +1599,7 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                         (base_angle + spiral_angle).cos() * radius,
                         (base_angle + spiral_angle).sin() * radius,
                     );
-                    ball.radius = BALL_RADIUS * shrink * shrink; // Shrink faster
+                    ball.radius = ball.base_radius() * shrink * shrink; // Shrink faster
 
                     // Set velocity for trail color (based on movement)
                     if dt > 0.0 {
@@ -1496,10 +1607,17 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                     }
 
                     // Record trail during death spiral
-                    ball.record_trail();
+                    ball.record_trail(trail_length);
                 }
             }
 
+            // A caught ball waits on the paddle for a new serve
+            if caught {
+                state.catch_available = false;
+                state.catch_armed = false;
+                state.phase = GamePhase::Serve;
+            }
+
             // Remove fully dead balls
             state.balls.retain(|ball| {
                 if let BallState::Dying { timer, .. } = ball.state {
@@ -1519,23 +1637,30 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                 } else {
                     // Respawn after delay (handled by respawn timer, simplified here)
                     state.spawn_ball_attached();
+                    state.mercy_ticks = MERCY_TICKS;
+                    state.reset_catch();
                     state.phase = GamePhase::Serve;
                 }
             }
 
+            // Ring clears (this tick may have emptied more than one)
+            let rings_after = state.clearable_rings();
+            for ring_id in rings_before {
+                if !rings_after.contains(&ring_id) {
+                    clear_ring(state, ring_id);
+                }
+            }
+
             // Check wave clear (invincible blocks don't count)
-            let clearable_blocks = state.blocks.iter().filter(|b| b.counts_for_clear()).count();
-            if clearable_blocks == 0 {
+            if state.clearable_blocks() == 0 {
                 // 🎆 WAVE CLEAR CELEBRATION!
                 // Spawn ring of particles expanding outward
                 let ring_particles = 32;
                 for i in 0..ring_particles {
-                    let hash = (state.wave_index)
-                        .wrapping_mul(2654435761)
-                        .wrapping_add(i * 31337);
-                    let rand1 = (hash % 1000) as f32 / 1000.0;
-                    let rand2 = ((hash >> 10) % 1000) as f32 / 1000.0;
-                    let rand3 = ((hash >> 20) % 1000) as f32 / 1000.0;
+                    let hash = rng::hash_u32(state.wave_index, i);
+                    let rand1 = rng::rand_f32(hash);
+                    let rand2 = rng::rand_f32(hash >> 10);
+                    let rand3 = rng::rand_f32(hash >> 20);
 
                     let angle = std::f32::consts::TAU * (i as f32 / ring_particles as f32);
                     let outward = Vec2::new(angle.cos(), angle.sin());
@@ -1550,12 +1675,10 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                 }
                 // Inner burst
                 for i in 0..24u32 {
-                    let hash = (state.wave_index)
-                        .wrapping_mul(7919)
-                        .wrapping_add(i * 104729);
-                    let rand1 = (hash % 1000) as f32 / 1000.0;
-                    let rand2 = ((hash >> 10) % 1000) as f32 / 1000.0;
-                    let rand3 = ((hash >> 20) % 1000) as f32 / 1000.0;
+                    let hash = rng::hash_u32(state.wave_index, ring_particles + i);
+                    let rand1 = rng::rand_f32(hash);
+                    let rand2 = rng::rand_f32(hash >> 10);
+                    let rand3 = rng::rand_f32(hash >> 20);
 
                     let angle = rand1 * std::f32::consts::TAU;
                     let outward = Vec2::new(angle.cos(), angle.sin());
@@ -1572,10 +1695,19 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
                 state.wave_flash = 1.0;
                 state.events.push(super::state::GameEvent::WaveClear);
 
+                // Purist bonus: the whole wave without a single pickup
+                let bonus = state.tuning.purist_bonus;
+                if bonus > 0 && state.pickups_collected_this_wave == 0 {
+                    state.score += bonus;
+                    state
+                        .events
+                        .push(super::state::GameEvent::PuristBonus { amount: bonus });
+                }
+
                 // Remove invincible blocks too when wave clears
                 state.blocks.clear();
                 state.wave_index += 1;
-                state.breather_ticks = BREATHER_DURATION_TICKS;
+                state.breather_ticks = state.tuning.breather_ticks;
                 state.phase = GamePhase::Breather;
                 // Clear balls for breather
                 state.balls.clear();
@@ -1590,12 +1722,7 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
 
             // Keep particles animating during breather!
             for particle in state.particles.iter_mut() {
-                particle.pos += particle.vel * dt;
-                let to_center = -particle.pos.normalize_or_zero();
-                particle.vel += to_center * 50.0 * dt;
-                particle.vel *= 0.98;
-                particle.life -= dt * 1.5;
-                particle.size *= 0.995;
+                particle.update(dt);
             }
             state.particles.retain(|p| p.life > 0.0);
 
@@ -1612,16 +1739,67 @@ pub fn tick(state: &mut GameState, input: &TickInput, dt: f32) {
         _ => {}
     }
 
+    announce_combo_milestones(state);
+
+    // Refresh magnet chain endpoints for next tick's physics and the renderer
+    state.update_magnet_poles();
+
     // Ensure deterministic ordering
     state.normalize_order();
+
+    #[cfg(debug_assertions)]
+    assert_finite(state);
+}
+
+/// Panic if a ball or paddle float went NaN/infinite this tick. Debug builds
+/// only: headless tests fail at the tick that produced the value instead of
+/// silently desyncing later.
+#[cfg(debug_assertions)]
+fn assert_finite(state: &GameState) {
+    let paddle = &state.paddle;
+    for (field, value) in [
+        ("theta", paddle.theta),
+        ("arc_width", paddle.arc_width),
+        ("angular_vel", paddle.angular_vel),
+        ("arc_width_vel", paddle.arc_width_vel),
+    ] {
+        assert!(
+            value.is_finite(),
+            "paddle {} is {} at tick {}",
+            field,
+            value,
+            state.time_ticks
+        );
+    }
+    for ball in &state.balls {
+        for (field, value) in [
+            ("pos.x", ball.pos.x),
+            ("pos.y", ball.pos.y),
+            ("vel.x", ball.vel.x),
+            ("vel.y", ball.vel.y),
+            ("radius", ball.radius),
+            ("curve", ball.curve),
+            ("electric_charge", ball.electric_charge),
+            ("spin", ball.spin),
+        ] {
+            assert!(
+                value.is_finite(),
+                "ball {} {} is {} at tick {}",
+                ball.id,
+                field,
+                value,
+                state.time_ticks
+            );
+        }
+    }
 }
 
 fn reflect_velocity(vel: Vec2, normal: Vec2) -> Vec2 {
     super::collision::reflect_velocity(vel, normal)
 }
 
-/// Calculate arena radius for a given wave
-pub fn arena_radius_for_wave(wave: u32) -> f32 {
+/// Calculate arena radius for a given wave, shaped by the tuning's growth curve
+pub fn arena_radius_for_wave(wave: u32, tuning: &TuningConfig) -> f32 {
     use super::state::{
         ARENA_GROWTH_PER_WAVE, ARENA_GROWTH_START_WAVE, BASE_ARENA_RADIUS, MAX_ARENA_RADIUS,
     };
@@ -1630,21 +1808,114 @@ pub fn arena_radius_for_wave(wave: u32) -> f32 {
         BASE_ARENA_RADIUS
     } else {
         let growth_waves = wave - ARENA_GROWTH_START_WAVE;
-        let growth = growth_waves as f32 * ARENA_GROWTH_PER_WAVE;
-        (BASE_ARENA_RADIUS + growth).min(MAX_ARENA_RADIUS)
+        let total_growth = MAX_ARENA_RADIUS - BASE_ARENA_RADIUS;
+        let span_waves = total_growth / ARENA_GROWTH_PER_WAVE;
+        let progress = tuning.arena_growth.progress(growth_waves, span_waves);
+        BASE_ARENA_RADIUS + total_growth * progress
+    }
+}
+
+/// Black hole pull this tick, before the distance falloff. Reversed Gravity
+/// waves push out instead; relaxed runs and Freeze have no pull at all.
+pub(super) fn black_hole_pull(state: &GameState) -> f32 {
+    let sign = if !state.tuning.gravity_enabled || state.effects.freeze_ticks > 0 {
+        0.0
+    } else if state
+        .wave_modifiers
+        .contains(WaveModifiers::REVERSED_GRAVITY)
+    {
+        -1.0
+    } else {
+        1.0
+    };
+    state.tuning.gravity.base_accel * endless_scaling(state.endless_level).gravity * sign
+}
+
+/// Send `ComboMilestone` for each milestone the combo has newly reached.
+/// A milestone isn't announced again until the combo drops below it.
+fn announce_combo_milestones(state: &mut GameState) {
+    if state.combo < state.combo_milestone {
+        state.combo_milestone = COMBO_MILESTONES
+            .into_iter()
+            .filter(|&m| m <= state.combo)
+            .max()
+            .unwrap_or(0);
+    }
+    for milestone in COMBO_MILESTONES {
+        if milestone > state.combo_milestone && milestone <= state.combo {
+            state
+                .events
+                .push(super::state::GameEvent::ComboMilestone(milestone));
+            state.combo_milestone = milestone;
+        }
+    }
+}
+
+/// Kick a ball that just bounced off a bumper along the surface `normal`.
+/// Each kick in an unbroken chain of bumper hits is weaker than the last,
+/// so a ball bouncing between bumpers settles instead of gaining speed.
+fn bumper_kick(
+    ball: &mut super::state::Ball,
+    normal: Vec2,
+    max_speed: f32,
+    particles: &mut super::state::ParticlePool,
+    time_ticks: u64,
+) {
+    if ball.bumper_chain < BUMPER_MAX_CHAIN {
+        let kick = BUMPER_KICK * BUMPER_CHAIN_DECAY.powi(ball.bumper_chain as i32);
+        ball.vel = (ball.vel + normal * kick).clamp_length_max(max_speed);
+    }
+    ball.bumper_chain = ball.bumper_chain.saturating_add(1);
+
+    // Ring of sparks off the bumper face
+    let normal_angle = normal.y.atan2(normal.x);
+    for j in 0..10 {
+        let hash = rng::hash_u32(time_ticks as u32 ^ ball.id, j);
+        let angle = normal_angle + (rng::rand_f32(hash) - 0.5) * std::f32::consts::PI;
+        let speed = 120.0 + rng::rand_f32(hash >> 10) * 160.0;
+        particles.push(super::state::Particle {
+            pos: ball.pos,
+            vel: Vec2::new(angle.cos(), angle.sin()) * speed,
+            color: 10, // Bumper - magenta
+            life: 0.25 + rng::rand_f32(hash >> 20) * 0.2,
+            size: 2.5 + rng::rand_f32(hash >> 5) * 2.0,
+        });
+    }
+}
+
+/// Acceleration toward the center at `pos` for a pull of `strength`,
+/// scaled by the profile's distance falloff
+pub(super) fn gravity_accel(pos: Vec2, strength: f32, profile: &GravityProfile) -> Vec2 {
+    -pos.normalize_or_zero() * strength * profile.multiplier(pos.length())
+}
+
+/// Difficulty multipliers for waves past the generator's content cap
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EndlessScaling {
+    /// Multiplier on the ball max speed
+    pub speed: f32,
+    /// Multiplier on the black hole pull
+    pub gravity: f32,
+    /// HP added to every breakable block
+    pub extra_hp: u8,
+}
+
+/// Scaling for `level` layers past the cap (formula in `tuning::params`)
+pub fn endless_scaling(level: u32) -> EndlessScaling {
+    let l = level as f32;
+    EndlessScaling {
+        speed: (1.0 + ENDLESS_SPEED_STEP * l).min(ENDLESS_SPEED_MAX_SCALE),
+        gravity: (1.0 + ENDLESS_GRAVITY_STEP * l).min(ENDLESS_GRAVITY_MAX_SCALE),
+        extra_hp: (level / ENDLESS_LEVELS_PER_HP).min(u8::MAX as u32) as u8,
     }
 }
 
 /// Generate wave with variable blocks, widths, and layers
 pub fn generate_wave(state: &mut GameState) {
-    use super::arc::ArcSegment;
-    use super::state::{Block, BlockKind, INNER_MARGIN, LAYER_SPACING, WALL_MARGIN};
-    use std::f32::consts::PI;
-
     let wave = state.wave_index;
 
     // Update arena radius for this wave
-    let new_radius = arena_radius_for_wave(wave);
+    let new_radius = arena_radius_for_wave(wave, &state.tuning);
     log::info!(
         "Wave {} arena radius: {} -> {}",
         wave,
@@ -1653,16 +1924,81 @@ pub fn generate_wave(state: &mut GameState) {
     );
     state.arena_radius = new_radius;
 
-    // Deterministic "randomness" based on wave number AND game seed
-    // This gives variety between runs while keeping determinism within a run
-    let wave_seed = ((wave as u64)
-        .wrapping_mul(2654435761)
-        .wrapping_add(state.seed)) as u32;
+    let wave_seed = wave_seed(wave, state.seed);
+
+    state.inner_wall = inner_wall_for_wave(wave, wave_seed, &state.tuning);
+    state.shockwaves.clear();
+    state.mercy_ticks = 0;
+    state.shuffle_ticks = 0;
+    state.pickups_collected_this_wave = 0;
+    state.wave_modifiers = wave_modifiers_for(wave, state.seed, &state.tuning);
+    if state.wave_modifiers.contains(WaveModifiers::WIND) {
+        // Seeded starting point so the first gust's direction varies
+        state.wind_strength = WIND_ACCEL;
+        state.wind_phase =
+            (wave_seed.wrapping_mul(2246822519) % 1000) as f32 / 1000.0 * std::f32::consts::TAU;
+    } else {
+        state.wind_strength = 0.0;
+        state.wind_phase = 0.0;
+    }
+    if !state.wave_modifiers.is_empty() {
+        log::info!(
+            "Wave {} chaos: {}",
+            wave,
+            state.wave_modifiers.names().collect::<Vec<_>>().join(" + ")
+        );
+    }
+
+    let (num_layers, endless_level) = wave_layers(wave, state.arena_radius, &state.tuning);
+    state.endless_level = endless_level;
+    if endless_level > 0 {
+        let endless = endless_scaling(endless_level);
+        log::info!(
+            "Wave {} endless level {}: speed x{:.2}, gravity x{:.2}, +{} hp",
+            wave,
+            endless_level,
+            endless.speed,
+            endless.gravity,
+            endless.extra_hp
+        );
+    }
+
+    log::info!(
+        "Wave {}: arena={}, layers={}",
+        wave,
+        state.arena_radius,
+        num_layers
+    );
+    if is_jello_madness(wave) {
+        log::info!("🟢 JELLO MADNESS WAVE!");
+    }
+
+    let blocks = wave_blocks(wave, state.seed, &state.tuning, state.next_id());
+    state.claim_ids(blocks.len() as u32);
+    state.blocks.extend(blocks);
+    state.wave_total_blocks = state.clearable_blocks();
+}
+
+/// The blocks `generate_wave` will build for the wave it's about to
+/// start, without touching `state`. Same seed, tuning and entity ids, so
+/// the layout shown during the breather is exactly the wave that follows.
+pub fn preview_wave(state: &GameState) -> Vec<super::state::Block> {
+    wave_blocks(state.wave_index, state.seed, &state.tuning, state.next_id())
+}
+
+/// Special wave: Jello Madness! Every 10th wave starting at wave 10
+fn is_jello_madness(wave: u32) -> bool {
+    wave >= 10 && wave.is_multiple_of(10)
+}
+
+/// Ring count and endless level for a wave in an arena of `arena_radius`
+fn wave_layers(wave: u32, arena_radius: f32, tuning: &TuningConfig) -> (u32, u32) {
+    use super::state::{INNER_MARGIN, LAYER_SPACING, MAX_ARENA_RADIUS, WALL_MARGIN};
 
     // Calculate layer radii dynamically based on arena size
     // Layers go from outer (near wall) to inner (near black hole)
     // More space = more layers!
-    let outer_radius = state.arena_radius - WALL_MARGIN; // Start 25px from wall
+    let outer_radius = arena_radius - WALL_MARGIN; // Start 25px from wall
     let inner_radius = INNER_MARGIN; // Stop 120px from center (above paddle)
     let available_space = outer_radius - inner_radius;
 
@@ -1673,19 +2009,37 @@ pub fn generate_wave(state: &mut GameState) {
     let desired_layers = 1 + (wave / 2).min(max_possible_layers);
     let num_layers = desired_layers.min(max_possible_layers).max(1);
 
-    log::info!(
-        "Wave {}: arena={}, space={}, layers={}",
-        wave,
-        state.arena_radius,
-        available_space,
-        num_layers
-    );
+    // Endless: once the arena is done growing, layers that no longer fit
+    // turn into speed/gravity/HP scaling instead
+    let uncapped_layers = 1 + wave / 2;
+    let at_max_arena = arena_radius >= MAX_ARENA_RADIUS;
+    let endless_level = if tuning.endless_scaling && at_max_arena {
+        uncapped_layers.saturating_sub(max_possible_layers)
+    } else {
+        0
+    };
+    (num_layers, endless_level)
+}
 
-    // Special wave: Jello Madness! Every 10th wave starting at wave 10
-    let jello_madness = wave >= 10 && wave.is_multiple_of(10);
-    if jello_madness {
-        log::info!("🟢 JELLO MADNESS WAVE!");
-    }
+/// Lay out a wave's blocks, numbered from `first_id`. Pure, so
+/// `generate_wave` and `preview_wave` always agree.
+fn wave_blocks(
+    wave: u32,
+    seed: u64,
+    tuning: &TuningConfig,
+    first_id: u32,
+) -> Vec<super::state::Block> {
+    use super::arc::ArcSegment;
+    use super::state::{Block, BlockKind, LAYER_SPACING, WALL_MARGIN};
+    use std::f32::consts::PI;
+
+    let arena_radius = arena_radius_for_wave(wave, tuning);
+    let wave_seed = wave_seed(wave, seed);
+    let (num_layers, endless_level) = wave_layers(wave, arena_radius, tuning);
+    let endless = endless_scaling(endless_level);
+    let outer_radius = arena_radius - WALL_MARGIN;
+    let jello_madness = is_jello_madness(wave);
+    let mut blocks = Vec::new();
 
     // Wave-wide caps on special block types (prevent monotony)
     let mut electric_count = 0u32;
@@ -1701,6 +2055,12 @@ pub fn generate_wave(state: &mut GameState) {
     let max_ghost = 4 + num_layers;
     let max_portal = 4 + num_layers;
 
+    // Arc-length mode: every ring uses the outer ring's slot length, so
+    // blocks are about the same physical size at any radius
+    let slot_len = tuning
+        .arc_length_widths
+        .then(|| 2.0 * PI * outer_radius / layer_block_count(0, wave) as f32);
+
     // Generate layer radii from outer to inner
     let mut layer_radii = Vec::with_capacity(num_layers as usize);
     for i in 0..num_layers {
@@ -1712,23 +2072,18 @@ pub fn generate_wave(state: &mut GameState) {
         let layer = layer as u32;
         let layer_seed = wave_seed.wrapping_add(layer * 1000);
 
-        // More blocks in outer layers, fewer in inner
-        let base_blocks = match layer {
-            0 => 12 + wave * 2, // Outer: 12-32 blocks
-            1 => 10 + wave,     // Second: 10-22
-            2 => 8 + wave / 2,  // Third: 8-14
-            _ => 6 + wave / 3,  // Inner: 6-10
+        let num_blocks = match slot_len {
+            Some(len) => ((2.0 * PI * radius / len).round() as usize)
+                .clamp(ARC_LENGTH_MIN_BLOCKS, layer_block_count(0, wave) as usize),
+            None => layer_block_count(layer, wave) as usize,
         };
-        let num_blocks = base_blocks.min(28) as usize;
 
         // Layer style: packed (no gaps) or spaced (gaps)
         let packed = !layer_seed.is_multiple_of(3); // ~67% packed, 33% spaced
 
         // Rotation: occasionally ONE layer rotates (wave 2+)
         // Use a better hash to decorrelate layers
-        let rotation_hash = layer_seed
-            .wrapping_mul(2654435761) // Golden ratio hash
-            .wrapping_add(layer * 7919); // Prime offset per layer
+        let rotation_hash = rng::hash_u32(layer_seed, layer);
         let rotation_roll = rotation_hash % 100;
 
         // ~20% chance per layer rotates, so usually 0-1 spinning rings
@@ -1802,7 +2157,7 @@ pub fn generate_wave(state: &mut GameState) {
 
             // Update counters
             match kind {
-                BlockKind::Invincible => invincible_in_layer += 1,
+                BlockKind::Invincible | BlockKind::Bumper => invincible_in_layer += 1,
                 BlockKind::Electric => electric_count += 1,
                 BlockKind::Crystal => crystal_count += 1,
                 BlockKind::Magnet => magnet_count += 1,
@@ -1811,21 +2166,17 @@ pub fn generate_wave(state: &mut GameState) {
                 _ => {}
             }
 
+            let hp = tuning.blocks.hp(kind, wave);
             let hp = match kind {
-                BlockKind::Armored => 2 + (wave / 5) as u8, // Armored gets tougher
-                BlockKind::Explosive => 1,
-                BlockKind::Invincible => 255, // Doesn't matter, can't be damaged
-                BlockKind::Portal { .. } => 3, // 3 passes before breaking
-                BlockKind::Jello => 2,        // Takes 2 hits, wobbles each time
-                _ => 1,
+                BlockKind::Invincible | BlockKind::Bumper | BlockKind::Portal { .. } => hp,
+                _ => hp.saturating_add(endless.extra_hp),
             };
 
             // Thicker blocks contain powerups! ~10% chance, not on invincible/portal
-            let can_have_powerup = kind != BlockKind::Invincible
-                && !matches!(kind, BlockKind::Portal { .. })
-                && wave > 1;
+            let can_have_powerup =
+                !kind.is_indestructible() && !matches!(kind, BlockKind::Portal { .. }) && wave > 1;
             // Use hash for better distribution (block_seed has bad divisibility patterns)
-            let powerup_roll = block_seed.wrapping_mul(2654435761) % 100;
+            let powerup_roll = rng::hash_u32(block_seed, 0) % 100;
             let has_powerup = can_have_powerup && powerup_roll < 10;
             let thickness = if has_powerup {
                 BLOCK_THICKNESS * 1.5
@@ -1835,13 +2186,13 @@ pub fn generate_wave(state: &mut GameState) {
 
             // Ghost blocks start with random phase for staggered fading
             let ghost_phase = if kind == BlockKind::Ghost {
-                (block_seed % 1000) as f32 / 1000.0 * std::f32::consts::TAU
+                rng::rand_f32(block_seed) * std::f32::consts::TAU
             } else {
                 0.0
             };
 
             let block = Block {
-                id: state.next_entity_id(),
+                id: first_id + blocks.len() as u32,
                 kind,
                 hp,
                 arc: ArcSegment::new(radius, thickness, theta_start, theta_end),
@@ -1850,31 +2201,327 @@ pub fn generate_wave(state: &mut GameState) {
                 visibility: 1.0,
                 ghost_phase,
                 ring_id: layer,
+                pole_flags: Block::POLE_RED | Block::POLE_SILVER,
+                shuffle_remaining: 0.0,
             };
-            state.blocks.push(block);
+            blocks.push(block);
 
             theta += base_arc;
         }
     }
+
+    blocks
 }
 
-/// Determine block type based on wave progression
-/// Caps prevent any one special type from dominating
-#[allow(clippy::too_many_arguments)]
-fn determine_block_kind(
-    wave: u32,
-    layer: u32,
-    index: u32,
-    seed: u32,
-    layer_block_count: usize,
-    invincible_in_layer: u32,
-    electric_capped: bool,
-    crystal_capped: bool,
-    magnet_capped: bool,
-    ghost_capped: bool,
-    portal_capped: bool,
-) -> super::state::BlockKind {
-    use super::state::BlockKind;
+/// Block slots on a ring: more in outer layers, fewer in inner
+fn layer_block_count(layer: u32, wave: u32) -> u32 {
+    let base_blocks = match layer {
+        0 => 12 + wave * 2, // Outer: 12-32 blocks
+        1 => 10 + wave,     // Second: 10-22
+        2 => 8 + wave / 2,  // Third: 8-14
+        _ => 6 + wave / 3,  // Inner: 6-10
+    };
+    base_blocks.min(28)
+}
+
+/// Deterministic "randomness" based on wave number AND game seed.
+/// This gives variety between runs while keeping determinism within a run.
+fn wave_seed(wave: u32, seed: u64) -> u32 {
+    ((wave as u64)
+        .wrapping_mul(rng::GOLDEN as u64)
+        .wrapping_add(seed)) as u32
+}
+
+/// Shuffle waves: every `shuffle_interval_ticks` each ring is given a
+/// seeded angle to travel, then glides there (ease-out) over the next
+/// ticks. Blocks move in place, so collisions always see where they are
+/// mid-glide. A ring moves as a unit so its blocks never overlap, and holds
+/// still for a tick if the step would bring any of its blocks onto a ball.
+/// Blocks can't reach the paddle: every ring sits outside `INNER_MARGIN`.
+fn advance_shuffle(state: &mut GameState, dt: f32) {
+    let interval = state.tuning.shuffle_interval_ticks;
+    if interval == 0 || !state.wave_modifiers.contains(WaveModifiers::SHUFFLE) {
+        return;
+    }
+
+    state.shuffle_ticks += 1;
+    if state.shuffle_ticks.is_multiple_of(interval) {
+        let round_seed =
+            wave_seed(state.wave_index, state.seed).wrapping_add(state.shuffle_ticks / interval);
+        for block in &mut state.blocks {
+            let hash = rng::hash_u32(round_seed, block.ring_id);
+            let shift =
+                SHUFFLE_MIN_SHIFT + rng::rand_f32(hash) * (SHUFFLE_MAX_SHIFT - SHUFFLE_MIN_SHIFT);
+            block.shuffle_remaining = if (hash >> 16) & 1 == 0 { shift } else { -shift };
+        }
+    }
+
+    let step_for = |remaining: f32| {
+        if remaining.abs() <= SHUFFLE_SNAP {
+            remaining
+        } else {
+            remaining * (SHUFFLE_EASE_RATE * dt).min(1.0)
+        }
+    };
+
+    // Rings whose next step would touch a ball (free or riding a portal)
+    let mut held: Vec<u32> = Vec::new();
+    for block in &state.blocks {
+        if block.shuffle_remaining == 0.0 || held.contains(&block.ring_id) {
+            continue;
+        }
+        let mut moved = block.clone();
+        moved.turn(step_for(block.shuffle_remaining));
+        let touches_ball = state.balls.iter().any(|ball| {
+            matches!(ball.state, BallState::Free | BallState::Sliding { .. })
+                && super::sdf::sd_arc(
+                    ball.pos,
+                    moved.arc.theta_start,
+                    moved.arc.theta_end,
+                    moved.arc.radius,
+                    moved.arc.thickness,
+                ) < ball.radius + SHUFFLE_BALL_CLEARANCE
+        });
+        if touches_ball {
+            held.push(block.ring_id);
+        }
+    }
+
+    for block in &mut state.blocks {
+        if block.shuffle_remaining == 0.0 || held.contains(&block.ring_id) {
+            continue;
+        }
+        let step = step_for(block.shuffle_remaining);
+        block.turn(step);
+        block.shuffle_remaining -= step;
+    }
+}
+
+/// Grow the blast rings, damaging each pending block once its ring has
+/// spread past it. Blocks broken this way score their base value and
+/// disintegrate like a direct hit, but don't chain further. Once a ring has
+/// reached all its blocks, the chain's total goes out as one `Score` event.
+fn advance_shockwaves(state: &mut GameState, dt: f32) {
+    if state.shockwaves.is_empty() {
+        return;
+    }
+
+    // (ring index, block id)
+    let mut reached = Vec::new();
+    for (wave_idx, wave) in state.shockwaves.iter_mut().enumerate() {
+        wave.radius += wave.expand_rate * dt;
+        wave.pending.retain(|&id| {
+            // A block broken some other way in the meantime is dropped
+            let Some(block) = state.blocks.iter().find(|b| b.id == id) else {
+                return false;
+            };
+            if (block.arc.center() - wave.center).length() > wave.radius {
+                return true;
+            }
+            reached.push((wave_idx, id));
+            false
+        });
+    }
+
+    if !reached.is_empty() {
+        break_reached_blocks(state, &reached);
+    }
+
+    // Announce each finished chain once
+    for wave in &mut state.shockwaves {
+        if wave.pending.is_empty() && wave.scored > 0 {
+            state.events.push(super::state::GameEvent::Score {
+                amount: wave.scored,
+                pos: wave.center,
+            });
+            wave.scored = 0;
+        }
+    }
+    state
+        .shockwaves
+        .retain(|w| !w.pending.is_empty() || w.radius < SHOCKWAVE_MAX_RADIUS);
+}
+
+/// Damage the blocks the blast rings just reached (`(ring index, block id)`)
+/// and break the ones that run out of HP
+fn break_reached_blocks(state: &mut GameState, reached: &[(usize, u32)]) {
+    for &(_, id) in reached {
+        let Some(victim_idx) = state.blocks.iter().position(|b| b.id == id) else {
+            continue;
+        };
+        let victim = &state.blocks[victim_idx];
+        let v_mid = (victim.arc.theta_start + victim.arc.theta_end) / 2.0;
+        let v_radius = victim.arc.radius;
+
+        // Impact particles AT the victim
+        for i in 0..6 {
+            let hash = rng::hash_u32(state.time_ticks as u32 + id, i);
+            let angle = v_mid + (rng::rand_f32(hash) - 0.5) * 0.8;
+            let pos = Vec2::new(angle.cos() * v_radius, angle.sin() * v_radius);
+            let vel = Vec2::new(angle.cos(), angle.sin()) * (80.0 + (hash / 1000 % 80) as f32);
+            state.particles.push(super::state::Particle {
+                pos,
+                vel,
+                color: 2, // Orange
+                life: 0.5,
+                size: 4.0,
+            });
+        }
+
+        state.blocks[victim_idx].hp = state.blocks[victim_idx].hp.saturating_sub(2);
+        state.blocks[victim_idx].trigger_wobble();
+    }
+
+    // Spawn particles for blocks killed by explosion BEFORE removing them
+    for block in state.blocks.iter() {
+        if block.hp == 0 {
+            let mid_angle = (block.arc.theta_start + block.arc.theta_end) / 2.0;
+            let arc_span = block.arc.theta_end - block.arc.theta_start;
+            let color = block.kind.color_index();
+            // Disintegration burst for explosion victims
+            let particle_count = (25.0 + arc_span * 30.0).min(45.0) as usize;
+            let particle_seed = state.time_ticks as u32 + block.id;
+
+            for i in 0..particle_count {
+                let hash = rng::hash_u32(particle_seed, i as u32);
+                let angle_offset = (rng::rand_f32(hash) - 0.5) * arc_span * 1.2;
+                let radius_offset = (rng::rand_f32(hash / 1000) - 0.5) * block.arc.thickness;
+                let spawn_angle = mid_angle + angle_offset;
+                let spawn_radius = block.arc.radius + radius_offset;
+                let pos = Vec2::new(
+                    spawn_angle.cos() * spawn_radius,
+                    spawn_angle.sin() * spawn_radius,
+                );
+                // Burst in all directions - re-hash for independent random values
+                let [vel_hash, speed_hash, size_hash] = rng::rehash3(hash);
+                let vel_angle = (vel_hash % 10000) as f32 / 10000.0 * std::f32::consts::TAU;
+                let base_speed = 70.0 + (speed_hash % 180) as f32;
+                let vel = Vec2::new(vel_angle.cos(), vel_angle.sin()) * base_speed;
+                let size = 1.5 + (size_hash % 200) as f32 / 100.0;
+
+                state.particles.push(super::state::Particle {
+                    pos,
+                    vel,
+                    color,
+                    life: 0.32, // Quick disintegration
+                    size,
+                });
+            }
+
+            // Score for explosion kills too, credited to the ring that broke it
+            let base_score = state.tuning.blocks.blast_score(block.kind);
+            state.score += base_score;
+            if let Some(&(wave_idx, _)) = reached.iter().find(|&&(_, id)| id == block.id) {
+                state.shockwaves[wave_idx].scored += base_score;
+            }
+        }
+    }
+
+    // Remove dead blocks from explosion
+    state.blocks.retain(|b| b.hp > 0);
+}
+
+/// Reward a ring emptied of clearable blocks, then shake up the next
+/// inner ring that still has some: its blocks lose a little HP (down to
+/// 1 at most) and Jello wobbles
+fn clear_ring(state: &mut GameState, ring_id: u32) {
+    let bonus = state.tuning.ring_clear_bonus;
+    state.score += bonus;
+    state
+        .events
+        .push(super::state::GameEvent::RingClear { ring_id });
+    state.screen_shake = (state.screen_shake + 0.3).min(1.0);
+
+    let damage = state.tuning.ring_cascade_damage;
+    if damage == 0 {
+        return;
+    }
+    let Some(next_ring) = state
+        .clearable_rings()
+        .into_iter()
+        .find(|&ring| ring > ring_id)
+    else {
+        return;
+    };
+    for block in state
+        .blocks
+        .iter_mut()
+        .filter(|b| b.ring_id == next_ring && b.counts_for_clear())
+    {
+        block.hp = block.hp.saturating_sub(damage).max(1);
+        block.trigger_wobble();
+    }
+}
+
+/// Drop roll for a destroyed block. Depends only on the block id and the
+/// wave seed, never on when the block broke, so reloading a save and
+/// replaying a wave can't reroll the pickups it drops. Mixed through
+/// `fmix32` so drops don't repeat every few block ids.
+fn pickup_roll(wave: u32, seed: u64, block_id: u32) -> u32 {
+    rng::fmix32(wave_seed(wave, seed).wrapping_add(rng::hash_u32(block_id, 0)))
+}
+
+/// Chaos modifiers for a wave: occasionally one modifier, rolled from the
+/// wave seed, unless the tuning forces a set. Public so the UI can preview
+/// the next wave during the breather.
+pub fn wave_modifiers_for(wave: u32, seed: u64, tuning: &TuningConfig) -> WaveModifiers {
+    if let Some(forced) = tuning.forced_wave_modifiers {
+        return forced;
+    }
+    // Jello Madness waves are special enough already
+    if wave < CHAOS_START_WAVE || wave.is_multiple_of(10) {
+        return WaveModifiers::NONE;
+    }
+
+    let hash = wave_seed(wave, seed)
+        .wrapping_mul(3266489917)
+        .rotate_left(17);
+    if hash % 100 >= CHAOS_CHANCE_PERCENT {
+        return WaveModifiers::NONE;
+    }
+    let all = WaveModifiers::ALL;
+    all[(hash >> 8) as usize % all.len()].0
+}
+
+/// Inner ring wall layout for a wave (2-4 openings, slow spin either way),
+/// or `None` if this wave has no inner wall
+fn inner_wall_for_wave(
+    wave: u32,
+    wave_seed: u32,
+    tuning: &TuningConfig,
+) -> Option<super::state::InnerWall> {
+    let every = tuning.inner_wall_every;
+    if every == 0
+        || wave < tuning.inner_wall_start_wave
+        || !(wave - tuning.inner_wall_start_wave).is_multiple_of(every)
+    {
+        return None;
+    }
+
+    let hash = wave_seed.wrapping_mul(2246822519).rotate_left(13);
+    let gaps = 2 + hash % 3;
+    let speed = 0.25 + ((hash >> 8) % 100) as f32 / 100.0 * 0.25;
+    let spin = if (hash >> 16) & 1 == 0 { speed } else { -speed };
+    Some(super::state::InnerWall::new(gaps, spin))
+}
+
+/// Determine block type based on wave progression
+/// Caps prevent any one special type from dominating
+#[allow(clippy::too_many_arguments)]
+fn determine_block_kind(
+    wave: u32,
+    layer: u32,
+    index: u32,
+    seed: u32,
+    layer_block_count: usize,
+    invincible_in_layer: u32,
+    electric_capped: bool,
+    crystal_capped: bool,
+    magnet_capped: bool,
+    ghost_capped: bool,
+    portal_capped: bool,
+) -> super::state::BlockKind {
+    use super::state::BlockKind;
 
     // Wave 0-1: all glass (tutorial waves)
     if wave <= 1 {
@@ -1884,13 +2531,16 @@ fn determine_block_kind(
     // Use a simple hash for variety
     let roll = seed % 100;
 
-    // Invincible blocks (wave 5+, very sparse)
-    // Max 2 per layer, and never adjacent (check index spacing)
+    // Invincible blocks (wave 5+, very sparse), half of them bumpers from
+    // BUMPER_START_WAVE. Max 2 per layer, and never adjacent (check index spacing)
     let max_invincible = (layer_block_count / 7).max(1) as u32;
     let can_place_invincible =
         wave >= 5 && invincible_in_layer < max_invincible.min(2) && index.is_multiple_of(4);
 
     if can_place_invincible && roll < 8 {
+        if wave >= BUMPER_START_WAVE && roll >= 4 {
+            return BlockKind::Bumper;
+        }
         return BlockKind::Invincible;
     }
 
@@ -1946,6 +2596,29 @@ fn determine_block_kind(
     BlockKind::Glass
 }
 
+/// Re-attach a ball to the paddle where it touched, ready to serve again
+fn catch_ball(ball: &mut super::state::Ball, paddle: &super::state::Paddle, angle: f32) {
+    let half_arc = paddle.arc_width / 2.0;
+    let offset = crate::normalize_angle(angle - paddle.theta).clamp(-half_arc, half_arc);
+    ball.state = BallState::Attached { offset };
+    ball.vel = Vec2::ZERO;
+    ball.curve = 0.0;
+    ball.update_attached(paddle);
+}
+
+/// Spin (rad/s) a paddle hit gives a ball: the ball rolls against the
+/// paddle surface, so the spin runs opposite to the paddle's rotation
+fn paddle_spin(angular_vel: f32, ball_radius: f32) -> f32 {
+    let surface_speed = angular_vel * PADDLE_RADIUS;
+    (-surface_speed * SPIN_TRANSFER / ball_radius.max(1.0)).clamp(-SPIN_MAX, SPIN_MAX)
+}
+
+/// Curve imparted by a paddle hit at the given paddle angular velocity
+fn paddle_curve(angular_vel: f32) -> f32 {
+    let excess = (angular_vel.abs() - CURVE_MIN_PADDLE_SPEED).max(0.0);
+    (excess * CURVE_PER_PADDLE_SPEED).min(CURVE_MAX) * angular_vel.signum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1974,23 +2647,11 @@ mod tests {
     #[test]
     fn test_tick_pause() {
         use crate::sim::ArcSegment;
-        use crate::sim::state::{Block, BlockKind};
-
-        let mut state = GameState::new(12345);
+        use crate::sim::state::BlockKind;
 
         // Add a block so wave doesn't immediately clear
-        let block_id = state.next_entity_id();
-        state.blocks.push(Block {
-            id: block_id,
-            kind: BlockKind::Glass,
-            hp: 1,
-            arc: ArcSegment::new(200.0, 20.0, 0.0, 0.5),
-            rotation_speed: 0.0,
-            wobble: 0.0,
-            visibility: 1.0,
-            ghost_phase: 0.0,
-            ring_id: 0,
-        });
+        let mut state = GameState::new(12345)
+            .with_block(ArcSegment::new(200.0, 20.0, 0.0, 0.5), BlockKind::Glass);
 
         // Launch the ball first so we're in Playing state
         let launch = TickInput {
@@ -2013,6 +2674,1565 @@ mod tests {
         assert_eq!(state.phase, GamePhase::Playing);
     }
 
+    #[test]
+    fn test_paddle_arc_snapshot_order() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent, Pickup};
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(250.0, 100.0), Vec2::new(0.0, 200.0))
+            .with_block(ArcSegment::new(300.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+
+        // Widened paddle mid-spring, shrinking fast
+        state.paddle.theta = 0.0;
+        state.paddle.arc_width = PADDLE_ARC_WIDTH * 2.0;
+        let start_width = state.paddle.arc_width;
+        state.paddle.arc_width_vel = -40.0;
+
+        // Just inside the collection range at the start of the tick, but
+        // outside it once the spring has shrunk the paddle
+        let angle = start_width / 2.0 + 0.05;
+        state.pickups.push(Pickup {
+            id: 999,
+            kind: PickupKind::Shield,
+            pos: Vec2::new(angle.cos(), angle.sin()) * PADDLE_RADIUS,
+            vel: Vec2::ZERO,
+            ttl_ticks: 1200,
+        });
+
+        tick(&mut state, &TickInput::default(), SIM_DT);
+
+        assert!(
+            state
+                .events
+                .iter()
+                .any(|e| matches!(e, GameEvent::PickupCollect(_)))
+        );
+        // Spring ran after collection: new width applies from the next tick
+        assert!(state.paddle.arc_width < start_width - 0.2);
+        assert!(angle >= state.paddle.arc_width / 2.0 + 0.1);
+    }
+
+    #[test]
+    fn test_screen_shake_decay_ignores_step_size() {
+        let shake_after = |secs: f32, steps: u32| {
+            let mut state = GameState::new(1);
+            state.screen_shake = 1.0;
+            for _ in 0..steps {
+                tick(&mut state, &TickInput::default(), secs / steps as f32);
+            }
+            state.screen_shake
+        };
+
+        // Same fade whether a stretch of time runs as few or many ticks
+        let expected = (-SCREEN_SHAKE_DECAY_RATE * 0.25).exp();
+        for steps in [15, 30, 60] {
+            let shake = shake_after(0.25, steps);
+            assert!(
+                (shake - expected).abs() < 1e-4,
+                "{} steps: {} vs {}",
+                steps,
+                shake,
+                expected
+            );
+        }
+        // And it has always settled after a second
+        for steps in [60, 120, 240] {
+            assert_eq!(shake_after(1.0, steps), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_arc_length_widths_even_out_rings() {
+        // Outer ring's slot length, and the longest block on every ring
+        let ring_lengths = |arc_length_widths: bool| {
+            let mut state = GameState::new(11).without_balls();
+            state.tuning.arc_length_widths = arc_length_widths;
+            state.wave_index = 12;
+            generate_wave(&mut state);
+
+            let rings = state.blocks.iter().map(|b| b.ring_id).max().unwrap() + 1;
+            assert!(rings >= 4, "need several rings, got {}", rings);
+            let outer = state.blocks.iter().find(|b| b.ring_id == 0).unwrap();
+            let slot = std::f32::consts::TAU * outer.arc.radius / layer_block_count(0, 12) as f32;
+            let longest: Vec<f32> = (0..rings)
+                .map(|ring| {
+                    state
+                        .blocks
+                        .iter()
+                        .filter(|b| b.ring_id == ring)
+                        .map(|b| (b.arc.theta_end - b.arc.theta_start) * b.arc.radius)
+                        .fold(0.0, f32::max)
+                })
+                .collect();
+            (slot, longest)
+        };
+
+        // Every ring's blocks fit the outer slot length, at 55-95% fill
+        let (slot, longest) = ring_lengths(true);
+        for len in &longest {
+            assert!(
+                *len > slot * 0.5 && *len < slot,
+                "{:?} vs slot {}",
+                longest,
+                slot
+            );
+        }
+
+        // By default inner rings get far longer blocks
+        let (slot, longest) = ring_lengths(false);
+        assert!(
+            longest.iter().any(|len| *len > slot * 1.5),
+            "{:?} vs slot {}",
+            longest,
+            slot
+        );
+    }
+
+    #[test]
+    fn test_purist_bonus_needs_a_pickup_free_wave() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent, Pickup};
+
+        // One glass block straight above the ball; returns the bonus paid
+        let clear_wave = |take_pickup: bool| {
+            let mut state = GameState::new(1)
+                .without_balls()
+                .with_ball_free(Vec2::new(0.0, 200.0), Vec2::new(0.0, 300.0))
+                .with_block(ArcSegment::new(260.0, 20.0, 1.3, 1.8), BlockKind::Glass)
+                .with_phase(GamePhase::Playing);
+            if take_pickup {
+                let theta = state.paddle.theta;
+                state.pickups.push(Pickup {
+                    id: 999,
+                    kind: PickupKind::Shield,
+                    pos: Vec2::new(theta.cos(), theta.sin()) * PADDLE_RADIUS,
+                    vel: Vec2::ZERO,
+                    ttl_ticks: 1200,
+                });
+            }
+            for _ in 0..120 {
+                tick(&mut state, &TickInput::default(), SIM_DT);
+                if state.phase == GamePhase::Breather {
+                    return state.events.iter().find_map(|e| match e {
+                        GameEvent::PuristBonus { amount } => Some(*amount),
+                        _ => None,
+                    });
+                }
+            }
+            panic!("wave never cleared");
+        };
+
+        assert_eq!(
+            clear_wave(false),
+            Some(TuningConfig::default().purist_bonus)
+        );
+        assert_eq!(clear_wave(true), None);
+    }
+
+    #[test]
+    fn test_heavy_ball_plows_first_block() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        // Heavy ball heading straight out through two armored rings
+        let mut state = GameState::new(3)
+            .without_balls()
+            .with_ball_free(Vec2::new(180.0, 0.0), Vec2::new(300.0, 0.0))
+            .with_block(ArcSegment::new(230.0, 20.0, -0.3, 0.3), BlockKind::Armored)
+            .with_block(ArcSegment::new(320.0, 20.0, -0.3, 0.3), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.tuning.gravity_enabled = false;
+        state.blocks[0].hp = 3;
+        state.blocks[1].hp = 3;
+        state.balls[0].make_heavy();
+        assert!(state.balls[0].radius > BALL_RADIUS);
+
+        for _ in 0..50 {
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        // Straight through the first ring, hit once for double damage...
+        assert_eq!(state.blocks[0].hp, 1);
+        // ...then back off the second like any ball
+        assert_eq!(state.blocks[1].hp, 1);
+        let ball = &state.balls[0];
+        assert!(ball.vel.x < 0.0);
+        assert!(ball.pos.x > 250.0, "{}", ball.pos.x);
+        assert!(!ball.plow_ready);
+        assert_eq!(ball.plowing, None);
+
+        // Gravity bends a heavy ball half as much
+        let mut state = GameState::new(3)
+            .without_balls()
+            .with_ball_free(Vec2::new(200.0, 0.0), Vec2::new(0.0, 300.0))
+            .with_ball_free(Vec2::new(0.0, 200.0), Vec2::new(-300.0, 0.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.balls[1].make_heavy();
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        // Inward speed picked up from the pull
+        let (light, heavy) = (-state.balls[0].vel.x, -state.balls[1].vel.y);
+        assert!(light > 0.0);
+        assert!(
+            (light - heavy * HEAVY_BALL_MASS).abs() < 1e-3,
+            "{light} {heavy}"
+        );
+    }
+
+    #[test]
+    fn test_bumper_kicks_without_breaking() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent};
+
+        // Ball heading straight out at a bumper; returns the state once it
+        // has bounced back
+        let bounce = |chain: u8| {
+            let mut state = GameState::new(3)
+                .without_balls()
+                .with_ball_free(Vec2::new(200.0, 0.0), Vec2::new(300.0, 0.0))
+                .with_block(ArcSegment::new(250.0, 20.0, -0.3, 0.3), BlockKind::Bumper)
+                .with_block(ArcSegment::new(250.0, 20.0, 2.5, 3.0), BlockKind::Armored)
+                .with_phase(GamePhase::Playing);
+            state.tuning.gravity_enabled = false;
+            state.balls[0].bumper_chain = chain;
+            let mut events = Vec::new();
+            for _ in 0..30 {
+                tick(&mut state, &TickInput::default(), SIM_DT);
+                events.extend(state.events.iter().copied());
+            }
+            (state, events)
+        };
+
+        let (state, events) = bounce(0);
+        let ball = &state.balls[0];
+        assert!(ball.vel.x < 0.0);
+        // Faster than it came in, but never past the cap
+        assert!(ball.vel.length() > 300.0 + 1.0, "{}", ball.vel.length());
+        assert!(ball.vel.length() <= BALL_MAX_SPEED + 0.01);
+        assert_eq!(ball.bumper_chain, 1);
+        assert_eq!(state.blocks[0].hp, 255);
+        assert_eq!(state.combo, 0);
+        assert!(events.iter().any(|e| matches!(e, GameEvent::BumperHit(_))));
+        assert!(state.screen_shake > 0.0);
+        assert!(state.particles.iter().any(|p| p.color == 10));
+
+        // A long chain of bumper hits only bounces the ball
+        let (state, _) = bounce(BUMPER_MAX_CHAIN);
+        assert!((state.balls[0].vel.length() - 300.0).abs() < 0.01);
+
+        // Bumpers never hold up a wave clear
+        let only_bumper =
+            GameState::new(3).with_block(ArcSegment::new(250.0, 20.0, 0.0, 0.3), BlockKind::Bumper);
+        assert_eq!(only_bumper.clearable_blocks(), 0);
+
+        // Late waves place some
+        let mut state = GameState::new(11);
+        let found = (BUMPER_START_WAVE..BUMPER_START_WAVE + 20).any(|wave| {
+            state.wave_index = wave;
+            generate_wave(&mut state);
+            state.blocks.iter().any(|b| b.kind == BlockKind::Bumper)
+        });
+        assert!(found);
+    }
+
+    #[test]
+    fn test_ring_clear_bonus_and_cascade() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent};
+
+        // Ring 0: a glass block straight above the ball, plus an invincible
+        // one that doesn't count. Ring 1 is next inward; ring 2 (far armored)
+        // keeps the wave from clearing.
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, 200.0), Vec2::new(0.0, 300.0))
+            .with_block(ArcSegment::new(260.0, 20.0, 1.3, 1.8), BlockKind::Glass)
+            .with_block(
+                ArcSegment::new(260.0, 20.0, 4.0, 4.5),
+                BlockKind::Invincible,
+            )
+            .with_block(ArcSegment::new(150.0, 20.0, 3.0, 3.4), BlockKind::Armored)
+            .with_block(ArcSegment::new(150.0, 20.0, 3.6, 4.0), BlockKind::Glass)
+            .with_block(ArcSegment::new(380.0, 20.0, 5.0, 5.4), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.effects.shield_active = true;
+        for (block, ring_id) in state.blocks.iter_mut().zip([0, 0, 1, 1, 2]) {
+            block.ring_id = ring_id;
+        }
+        state.blocks[4].hp = 200;
+
+        let mut clears = Vec::new();
+        let mut score_at_clear = 0;
+        for _ in 0..60 {
+            let before = state.score;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            for event in &state.events {
+                if let GameEvent::RingClear { ring_id } = event {
+                    clears.push(*ring_id);
+                    score_at_clear = state.score - before;
+                }
+            }
+        }
+
+        assert_eq!(clears, vec![0]);
+        // Glass break plus the ring bonus
+        assert!(score_at_clear >= TuningConfig::default().ring_clear_bonus);
+        // Ring 1 is shaken but nothing breaks; ring 2 is untouched
+        let hps: Vec<u8> = state.blocks.iter().map(|b| b.hp).collect();
+        assert_eq!(hps, vec![255, 1, 1, 200]);
+    }
+
+    #[test]
+    fn test_inner_wall_collision() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, INNER_WALL_RADIUS, InnerWall};
+
+        // Ball heading straight at the center along +x, rotation puts either
+        // solid wall (openings at +-90 degrees) or an opening in its path
+        let run = |rotation: f32| {
+            let mut state = GameState::new(1)
+                .without_balls()
+                .with_ball_free(Vec2::new(150.0, 0.0), Vec2::new(-300.0, 0.0))
+                .with_block(ArcSegment::new(300.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+                .with_phase(GamePhase::Playing);
+            state.blocks[0].hp = 200;
+            state.inner_wall = Some(InnerWall {
+                rotation,
+                ..InnerWall::new(2, 0.0)
+            });
+
+            let mut closest = f32::MAX;
+            for _ in 0..60 {
+                state.effects.shield_active = true;
+                tick(&mut state, &TickInput::default(), SIM_DT);
+                if let Some(ball) = state.balls.first() {
+                    closest = closest.min(ball.pos.length());
+                }
+            }
+            (closest, state)
+        };
+
+        let (closest, state) = run(std::f32::consts::FRAC_PI_2);
+        assert!(closest > INNER_WALL_RADIUS, "ball got through solid wall");
+        assert!(state.balls[0].vel.x > 0.0, "ball should bounce back out");
+
+        let (closest, _) = run(0.0);
+        assert!(closest < INNER_WALL_RADIUS, "ball should pass the opening");
+    }
+
+    #[test]
+    fn test_fast_ball_scorch_marks() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, MAX_SCORCH_PARTICLES};
+
+        let run = |speed: f32| {
+            let mut state = GameState::new(1)
+                .without_balls()
+                .with_ball_free(Vec2::new(200.0, 0.0), Vec2::new(0.0, speed))
+                .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+                .with_phase(GamePhase::Playing);
+            state.blocks[0].hp = 200;
+            for _ in 0..600 {
+                state.effects.shield_active = true;
+                if let Some(ball) = state.balls.first_mut() {
+                    // Hold the speed so the drag/gravity don't matter
+                    ball.vel = ball.vel.normalize_or_zero() * speed;
+                }
+                tick(&mut state, &TickInput::default(), SIM_DT);
+                let scorch = state.particles.iter().filter(|p| p.is_scorch()).count();
+                assert!(scorch <= MAX_SCORCH_PARTICLES);
+            }
+            state.particles.iter().filter(|p| p.is_scorch()).count()
+        };
+
+        assert_eq!(run(BALL_MIN_SPEED), 0);
+        assert!(run(BALL_MAX_SPEED) > 0);
+    }
+
+    #[test]
+    fn test_multiball_trail_starts_at_spawn() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, Pickup};
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(200.0, 0.0), Vec2::new(0.0, 300.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.blocks[0].hp = 200;
+        state.tuning.trail_length = 12;
+        for _ in 0..30 {
+            state.effects.shield_active = true;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        assert_eq!(state.balls[0].trail.len(), 12);
+
+        // Drop a MultiBall pickup right on the paddle so it's collected this tick
+        let id = state.next_entity_id();
+        state.pickups.push(Pickup {
+            id,
+            kind: PickupKind::MultiBall,
+            pos: Vec2::new(state.paddle.theta.cos(), state.paddle.theta.sin()) * PADDLE_RADIUS,
+            vel: Vec2::ZERO,
+            ttl_ticks: 600,
+        });
+        tick(&mut state, &TickInput::default(), SIM_DT);
+
+        assert_eq!(state.balls.len(), 3);
+        let spawn_pos = state.balls[0].pos;
+        for ball in &state.balls[1..] {
+            assert_eq!(ball.trail.len(), 1);
+            assert_eq!(ball.trail[0].pos, spawn_pos);
+            assert_eq!(ball.pos, spawn_pos);
+        }
+    }
+
+    #[test]
+    fn test_pickup_drops_are_not_periodic() {
+        let drops: Vec<u32> = (0..2400)
+            .filter(|&id| pickup_roll(3, 42, id).is_multiple_of(12))
+            .collect();
+
+        // About one block in twelve drops...
+        assert!((140..=260).contains(&drops.len()), "{} drops", drops.len());
+
+        // ...and not only ids on a fixed stride: drops land in every
+        // residue class, so no period of block ids is drop-free
+        for period in 2..=12 {
+            for residue in 0..period {
+                assert!(
+                    drops.iter().any(|id| id % period == residue),
+                    "no drops at ids {residue} mod {period}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pickup_drop_ignores_break_timing() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        // Pick a block id whose roll drops a pickup, then break that block
+        // early in one run and later in another
+        let seed = 5;
+        let block_id = (1..1000)
+            .find(|&id| pickup_roll(0, seed, id).is_multiple_of(12))
+            .unwrap();
+        let run = |start_x: f32| {
+            let mut state = GameState::new(seed)
+                .without_balls()
+                .with_ball_free(Vec2::new(start_x, 0.0), Vec2::new(400.0, 0.0))
+                .with_block(ArcSegment::new(300.0, 20.0, -0.2, 0.2), BlockKind::Glass)
+                .with_phase(GamePhase::Playing);
+            state.blocks[0].id = block_id;
+            let mut broke_at = None;
+            for _ in 0..120 {
+                state.effects.shield_active = true;
+                tick(&mut state, &TickInput::default(), SIM_DT);
+                if broke_at.is_none() && state.blocks.is_empty() {
+                    broke_at = Some(state.time_ticks);
+                }
+            }
+            (
+                broke_at.unwrap(),
+                state.pickups.iter().map(|p| p.kind).collect::<Vec<_>>(),
+            )
+        };
+
+        let (early, early_drops) = run(220.0);
+        let (late, late_drops) = run(120.0);
+        assert_ne!(early, late);
+        assert_eq!(early_drops.len(), 1);
+        assert_eq!(early_drops, late_drops);
+    }
+
+    #[test]
+    fn test_outer_wall_bounce_sends_one_wall_hit() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent};
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, 300.0), Vec2::new(0.0, 300.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 3.5, 3.8), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.tuning.gravity_enabled = false;
+
+        let mut wall_hits = 0;
+        for _ in 0..120 {
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            wall_hits += state
+                .events
+                .iter()
+                .filter(|e| matches!(e, GameEvent::WallHit(_)))
+                .count();
+        }
+        assert_eq!(wall_hits, 1);
+        assert!(state.balls[0].vel.y < 0.0);
+
+        // Ticks that return early don't repeat the previous tick's events
+        state.balls[0].pos = Vec2::new(0.0, state.arena_radius - 9.0);
+        state.balls[0].vel = Vec2::new(0.0, 300.0);
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert!(
+            state
+                .events
+                .iter()
+                .any(|e| matches!(e, GameEvent::WallHit(_)))
+        );
+        let pause = TickInput {
+            pause: true,
+            ..TickInput::default()
+        };
+        tick(&mut state, &pause, SIM_DT);
+        assert_eq!(state.phase, GamePhase::Paused);
+        assert!(state.events.is_empty());
+    }
+
+    #[test]
+    fn test_combo_milestones_fire_once() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent};
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(200.0, 0.0), Vec2::new(0.0, 150.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.tuning.gravity_enabled = false;
+        let mut run = |combo: u32| {
+            state.combo = combo;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            state
+                .events
+                .iter()
+                .filter_map(|e| match e {
+                    GameEvent::ComboMilestone(m) => Some(*m),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(run(4).is_empty());
+        assert_eq!(run(5), vec![5]);
+        // Staying above the threshold doesn't repeat it
+        assert!(run(6).is_empty());
+        // Jumping past several announces each new one
+        assert_eq!(run(21), vec![10, 20]);
+        // Dropping back re-arms the milestones above the new combo
+        assert!(run(7).is_empty());
+        assert_eq!(run(10), vec![10]);
+        assert!(run(0).is_empty());
+        assert_eq!(run(5), vec![5]);
+    }
+
+    #[test]
+    fn test_combo_break_event() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent};
+
+        // Lose a ball headed straight into the black hole with the given combo
+        let run = |combo: u32| {
+            let mut state = GameState::new(1)
+                .without_balls()
+                .with_ball_free(Vec2::new(120.0, 0.0), Vec2::new(-300.0, 0.0))
+                .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+                .with_phase(GamePhase::Playing);
+            state.blocks[0].hp = 200;
+            state.combo = combo;
+            let mut events = Vec::new();
+            // Long enough for the death spiral to finish
+            for _ in 0..240 {
+                tick(&mut state, &TickInput::default(), SIM_DT);
+                events.extend(state.events.iter().copied());
+            }
+            assert!(events.contains(&GameEvent::BallLost));
+            assert_eq!(state.combo, 0);
+            events
+                .into_iter()
+                .filter(|e| matches!(e, GameEvent::ComboBreak { .. }))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            run(COMBO_BREAK_MIN + 3),
+            vec![GameEvent::ComboBreak {
+                previous: COMBO_BREAK_MIN + 3
+            }]
+        );
+        assert!(run(COMBO_BREAK_MIN - 1).is_empty());
+    }
+
+    #[test]
+    fn test_mercy_suppresses_loss_after_respawn() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent};
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(120.0, 0.0), Vec2::new(-300.0, 0.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.blocks[0].hp = 200;
+        while state.phase == GamePhase::Playing {
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        assert_eq!(state.phase, GamePhase::Serve);
+        assert_eq!(state.lives, 2);
+        assert_eq!(state.mercy_ticks, MERCY_TICKS);
+
+        // Launch and dive straight at the black hole: bounced, not lost
+        let launch = TickInput {
+            launch: true,
+            ..Default::default()
+        };
+        tick(&mut state, &launch, SIM_DT);
+        let dive = |state: &mut GameState| {
+            state.balls[0].pos = Vec2::new(120.0, 0.0);
+            state.balls[0].vel = Vec2::new(-300.0, 0.0);
+            let mut lost = false;
+            for _ in 0..240 {
+                tick(state, &TickInput::default(), SIM_DT);
+                lost |= state.events.contains(&GameEvent::BallLost);
+            }
+            lost
+        };
+        assert!(!dive(&mut state));
+        assert_eq!(state.lives, 2);
+        assert!(!state.effects.shield_active);
+
+        // Once the window has run out the black hole takes the ball again
+        state.mercy_ticks = 0;
+        assert!(dive(&mut state));
+        assert_eq!(state.lives, 1);
+    }
+
+    #[test]
+    fn test_shield_save_sends_event_once() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent};
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(120.0, 0.0), Vec2::new(-300.0, 0.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.blocks[0].hp = 200;
+        state.effects.shield_active = true;
+
+        let mut saves = 0;
+        for _ in 0..120 {
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            saves += state
+                .events
+                .iter()
+                .filter(|e| **e == GameEvent::ShieldSave)
+                .count();
+        }
+        assert_eq!(saves, 1);
+        assert!(!state.effects.shield_active);
+        assert_eq!(state.lives, 3);
+    }
+
+    #[test]
+    fn test_sticky_paddle_holds_one_ball() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        // Two balls reach the paddle on the same tick
+        let mut state = GameState::new(1).with_phase(GamePhase::Playing);
+        let theta = state.paddle.theta;
+        let dir = |offset: f32| Vec2::new((theta + offset).cos(), (theta + offset).sin());
+        state = state
+            .without_balls()
+            .with_ball_free(dir(-0.05) * 150.0, -dir(-0.05) * 300.0)
+            .with_ball_free(dir(0.05) * 150.0, -dir(0.05) * 300.0)
+            .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored);
+        state.blocks[0].hp = 200;
+        state.effects.sticky_active = true;
+
+        for _ in 0..60 {
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        let attached = |state: &GameState| {
+            state
+                .balls
+                .iter()
+                .filter(|b| matches!(b.state, BallState::Attached { .. }))
+                .count()
+        };
+        assert_eq!(attached(&state), 1);
+        assert!(!state.effects.sticky_active);
+        // Mid-wave: no new serve, and the other ball bounced as usual
+        assert_eq!(state.phase, GamePhase::Playing);
+        assert!(state.free_balls().all(|b| b.vel.dot(b.pos) > 0.0));
+
+        // The held ball rides the paddle...
+        let target = TickInput {
+            target_theta: Some(theta + 1.0),
+            ..Default::default()
+        };
+        for _ in 0..30 {
+            tick(&mut state, &target, SIM_DT);
+        }
+        let held = state
+            .balls
+            .iter()
+            .find(|b| matches!(b.state, BallState::Attached { .. }))
+            .unwrap();
+        let held_angle = held.pos.y.atan2(held.pos.x);
+        assert!(crate::normalize_angle(held_angle - state.paddle.theta).abs() < 0.1);
+
+        // ...until the next launch
+        let launch = TickInput {
+            launch: true,
+            ..Default::default()
+        };
+        tick(&mut state, &launch, SIM_DT);
+        assert_eq!(attached(&state), 0);
+        assert!(
+            state
+                .events
+                .contains(&super::super::state::GameEvent::Launch)
+        );
+    }
+
+    #[test]
+    fn test_catch_reattaches_ball_once_per_life() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        let mut state = GameState::new(1).with_phase(GamePhase::Playing);
+        let dir = Vec2::new(state.paddle.theta.cos(), state.paddle.theta.sin());
+        state = state
+            .without_balls()
+            .with_ball_free(dir * 150.0, -dir * 300.0)
+            .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored);
+        state.blocks[0].hp = 200;
+
+        let catch = TickInput {
+            catch: true,
+            ..Default::default()
+        };
+        tick(&mut state, &catch, SIM_DT);
+        assert!(state.catch_armed);
+        for _ in 0..60 {
+            if state.phase == GamePhase::Serve {
+                break;
+            }
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        assert_eq!(state.phase, GamePhase::Serve);
+        assert!(matches!(state.balls[0].state, BallState::Attached { .. }));
+        assert!(!state.catch_available && !state.catch_armed);
+
+        // Relaunch; the catch is spent, so the next hit bounces
+        let launch = TickInput {
+            launch: true,
+            ..Default::default()
+        };
+        tick(&mut state, &launch, SIM_DT);
+        assert_eq!(state.phase, GamePhase::Playing);
+        tick(&mut state, &catch, SIM_DT);
+        assert!(!state.catch_armed);
+
+        // Losing a life gives the catch back
+        state.balls.clear();
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert!(state.catch_available);
+    }
+
+    #[test]
+    fn test_multiball_respects_ball_cap() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, Pickup};
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.blocks[0].hp = 200;
+        for i in 0..MAX_BALLS - 1 {
+            let theta = i as f32 * 0.7;
+            state.add_ball_free(
+                Vec2::new(theta.cos(), theta.sin()) * 250.0,
+                Vec2::new(-theta.sin(), theta.cos()) * 200.0,
+            );
+        }
+
+        // Each pickup tries to add two balls; only one fits, then none
+        for _ in 0..2 {
+            state.effects.shield_active = true;
+            let id = state.next_entity_id();
+            state.pickups.push(Pickup {
+                id,
+                kind: PickupKind::MultiBall,
+                pos: Vec2::new(state.paddle.theta.cos(), state.paddle.theta.sin()) * PADDLE_RADIUS,
+                vel: Vec2::ZERO,
+                ttl_ticks: 600,
+            });
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            assert!(state.pickups.is_empty());
+            assert_eq!(state.balls.len(), MAX_BALLS);
+        }
+    }
+
+    #[test]
+    fn test_preview_matches_next_wave() {
+        for (seed, wave) in [(1, 1), (7, 4), (42, 10), (3, 25)] {
+            let mut state = GameState::new(seed).without_balls();
+            state.blocks.clear();
+            state.wave_index = wave;
+            state.phase = GamePhase::Breather;
+            state.breather_ticks = 1;
+
+            let preview = preview_wave(&state);
+            assert!(!preview.is_empty());
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            assert_eq!(state.phase, GamePhase::Serve);
+            assert_eq!(state.blocks, preview, "seed {seed} wave {wave}");
+        }
+    }
+
+    #[test]
+    fn test_wave_modifiers_roll() {
+        let tuning = TuningConfig::default();
+        let rolled: Vec<_> = (0..300)
+            .map(|wave| wave_modifiers_for(wave, 42, &tuning))
+            .collect();
+        let again: Vec<_> = (0..300)
+            .map(|wave| wave_modifiers_for(wave, 42, &tuning))
+            .collect();
+        assert_eq!(rolled, again);
+
+        for (wave, modifiers) in rolled.iter().enumerate() {
+            if (wave as u32) < CHAOS_START_WAVE || wave % 10 == 0 {
+                assert!(modifiers.is_empty(), "wave {} rolled {:?}", wave, modifiers);
+            }
+        }
+        for (modifier, name) in WaveModifiers::ALL {
+            assert!(rolled.contains(&modifier), "{} never rolled", name);
+        }
+        let chaos = rolled.iter().filter(|m| !m.is_empty()).count();
+        assert!(chaos < rolled.len() / 2, "too many chaos waves: {}", chaos);
+
+        // Forcing overrides the roll, even on tutorial waves
+        let forced = TuningConfig {
+            forced_wave_modifiers: Some(WaveModifiers::DOUBLE_SPEED),
+            ..TuningConfig::default()
+        };
+        assert_eq!(
+            wave_modifiers_for(0, 42, &forced),
+            WaveModifiers::DOUBLE_SPEED
+        );
+    }
+
+    #[test]
+    fn test_shuffle_glides_rings_and_waits_for_balls() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        let mut state = GameState::new(8)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, -200.0), Vec2::new(0.0, 200.0))
+            .with_block(ArcSegment::new(300.0, 20.0, 0.0, 0.4), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.blocks[0].hp = 200;
+        state.wave_modifiers = WaveModifiers::SHUFFLE;
+        state.tuning.shuffle_interval_ticks = 10;
+        let park_ball = |state: &mut GameState, pos: Vec2| {
+            state.balls[0].pos = pos;
+            state.balls[0].vel = Vec2::new(0.0, 200.0);
+            state.effects.shield_active = true;
+        };
+
+        for _ in 0..10 {
+            park_ball(&mut state, Vec2::new(0.0, -200.0));
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        let shift = state.blocks[0].shuffle_remaining;
+        state.tuning.shuffle_interval_ticks = 10_000; // No second shuffle
+        assert!((SHUFFLE_MIN_SHIFT..=SHUFFLE_MAX_SHIFT).contains(&shift.abs()));
+
+        // A ball just past the leading tip holds the ring still
+        let start = state.blocks[0].arc.theta_start;
+        let tip: f32 = if shift > 0.0 { 0.4 + 0.06 } else { -0.06 };
+        for _ in 0..30 {
+            park_ball(&mut state, Vec2::new(tip.cos(), tip.sin()) * 300.0);
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        assert_eq!(state.blocks[0].arc.theta_start, start);
+
+        // Once it's gone the ring glides (no jumps) to the seeded angle
+        let mut last = start;
+        for _ in 0..9 {
+            park_ball(&mut state, Vec2::new(0.0, -200.0));
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            let theta = state.blocks[0].arc.theta_start;
+            let step = crate::normalize_angle(theta - last).abs();
+            assert!(step > 0.0 && step <= SHUFFLE_MAX_SHIFT * SHUFFLE_EASE_RATE * SIM_DT + 1e-4);
+            last = theta;
+        }
+        for _ in 0..300 {
+            park_ball(&mut state, Vec2::new(0.0, -200.0));
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        assert_eq!(state.blocks[0].shuffle_remaining, 0.0);
+        let moved = crate::normalize_angle(state.blocks[0].arc.theta_start - start);
+        assert!((moved - shift).abs() < 1e-3, "moved {} of {}", moved, shift);
+    }
+
+    #[test]
+    fn test_chaos_modifier_physics() {
+        // One tick of a ball moving tangentially at 150 from the center
+        let run = |forced: WaveModifiers| {
+            let mut state = GameState::new(5);
+            state.tuning.forced_wave_modifiers = Some(forced);
+            generate_wave(&mut state);
+            assert_eq!(state.wave_modifiers, forced);
+            let mut state = state
+                .without_balls()
+                .with_ball_free(Vec2::new(150.0, 0.0), Vec2::new(0.0, 200.0))
+                .with_phase(GamePhase::Playing);
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            state.balls[0].vel
+        };
+
+        let normal = run(WaveModifiers::NONE);
+        assert!(normal.x < 0.0, "gravity should pull in: {:?}", normal);
+        assert!(normal.length() < BALL_MIN_SPEED * 2.0);
+
+        let reversed = run(WaveModifiers::REVERSED_GRAVITY);
+        assert!(reversed.x > 0.0, "gravity should push out: {:?}", reversed);
+
+        let fast = run(WaveModifiers::DOUBLE_SPEED);
+        assert!(fast.length() >= BALL_MIN_SPEED * 2.0 - 0.01);
+    }
+
+    #[test]
+    fn test_ball_travels_straight_without_gravity() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        let mut state = GameState::new(5)
+            .without_balls()
+            .with_ball_free(Vec2::new(150.0, 0.0), Vec2::new(0.0, 200.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 3.0, 3.3), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.tuning.gravity_enabled = false;
+        for _ in 0..60 {
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        let ball = &state.balls[0];
+        assert!((ball.pos.x - 150.0).abs() < 0.01, "drifted: {:?}", ball.pos);
+        assert!(
+            (ball.pos.y - 100.0).abs() < 0.5,
+            "wrong distance: {:?}",
+            ball.pos
+        );
+        assert!(ball.vel.abs_diff_eq(Vec2::new(0.0, 200.0), 0.01));
+    }
+
+    #[test]
+    fn test_arc_tip_reflects_predictably() {
+        use crate::sim::ArcSegment;
+        use crate::sim::sdf::sd_arc;
+        use crate::sim::state::BlockKind;
+
+        // Fire straight up at the start tip of an arc lying along +x, hitting
+        // the rounded cap dead on and grazing it from either side
+        for offset in [-9.0, -4.0, 0.0, 4.0, 9.0] {
+            let mut state = GameState::new(1)
+                .without_balls()
+                .with_ball_free(Vec2::new(300.0 + offset, -60.0), Vec2::new(0.0, 300.0))
+                .with_block(ArcSegment::new(300.0, 20.0, 0.0, 0.5), BlockKind::Armored)
+                .with_phase(GamePhase::Playing);
+            state.blocks[0].hp = 200;
+            state.tuning.gravity_enabled = false;
+            for _ in 0..40 {
+                state.effects.shield_active = true;
+                tick(&mut state, &TickInput::default(), SIM_DT);
+                let ball = &state.balls[0];
+                let dist = sd_arc(ball.pos, 0.0, 0.5, 300.0, 20.0);
+                assert!(dist > ball.radius, "ball inside tip at offset {}", offset);
+            }
+
+            let vel = state.balls[0].vel;
+            assert!(vel.y < 0.0, "no bounce at offset {}: {:?}", offset, vel);
+            // Deflected to the side of the tip it struck
+            if offset != 0.0 {
+                assert_eq!(vel.x.signum(), f32::signum(offset), "offset {}", offset);
+            }
+        }
+    }
+
+    #[test]
+    fn test_freeze_halts_block_rotation() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, Pickup};
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(150.0, 0.0), Vec2::new(0.0, 300.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.blocks[0].rotation_speed = 0.5;
+
+        let id = state.next_entity_id();
+        state.pickups.push(Pickup {
+            id,
+            kind: PickupKind::Freeze,
+            pos: Vec2::new(state.paddle.theta.cos(), state.paddle.theta.sin()) * PADDLE_RADIUS,
+            vel: Vec2::ZERO,
+            ttl_ticks: 600,
+        });
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert!(state.effects.freeze_ticks > 0);
+
+        let frozen_start = state.blocks[0].arc.theta_start;
+        for _ in 0..60 {
+            state.effects.shield_active = true;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        assert_eq!(state.blocks[0].arc.theta_start, frozen_start);
+
+        // Rotation resumes once the effect wears off
+        state.effects.freeze_ticks = 0;
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert_ne!(state.blocks[0].arc.theta_start, frozen_start);
+    }
+
+    #[test]
+    fn test_time_warp_slows_world_not_paddle() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        let run = |warp_ticks| {
+            let mut state = GameState::new(1)
+                .without_balls()
+                .with_ball_free(Vec2::new(150.0, 0.0), Vec2::new(0.0, 300.0))
+                .with_block(ArcSegment::new(380.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+                .with_phase(GamePhase::Playing);
+            state.tuning.gravity_enabled = false;
+            state.paddle.theta = 0.0;
+            state.effects.time_warp_ticks = warp_ticks;
+            let input = TickInput {
+                paddle_spin: 1.0,
+                ..TickInput::default()
+            };
+            for _ in 0..30 {
+                tick(&mut state, &input, SIM_DT);
+            }
+            state
+        };
+
+        let normal = run(0);
+        let warped = run(600);
+        let travel = |s: &GameState| s.balls[0].pos.y;
+        let ratio = travel(&warped) / travel(&normal);
+        assert!((ratio - TIME_WARP_SCALE).abs() < 1e-3, "{ratio}");
+        assert!((warped.paddle.theta - normal.paddle.theta).abs() < 1e-5);
+
+        // The effect runs out in real ticks
+        assert_eq!(warped.effects.time_warp_ticks, 570);
+        assert_eq!(run(20).effects.time_scale(), 1.0);
+    }
+
+    #[test]
+    fn test_explosion_damage_rides_shockwave() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent};
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(200.0, 0.0), Vec2::new(400.0, 0.0))
+            .with_block(
+                ArcSegment::new(300.0, 20.0, -0.1, 0.1),
+                BlockKind::Explosive,
+            )
+            .with_block(ArcSegment::new(300.0, 20.0, 0.15, 0.45), BlockKind::Glass)
+            .with_block(ArcSegment::new(380.0, 20.0, 3.0, 3.3), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.blocks[2].hp = 200;
+        let glass_id = state.blocks[1].id;
+
+        // Run until the explosive goes off
+        let mut ticks = 0;
+        while state.blocks.len() == 3 {
+            state.effects.shield_active = true;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            ticks += 1;
+            assert!(ticks < 120, "explosive never broke");
+        }
+        assert_eq!(state.shockwaves.len(), 1);
+        assert_eq!(state.shockwaves[0].pending, vec![glass_id]);
+        assert!(state.blocks.iter().any(|b| b.id == glass_id));
+        let score_at_blast = state.score;
+
+        // The neighbor survives until the ring reaches it, then breaks as before
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert!(state.blocks.iter().any(|b| b.id == glass_id));
+        let mut chain_scores = Vec::new();
+        for _ in 0..60 {
+            state.effects.shield_active = true;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            chain_scores.extend(state.events.iter().filter_map(|e| match e {
+                GameEvent::Score { amount, .. } => Some(*amount),
+                _ => None,
+            }));
+        }
+        assert!(state.blocks.iter().all(|b| b.id != glass_id));
+        assert_eq!(state.score, score_at_blast + 10);
+        assert!(state.shockwaves.is_empty());
+        assert_eq!(chain_scores, vec![10]);
+    }
+
+    #[test]
+    fn test_wind_pushes_tangentially() {
+        let mut state = GameState::new(5);
+        state.tuning.forced_wave_modifiers = Some(WaveModifiers::WIND);
+        generate_wave(&mut state);
+        assert_eq!(state.wind_strength, WIND_ACCEL);
+
+        // Ball moving straight out along +x; full counter-clockwise gust
+        let mut state = state
+            .without_balls()
+            .with_ball_free(Vec2::new(150.0, 0.0), Vec2::new(200.0, 0.0))
+            .with_phase(GamePhase::Playing);
+        state.wind_phase = std::f32::consts::FRAC_PI_2;
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert!(state.balls[0].vel.y > 0.0);
+        assert!(state.wind_phase > std::f32::consts::FRAC_PI_2);
+
+        // Half a cycle later the wind blows the other way
+        state.wind_phase += std::f32::consts::PI;
+        state.balls[0].vel = Vec2::new(200.0, 0.0);
+        state.balls[0].pos = Vec2::new(150.0, 0.0);
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert!(state.balls[0].vel.y < 0.0);
+
+        // Waves without the modifier are calm
+        let calm = GameState::new(5);
+        assert_eq!(calm.wind(), 0.0);
+    }
+
+    #[test]
+    fn test_idle_ai_defends_inbound_ball() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        // A near ball flying out below, a far one diving in from above
+        let mut state = GameState::new(3)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, -90.0), Vec2::new(0.0, -150.0))
+            .with_ball_free(Vec2::new(0.0, 300.0), Vec2::new(0.0, -250.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 3.0, 3.3), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.paddle.theta = 0.0;
+        let idle = TickInput {
+            idle_mode: true,
+            ..TickInput::default()
+        };
+
+        // The paddle swings up toward the diving ball, not down to the near one
+        for _ in 0..20 {
+            tick(&mut state, &idle, SIM_DT);
+        }
+        assert!(state.paddle.theta > 0.5, "theta {}", state.paddle.theta);
+    }
+
+    #[test]
+    fn test_released_keys_stop_paddle() {
+        let mut state = GameState::new(3);
+        let spin = TickInput {
+            paddle_spin: 1.0,
+            ..TickInput::default()
+        };
+        tick(&mut state, &spin, SIM_DT);
+        assert!(state.paddle.angular_vel > 0.0);
+
+        // With no input the paddle is still, so it adds no english to a hit
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert_eq!(state.paddle.angular_vel, 0.0);
+    }
+
+    #[test]
+    fn test_keyboard_spin() {
+        let mut state = GameState::new(3);
+        state.paddle.theta = 0.0;
+        let spin = |paddle_spin| TickInput {
+            paddle_spin,
+            ..TickInput::default()
+        };
+
+        // Held key rotates at the tuned speed, counter-clockwise for +1
+        for _ in 0..60 {
+            tick(&mut state, &spin(1.0), SIM_DT);
+        }
+        let expected = state.tuning.keyboard_speed * SIM_DT * 60.0;
+        assert!((state.paddle.theta - expected).abs() < 1e-4);
+        for _ in 0..60 {
+            tick(&mut state, &spin(-1.0), SIM_DT);
+        }
+        assert!(state.paddle.theta.abs() < 1e-4);
+
+        // Both keys held cancel out
+        tick(&mut state, &spin(0.0), SIM_DT);
+        assert!(state.paddle.theta.abs() < 1e-4);
+
+        // A pointer target wins over the keys
+        let both = TickInput {
+            target_theta: Some(-0.5),
+            paddle_spin: 1.0,
+            ..TickInput::default()
+        };
+        tick(&mut state, &both, SIM_DT);
+        assert!(state.paddle.theta < 0.0);
+    }
+
+    #[test]
+    fn test_degenerate_inputs_stay_finite() {
+        // Zero dt and a NaN pointer angle must not poison the state
+        // (assert_finite panics inside tick in debug builds)
+        let mut state = GameState::new(3);
+        let nan_input = TickInput {
+            target_theta: Some(f32::NAN),
+            ..TickInput::default()
+        };
+        tick(&mut state, &nan_input, SIM_DT);
+        tick(&mut state, &TickInput::default(), 0.0);
+        let launch = TickInput {
+            target_theta: Some(1.0),
+            launch: true,
+            ..TickInput::default()
+        };
+        tick(&mut state, &launch, 0.0);
+        for _ in 0..240 {
+            tick(&mut state, &nan_input, SIM_DT);
+        }
+        assert!(state.paddle.theta.is_finite());
+        assert!(state.paddle.angular_vel.is_finite());
+
+        // Ball dead-center with zero velocity (every direction degenerate)
+        let mut state = GameState::new(3)
+            .without_balls()
+            .with_ball_free(Vec2::ZERO, Vec2::ZERO)
+            .with_phase(GamePhase::Playing);
+        for _ in 0..10 {
+            state.effects.shield_active = true;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+    }
+
+    #[test]
+    fn test_widen_spring_stays_within_cap() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(250.0, 100.0), Vec2::new(0.0, 200.0))
+            .with_block(ArcSegment::new(300.0, 20.0, 2.0, 2.5), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        // Keep the wave from clearing
+        state.blocks[0].hp = 200;
+
+        // Max stacks, kicked hard outward so the spring overshoots
+        state.effects.widen_stacks = 4;
+        state.effects.widen_ticks = 600;
+        state.paddle.arc_width_vel = 20.0;
+
+        let mut max_seen: f32 = 0.0;
+        for _ in 0..240 {
+            state.effects.shield_active = true;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            max_seen = max_seen.max(state.paddle.arc_width);
+            assert!(state.paddle.arc_width <= PADDLE_ARC_WIDTH * 3.0);
+        }
+        assert_eq!(max_seen, PADDLE_ARC_WIDTH * 3.0);
+
+        // Stacks expire: settles back to base without dipping below it
+        state.effects.widen_stacks = 0;
+        state.effects.widen_ticks = 0;
+        for _ in 0..240 {
+            state.effects.shield_active = true;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            assert!(state.paddle.arc_width >= PADDLE_ARC_WIDTH);
+        }
+        assert_eq!(state.phase, GamePhase::Playing);
+        assert!((state.paddle.arc_width - PADDLE_ARC_WIDTH).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_custom_breather_duration() {
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_ball_free(Vec2::new(250.0, 100.0), Vec2::new(0.0, 200.0))
+            .with_phase(GamePhase::Playing);
+        state.tuning.breather_ticks = 10;
+
+        // No clearable blocks: the wave clears on the first tick
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert_eq!(state.phase, GamePhase::Breather);
+        assert_eq!(state.breather_ticks, 10);
+
+        for _ in 0..9 {
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            assert_eq!(state.phase, GamePhase::Breather);
+        }
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert_eq!(state.phase, GamePhase::Serve);
+    }
+
+    #[test]
+    fn test_arena_growth_boundaries() {
+        use super::super::state::{
+            ARENA_GROWTH_PER_WAVE, ARENA_GROWTH_START_WAVE, BASE_ARENA_RADIUS, MAX_ARENA_RADIUS,
+        };
+        use crate::tuning::ArenaGrowth;
+
+        let start = ARENA_GROWTH_START_WAVE;
+        let span = ((MAX_ARENA_RADIUS - BASE_ARENA_RADIUS) / ARENA_GROWTH_PER_WAVE) as u32;
+        let at_max = start + span;
+
+        for growth in [
+            ArenaGrowth::Linear,
+            ArenaGrowth::EaseOut,
+            ArenaGrowth::Stepped { waves: 4 },
+        ] {
+            let tuning = TuningConfig {
+                arena_growth: growth,
+                ..TuningConfig::default()
+            };
+            let radius = |wave| arena_radius_for_wave(wave, &tuning);
+            assert_eq!(radius(start - 1), BASE_ARENA_RADIUS, "{:?}", growth);
+            assert_eq!(radius(start), BASE_ARENA_RADIUS, "{:?}", growth);
+            assert!(radius(at_max - 1) < MAX_ARENA_RADIUS, "{:?}", growth);
+            assert_eq!(radius(at_max), MAX_ARENA_RADIUS, "{:?}", growth);
+            assert_eq!(radius(at_max + 50), MAX_ARENA_RADIUS, "{:?}", growth);
+
+            let mut last = 0.0;
+            for wave in 0..at_max + 5 {
+                assert!(radius(wave) >= last, "{:?} shrank at wave {}", growth, wave);
+                last = radius(wave);
+            }
+        }
+
+        // Linear keeps the fixed per-wave step
+        let linear = TuningConfig::default();
+        assert_eq!(
+            arena_radius_for_wave(start + 1, &linear),
+            BASE_ARENA_RADIUS + ARENA_GROWTH_PER_WAVE
+        );
+    }
+
+    #[test]
+    fn test_gravity_profiles() {
+        use crate::tuning::FalloffKind;
+
+        // The default keeps the original inverse falloff: 200 / max(d, 50), capped at 4
+        let classic = GravityProfile::default();
+        assert_eq!(GravityProfile::preset("classic"), Some(classic));
+        for dist in [10.0, 50.0, 120.0, 200.0, 400.0] {
+            assert_eq!(
+                classic.multiplier(dist),
+                (200.0 / f32::max(dist, 50.0)).min(4.0)
+            );
+        }
+
+        for (name, profile) in GravityProfile::PRESETS {
+            let at_ref = profile.multiplier(profile.falloff_reference);
+            assert!((at_ref - 1.0).abs() < 1e-6, "{}", name);
+            // Never weaker closer in, never past the cap
+            let mut last = 0.0;
+            for dist in (0..60).rev().map(|i| i as f32 * 10.0) {
+                let m = profile.multiplier(dist);
+                assert!(
+                    m >= last && m <= profile.max_multiplier,
+                    "{} at {}",
+                    name,
+                    dist
+                );
+                last = m;
+            }
+        }
+
+        let square = GravityProfile::preset("inverse_square").unwrap();
+        assert_eq!(square.falloff, FalloffKind::InverseSquare);
+        assert!(square.multiplier(400.0) < classic.multiplier(400.0));
+        let linear = GravityProfile::preset("linear").unwrap();
+        assert_eq!(linear.multiplier(linear.falloff_reference * 2.0), 0.0);
+        assert_eq!(GravityProfile::preset("nope"), None);
+
+        // A weaker profile changes what tick does
+        let ball = |profile| {
+            let mut state = GameState::new(1)
+                .without_balls()
+                .with_ball_free(Vec2::new(300.0, 0.0), Vec2::ZERO)
+                .with_block(
+                    crate::sim::ArcSegment::new(350.0, 20.0, 2.0, 2.5),
+                    crate::sim::state::BlockKind::Armored,
+                )
+                .with_phase(GamePhase::Playing);
+            state.tuning.gravity = profile;
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            state.balls[0].vel.x
+        };
+        assert!(ball(square) > ball(classic));
+    }
+
+    #[test]
+    fn test_paddle_sweep_curves_ball() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::{BlockKind, GameEvent};
+
+        // Ball falling straight onto the paddle (at the bottom); returns how far
+        // the ball's heading turned in the half second after the hit, and its curve
+        fn heading_turn_after_hit(sweep: bool) -> (f32, f32) {
+            let mut state = GameState::new(7)
+                .without_balls()
+                .with_block(ArcSegment::new(300.0, 20.0, 1.0, 1.5), BlockKind::Glass)
+                .with_ball_free(Vec2::new(0.0, -110.0), Vec2::new(0.0, 200.0))
+                .with_phase(GamePhase::Playing);
+            if sweep {
+                // Start behind the ball so the sweep carries the paddle under it
+                state.paddle.theta -= 0.4;
+            }
+
+            let mut hit = None;
+            for i in 0..240 {
+                let input = TickInput {
+                    target_theta: (sweep && i >= 16).then_some(state.paddle.theta + 1.0),
+                    ..Default::default()
+                };
+                tick(&mut state, &input, SIM_DT);
+                if state
+                    .events
+                    .iter()
+                    .any(|e| matches!(e, GameEvent::PaddleHit(_)))
+                {
+                    let ball = &state.balls[0];
+                    hit = Some((ball.vel.to_angle(), ball.curve));
+                    break;
+                }
+            }
+            let (start, curve) = hit.expect("ball should hit the paddle");
+
+            // Paddle stays still afterwards so only the curve differs
+            for _ in 0..60 {
+                tick(&mut state, &TickInput::default(), SIM_DT);
+            }
+            let end = state.balls[0].vel.to_angle();
+            (crate::normalize_angle(end - start), curve)
+        }
+
+        let (still_turn, still_curve) = heading_turn_after_hit(false);
+        let (sweep_turn, sweep_curve) = heading_turn_after_hit(true);
+
+        assert_eq!(still_curve, 0.0);
+        assert!(sweep_curve > 0.5, "sweep curve {}", sweep_curve);
+        assert!(
+            sweep_turn.abs() > still_turn.abs() + 0.1,
+            "sweep turn {} vs still turn {}",
+            sweep_turn,
+            still_turn
+        );
+    }
+
+    #[test]
+    fn test_paddle_spin_is_bounded_and_fades() {
+        use crate::sim::ArcSegment;
+        use crate::sim::state::BlockKind;
+
+        // Spin runs against the paddle's rotation and is capped
+        assert!(paddle_spin(2.0, BALL_RADIUS) < 0.0);
+        assert!(paddle_spin(-2.0, BALL_RADIUS) > 0.0);
+        assert_eq!(paddle_spin(1000.0, BALL_RADIUS), -SPIN_MAX);
+        assert_eq!(paddle_spin(0.0, BALL_RADIUS), 0.0);
+
+        let mut state = GameState::new(7)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, 250.0), Vec2::new(0.0, 200.0))
+            .with_block(ArcSegment::new(380.0, 20.0, 3.0, 3.3), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        state.blocks[0].hp = 200;
+        state.effects.shield_active = true;
+        state.balls[0].spin = SPIN_MAX;
+        let vel = state.balls[0].vel;
+
+        tick(&mut state, &TickInput::default(), SIM_DT);
+        assert!(state.balls[0].spin_angle > 0.0);
+        for _ in 0..1200 {
+            tick(&mut state, &TickInput::default(), SIM_DT);
+        }
+        assert_eq!(state.balls[0].spin, 0.0);
+
+        // Purely cosmetic: the flight is the same without it
+        let mut plain = GameState::new(7)
+            .without_balls()
+            .with_ball_free(Vec2::new(0.0, 250.0), vel)
+            .with_block(ArcSegment::new(380.0, 20.0, 3.0, 3.3), BlockKind::Armored)
+            .with_phase(GamePhase::Playing);
+        plain.blocks[0].hp = 200;
+        plain.effects.shield_active = true;
+        for _ in 0..1201 {
+            tick(&mut plain, &TickInput::default(), SIM_DT);
+        }
+        assert_eq!(plain.state_hash(), state.state_hash());
+    }
+
+    #[test]
+    fn test_endless_scaling_keeps_escalating() {
+        use crate::sim::state::BlockKind;
+
+        let wave_at = |wave: u32, endless: bool| {
+            let mut state = GameState::new(4242);
+            state.tuning.endless_scaling = endless;
+            state.wave_index = wave;
+            generate_wave(&mut state);
+            let breakable: Vec<u32> = state
+                .blocks
+                .iter()
+                .filter(|b| {
+                    !b.kind.is_indestructible() && !matches!(b.kind, BlockKind::Portal { .. })
+                })
+                .map(|b| b.hp as u32)
+                .collect();
+            let mean_hp = breakable.iter().sum::<u32>() as f32 / breakable.len() as f32;
+            (state.endless_level, mean_hp)
+        };
+
+        // Early waves still grow the arena and add layers
+        assert_eq!(wave_at(10, true).0, 0);
+
+        let (level_30, hp_30) = wave_at(30, true);
+        let (level_50, hp_50) = wave_at(50, true);
+        assert!(level_30 > 0);
+        assert!(level_50 > level_30);
+
+        let scale_30 = endless_scaling(level_30);
+        let scale_50 = endless_scaling(level_50);
+        assert!(
+            scale_50.speed >= scale_30.speed + 0.2,
+            "{:?} vs {:?}",
+            scale_50,
+            scale_30
+        );
+        assert!(
+            scale_50.gravity >= scale_30.gravity + 0.25,
+            "{:?} vs {:?}",
+            scale_50,
+            scale_30
+        );
+        assert!(hp_50 >= hp_30 + 2.0, "mean hp {} vs {}", hp_50, hp_30);
+
+        // Turning the option off flatlines at the cap again
+        assert_eq!(wave_at(50, false).0, 0);
+    }
+
     #[test]
     fn test_determinism() {
         // Two states with same seed should produce identical results
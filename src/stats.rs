@@ -0,0 +1,183 @@
+//! Lifetime statistics
+//!
+//! Accumulated across runs from simulation events, persisted to LocalStorage.
+
+use serde::{Deserialize, Serialize};
+
+use crate::consts::SIM_DT;
+use crate::sim::{GameEvent, GamePhase, GameState};
+
+/// Lifetime play statistics
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct Stats {
+    /// Runs finished (reached game over)
+    pub games_played: u32,
+    /// Blocks destroyed
+    pub blocks_destroyed: u64,
+    /// Balls lost to the black hole
+    pub balls_lost: u64,
+    /// Pickups collected
+    pub pickups_collected: u64,
+    /// Waves cleared
+    pub waves_cleared: u64,
+    /// Longest combo reached in any run
+    pub longest_combo: u32,
+    /// Best wave reached (1-indexed)
+    pub best_wave: u32,
+    /// Simulation ticks spent in active play
+    pub play_ticks: u64,
+}
+
+impl Stats {
+    /// LocalStorage key (used only in wasm32)
+    #[allow(dead_code)]
+    const STORAGE_KEY: &'static str = "roto_pong_stats";
+
+    /// Create empty stats
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accumulate one simulation tick (call after every `tick`)
+    pub fn record_tick(&mut self, state: &GameState) {
+        if state.phase == GamePhase::Playing {
+            self.play_ticks += 1;
+        }
+        self.longest_combo = self.longest_combo.max(state.combo);
+        self.best_wave = self.best_wave.max(state.wave_index + 1);
+
+        for event in &state.events {
+            match event {
+                GameEvent::BlockBreak(..) => self.blocks_destroyed += 1,
+                GameEvent::BallLost => self.balls_lost += 1,
+                GameEvent::PickupCollect(_) => self.pickups_collected += 1,
+                GameEvent::WaveClear => self.waves_cleared += 1,
+                GameEvent::GameOver => self.games_played += 1,
+                _ => {}
+            }
+        }
+    }
+
+    /// Total active play time in seconds
+    pub fn playtime_secs(&self) -> f64 {
+        self.play_ticks as f64 * SIM_DT as f64
+    }
+
+    /// Total play time formatted as "1h 02m" / "3m 05s"
+    pub fn format_playtime(&self) -> String {
+        let total = self.playtime_secs() as u64;
+        let (hours, mins, secs) = (total / 3600, (total / 60) % 60, total % 60);
+        if hours > 0 {
+            format!("{}h {:02}m", hours, mins)
+        } else {
+            format!("{}m {:02}s", mins, secs)
+        }
+    }
+
+    /// Clear all statistics
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Load stats from LocalStorage (WASM only)
+    #[cfg(target_arch = "wasm32")]
+    pub fn load() -> Self {
+        let storage = web_sys::window()
+            .and_then(|w| w.local_storage().ok())
+            .flatten();
+
+        if let Some(storage) = storage {
+            if let Ok(Some(json)) = storage.get_item(Self::STORAGE_KEY) {
+                if let Ok(stats) = serde_json::from_str::<Stats>(&json) {
+                    log::info!("Loaded lifetime stats");
+                    return stats;
+                }
+            }
+        }
+
+        Self::new()
+    }
+
+    /// Save stats to LocalStorage (WASM only)
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self) {
+        let storage = web_sys::window()
+            .and_then(|w| w.local_storage().ok())
+            .flatten();
+
+        if let Some(storage) = storage {
+            if let Ok(json) = serde_json::to_string(self) {
+                let _ = storage.set_item(Self::STORAGE_KEY, &json);
+            }
+        }
+    }
+
+    /// Native stubs
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load() -> Self {
+        Self::new()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self) {
+        // No-op for native
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::BlockKind;
+    use glam::Vec2;
+
+    #[test]
+    fn test_record_tick_counts_events() {
+        let mut state = GameState::new(1);
+        state.phase = GamePhase::Playing;
+        state.combo = 7;
+        state.events = vec![
+            GameEvent::BlockBreak(BlockKind::Glass, Vec2::ZERO),
+            GameEvent::BlockBreak(BlockKind::Armored, Vec2::ZERO),
+            GameEvent::BallLost,
+            GameEvent::PaddleHit(Vec2::ZERO),
+        ];
+
+        let mut stats = Stats::new();
+        stats.record_tick(&state);
+        state.combo = 0;
+        state.events.clear();
+        stats.record_tick(&state);
+
+        assert_eq!(stats.blocks_destroyed, 2);
+        assert_eq!(stats.balls_lost, 1);
+        assert_eq!(stats.longest_combo, 7);
+        assert_eq!(stats.play_ticks, 2);
+
+        stats.reset();
+        assert_eq!(stats, Stats::default());
+    }
+
+    #[test]
+    fn test_events_count_once_after_game_over() {
+        use crate::sim::{ArcSegment, TickInput, tick};
+
+        // Last life, last ball already gone: the next tick ends the run
+        let mut state = GameState::new(1)
+            .without_balls()
+            .with_block(ArcSegment::new(300.0, 20.0, 0.0, 0.5), BlockKind::Glass)
+            .with_phase(GamePhase::Playing);
+        state.lives = 1;
+
+        // Ticks after game over return early; they must not hand the final
+        // tick's events to the stats again
+        let mut stats = Stats::new();
+        for _ in 0..60 {
+            tick(&mut state, &TickInput::default(), SIM_DT);
+            stats.record_tick(&state);
+        }
+        assert_eq!(state.phase, GamePhase::GameOver);
+        assert_eq!(stats.games_played, 1);
+        assert_eq!(stats.balls_lost, 1);
+    }
+}
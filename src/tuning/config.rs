@@ -0,0 +1,478 @@
+//! Runtime balance configuration
+//!
+//! Pacing and balance values that difficulty modes and tests adjust per run.
+//! Stored on `GameState` so a saved run keeps the balance it was started
+//! with. `TuningConfig::TABLE` lists every value as JSON; a table that
+//! leaves fields out gets the defaults for them.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::consts::{
+    BALL_MAX_SPEED, BALL_MIN_SPEED, BALL_START_SPEED, BLACK_HOLE_GRAVITY, PADDLE_BOOST,
+};
+use crate::sim::state::{BREATHER_DURATION_TICKS, BlockKind, TRAIL_LENGTH, WaveModifiers};
+
+/// Shape of the arena growth between `ARENA_GROWTH_START_WAVE` and
+/// `MAX_ARENA_RADIUS`. All curves start and end at the same radius; they
+/// only differ in how the growth is spread across the waves in between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ArenaGrowth {
+    /// Fixed `ARENA_GROWTH_PER_WAVE` every wave
+    #[default]
+    Linear,
+    /// Fast early growth that slows down approaching the max radius
+    EaseOut,
+    /// Grow in jumps every `waves` waves
+    Stepped { waves: u32 },
+}
+
+impl ArenaGrowth {
+    /// Fraction (0..=1) of the total growth reached after `growth_waves`
+    /// waves, where `span_waves` is the number of waves linear growth
+    /// takes to reach the max radius
+    pub fn progress(&self, growth_waves: u32, span_waves: f32) -> f32 {
+        let t = |waves: u32| (waves as f32 / span_waves).clamp(0.0, 1.0);
+        match *self {
+            ArenaGrowth::Linear => t(growth_waves),
+            ArenaGrowth::EaseOut => {
+                let inv = 1.0 - t(growth_waves);
+                1.0 - inv * inv
+            }
+            // The last step is cut short so every curve hits the max together
+            ArenaGrowth::Stepped { .. } if growth_waves as f32 >= span_waves => t(growth_waves),
+            ArenaGrowth::Stepped { waves } => {
+                let waves = waves.max(1);
+                t(growth_waves / waves * waves)
+            }
+        }
+    }
+}
+
+/// How the black hole's pull falls off with distance `d` from the center.
+/// Every kind gives a multiplier of 1 at the profile's reference distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FalloffKind {
+    /// `reference / d`
+    #[default]
+    Inverse,
+    /// `(reference / d)²`: gentle far out, sharp near the hole
+    InverseSquare,
+    /// `2 - d / reference`: falls off evenly, no pull past twice the
+    /// reference distance
+    Linear,
+}
+
+/// Black hole pull: base acceleration and its falloff with distance
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GravityProfile {
+    /// Pull at the reference distance (pixels/s²)
+    pub base_accel: f32,
+    pub falloff: FalloffKind,
+    /// Distance where the multiplier is 1 (pixels)
+    pub falloff_reference: f32,
+    /// Distances closer than this count as this far (pixels)
+    pub min_distance_clamp: f32,
+    /// Cap on the distance multiplier
+    pub max_multiplier: f32,
+}
+
+impl GravityProfile {
+    /// Named profiles to experiment with. `classic` is the default.
+    pub const PRESETS: [(&'static str, GravityProfile); 3] = [
+        (
+            "classic",
+            GravityProfile {
+                base_accel: BLACK_HOLE_GRAVITY,
+                falloff: FalloffKind::Inverse,
+                falloff_reference: 200.0,
+                min_distance_clamp: 50.0,
+                max_multiplier: 4.0,
+            },
+        ),
+        (
+            "inverse_square",
+            GravityProfile {
+                base_accel: BLACK_HOLE_GRAVITY,
+                falloff: FalloffKind::InverseSquare,
+                falloff_reference: 200.0,
+                min_distance_clamp: 80.0,
+                max_multiplier: 6.0,
+            },
+        ),
+        (
+            "linear",
+            GravityProfile {
+                base_accel: BLACK_HOLE_GRAVITY,
+                falloff: FalloffKind::Linear,
+                falloff_reference: 250.0,
+                min_distance_clamp: 50.0,
+                max_multiplier: 2.0,
+            },
+        ),
+    ];
+
+    /// Profile from `PRESETS` by name
+    pub fn preset(name: &str) -> Option<Self> {
+        Self::PRESETS
+            .iter()
+            .find(|(preset, _)| *preset == name)
+            .map(|&(_, profile)| profile)
+    }
+
+    /// Pull multiplier at distance `dist` from the center
+    pub fn multiplier(&self, dist: f32) -> f32 {
+        let dist = dist.max(self.min_distance_clamp);
+        let multiplier = match self.falloff {
+            FalloffKind::Inverse => self.falloff_reference / dist,
+            FalloffKind::InverseSquare => (self.falloff_reference / dist).powi(2),
+            FalloffKind::Linear => (2.0 - dist / self.falloff_reference).max(0.0),
+        };
+        multiplier.min(self.max_multiplier)
+    }
+}
+
+impl Default for GravityProfile {
+    fn default() -> Self {
+        Self::PRESETS[0].1
+    }
+}
+
+/// How long the timed pickups last (ticks)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PickupDurations {
+    pub slow_ticks: u32,
+    pub piercing_ticks: u32,
+    /// Per widen stack; stacks run out one at a time
+    pub widen_ticks: u32,
+    pub freeze_ticks: u32,
+    /// Counted in real ticks, so the slowed world doesn't stretch it
+    pub time_warp_ticks: u32,
+}
+
+impl Default for PickupDurations {
+    fn default() -> Self {
+        Self {
+            slow_ticks: 600,
+            piercing_ticks: 480,
+            widen_ticks: 720,
+            freeze_ticks: 360,
+            time_warp_ticks: 480,
+        }
+    }
+}
+
+/// Block HP at generation and points for breaking each kind
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BlockTable {
+    /// Armored HP on wave 0
+    pub armored_hp: u8,
+    /// Waves per extra point of Armored HP (0 = never tougher)
+    pub armored_hp_waves: u32,
+    /// Passes through a portal before it breaks
+    pub portal_hp: u8,
+    pub jello_hp: u8,
+    pub glass_score: u64,
+    pub armored_score: u64,
+    pub explosive_score: u64,
+    pub jello_score: u64,
+    /// Every other breakable kind
+    pub other_score: u64,
+}
+
+impl BlockTable {
+    /// HP of a freshly generated block of `kind` on `wave`, before endless
+    /// scaling
+    pub fn hp(&self, kind: BlockKind, wave: u32) -> u8 {
+        match kind {
+            BlockKind::Armored => {
+                let extra = wave.checked_div(self.armored_hp_waves).unwrap_or(0);
+                self.armored_hp
+                    .saturating_add(extra.min(u8::MAX as u32) as u8)
+            }
+            BlockKind::Invincible | BlockKind::Bumper => 255, // Can't be damaged anyway
+            BlockKind::Portal { .. } => self.portal_hp,
+            BlockKind::Jello => self.jello_hp,
+            _ => 1,
+        }
+    }
+
+    /// Points for breaking a block of `kind`, before the combo multiplier
+    pub fn score(&self, kind: BlockKind) -> u64 {
+        match kind {
+            BlockKind::Glass => self.glass_score,
+            BlockKind::Armored => self.armored_score,
+            BlockKind::Explosive => self.explosive_score,
+            BlockKind::Jello => self.jello_score,
+            BlockKind::Invincible | BlockKind::Bumper => 0, // Never break
+            _ => self.other_score,
+        }
+    }
+
+    /// Points for a block of `kind` broken by an explosion. A chained
+    /// Explosive scores like an ordinary block, not a direct hit.
+    pub fn blast_score(&self, kind: BlockKind) -> u64 {
+        match kind {
+            BlockKind::Explosive => self.other_score,
+            _ => self.score(kind),
+        }
+    }
+}
+
+impl Default for BlockTable {
+    fn default() -> Self {
+        Self {
+            armored_hp: 2,
+            armored_hp_waves: 5,
+            portal_hp: 3,
+            jello_hp: 2,
+            glass_score: 10,
+            armored_score: 25,
+            explosive_score: 50,
+            jello_score: 20,
+            other_score: 15,
+        }
+    }
+}
+
+/// Run difficulty, picked on the main menu before New Game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Difficulty {
+    /// More lives, slower balls and a gentler pull
+    Casual,
+    #[default]
+    Normal,
+    /// Fewer lives, faster balls that ramp up quicker
+    Hard,
+}
+
+/// Tuning tables for the non-default difficulties. They list only what
+/// differs from `TuningConfig::default()`.
+const CASUAL_TUNING: &str = include_str!("casual.json");
+const HARD_TUNING: &str = include_str!("hard.json");
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Casual, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Difficulty::Casual => "Casual",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// Parse a difficulty from a string
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "casual" | "easy" => Some(Difficulty::Casual),
+            "normal" => Some(Difficulty::Normal),
+            "hard" => Some(Difficulty::Hard),
+            _ => None,
+        }
+    }
+
+    /// Tuning a new run on this difficulty starts with
+    pub fn tuning(&self) -> TuningConfig {
+        let mut tuning = match self {
+            Difficulty::Casual => CASUAL_TUNING.parse().expect("casual tuning table parses"),
+            Difficulty::Normal => TuningConfig::default(),
+            Difficulty::Hard => HARD_TUNING.parse().expect("hard tuning table parses"),
+        };
+        tuning.difficulty = *self;
+        tuning
+    }
+}
+
+/// Per-run pacing and balance parameters read by `tick`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TuningConfig {
+    /// Difficulty preset this tuning was built from
+    pub difficulty: Difficulty,
+    /// Lives a run starts with
+    pub starting_lives: u8,
+    /// Pause between waves (ticks)
+    pub breather_ticks: u32,
+    /// Paddle max angular speed (rad/s)
+    pub paddle_max_speed: f32,
+    /// Paddle speed while a rotate key is held (rad/s)
+    pub keyboard_speed: f32,
+    /// Portal slide speed (rad/s)
+    pub portal_slide_speed: f32,
+    /// Ticks after a paddle hit before the paddle can hit the same ball again
+    pub paddle_cooldown_ticks: u32,
+    /// How the arena radius grows from wave to wave
+    pub arena_growth: ArenaGrowth,
+    /// First wave that can have an inner ring wall
+    pub inner_wall_start_wave: u32,
+    /// Inner ring wall appears every this many waves from the start wave (0 = never)
+    pub inner_wall_every: u32,
+    /// Trail points kept per ball (ticks of history)
+    pub trail_length: usize,
+    /// Chaos modifiers applied to every wave instead of the seeded roll
+    pub forced_wave_modifiers: Option<WaveModifiers>,
+    /// Black hole pulls balls inward. Off for relaxed runs, which keep the
+    /// loss radius but don't go on the leaderboard.
+    pub gravity_enabled: bool,
+    /// Strength and distance falloff of the black hole's pull
+    pub gravity: GravityProfile,
+    /// Past the layer cap, keep raising ball speed, gravity and block HP
+    /// each wave instead of repeating the capped wave
+    pub endless_scaling: bool,
+    /// Playing ticks between shuffles on Shuffle waves (0 = never shuffle)
+    pub shuffle_interval_ticks: u32,
+    /// Score for clearing a wave without collecting a pickup (0 = off)
+    pub purist_bonus: u64,
+    /// Size blocks by arc length: every ring's slots are as long as the
+    /// outer ring's, so inner rings get fewer, wider-angle blocks
+    pub arc_length_widths: bool,
+    /// Score for clearing every block on one ring (0 = off)
+    pub ring_clear_bonus: u64,
+    /// HP a ring clear knocks off the next inner ring's blocks. Never
+    /// breaks a block (0 = off)
+    pub ring_cascade_damage: u8,
+    /// Ball speed at launch (pixels/s)
+    pub ball_start_speed: f32,
+    /// Slowest a free ball may go (pixels/s)
+    pub ball_min_speed: f32,
+    /// Fastest a free ball may go, before wave and endless scaling (pixels/s)
+    pub ball_max_speed: f32,
+    /// Ball speed multiplier on each paddle hit
+    pub paddle_boost: f32,
+    /// Score multiplier gained per combo hit after the first
+    pub combo_step: f32,
+    /// Cap on the combo score multiplier
+    pub combo_multiplier_cap: f32,
+    /// How long the timed pickups last
+    pub pickups: PickupDurations,
+    /// Block HP and score values
+    pub blocks: BlockTable,
+}
+
+impl Default for TuningConfig {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::Normal,
+            starting_lives: 3,
+            breather_ticks: BREATHER_DURATION_TICKS,
+            paddle_max_speed: 9.6,
+            keyboard_speed: 6.0,
+            portal_slide_speed: 0.75,
+            paddle_cooldown_ticks: 8,
+            arena_growth: ArenaGrowth::Linear,
+            inner_wall_start_wave: 12,
+            inner_wall_every: 3,
+            trail_length: TRAIL_LENGTH,
+            forced_wave_modifiers: None,
+            gravity_enabled: true,
+            gravity: GravityProfile::default(),
+            endless_scaling: true,
+            shuffle_interval_ticks: 1200,
+            purist_bonus: 500,
+            arc_length_widths: false,
+            ring_clear_bonus: 200,
+            ring_cascade_damage: 1,
+            ball_start_speed: BALL_START_SPEED,
+            ball_min_speed: BALL_MIN_SPEED,
+            ball_max_speed: BALL_MAX_SPEED,
+            paddle_boost: PADDLE_BOOST,
+            combo_step: 0.1,
+            combo_multiplier_cap: 3.0,
+            pickups: PickupDurations::default(),
+            blocks: BlockTable::default(),
+        }
+    }
+}
+
+impl TuningConfig {
+    /// The default tuning as a JSON table, a starting point for variants
+    pub const TABLE: &'static str = include_str!("tuning.json");
+
+    /// Score multiplier at `combo` (1.1x at combo 2 with the default step)
+    pub fn combo_multiplier(&self, combo: u32) -> f32 {
+        if combo > 1 {
+            (1.0 + (combo - 1) as f32 * self.combo_step).min(self.combo_multiplier_cap)
+        } else {
+            1.0
+        }
+    }
+}
+
+impl FromStr for TuningConfig {
+    type Err = serde_json::Error;
+
+    /// Parse a JSON tuning table; missing fields keep their defaults
+    fn from_str(table: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tuning_table() {
+        let table: TuningConfig = TuningConfig::TABLE.parse().unwrap();
+        assert_eq!(table, TuningConfig::default());
+
+        // A variant only lists what it changes
+        let easy: TuningConfig = r#"{"ball_max_speed": 320.0, "pickups": {"slow_ticks": 900}}"#
+            .parse()
+            .unwrap();
+        assert_eq!(easy.ball_max_speed, 320.0);
+        assert_eq!(easy.pickups.slow_ticks, 900);
+        assert_eq!(easy.pickups.freeze_ticks, 360);
+        assert_eq!(easy.blocks, BlockTable::default());
+
+        assert!(
+            "{\"ball_max_speed\": \"fast\"}"
+                .parse::<TuningConfig>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_block_table_and_combo() {
+        let tuning = TuningConfig::default();
+        assert_eq!(tuning.blocks.hp(BlockKind::Armored, 0), 2);
+        assert_eq!(tuning.blocks.hp(BlockKind::Armored, 12), 4);
+        assert_eq!(tuning.blocks.hp(BlockKind::Glass, 12), 1);
+        assert_eq!(tuning.blocks.score(BlockKind::Bumper), 0);
+        assert_eq!(tuning.blocks.score(BlockKind::Explosive), 50);
+        assert_eq!(tuning.blocks.blast_score(BlockKind::Explosive), 15);
+        assert_eq!(tuning.blocks.blast_score(BlockKind::Glass), 10);
+
+        assert_eq!(tuning.combo_multiplier(0), 1.0);
+        assert!((tuning.combo_multiplier(2) - 1.1).abs() < 1e-6);
+        assert_eq!(tuning.combo_multiplier(100), 3.0);
+    }
+
+    #[test]
+    fn test_difficulty_tuning() {
+        let [casual, normal, hard] = Difficulty::ALL.map(|d| d.tuning());
+        assert_eq!(normal, TuningConfig::default());
+        assert_eq!(casual.difficulty, Difficulty::Casual);
+        assert_eq!(hard.difficulty, Difficulty::Hard);
+
+        assert!(casual.starting_lives > normal.starting_lives);
+        let state = crate::sim::GameState::new(1).with_tuning(casual.clone());
+        assert_eq!(state.lives, casual.starting_lives);
+        assert!(hard.starting_lives < normal.starting_lives);
+        assert!(casual.ball_max_speed < normal.ball_max_speed);
+        assert!(hard.ball_max_speed > normal.ball_max_speed);
+        assert!(casual.gravity.base_accel < hard.gravity.base_accel);
+        // Tables only override; everything else stays at the default
+        assert_eq!(casual.gravity.falloff, normal.gravity.falloff);
+        assert_eq!(hard.breather_ticks, normal.breather_ticks);
+
+        for difficulty in Difficulty::ALL {
+            assert_eq!(Difficulty::parse(difficulty.as_str()), Some(difficulty));
+        }
+    }
+}
@@ -1,8 +1,12 @@
 //! Data-driven game tuning
 //!
-//! All gameplay-affecting constants loaded from assets/tuning.ron
-//! Changes update tuning_hash, invalidating old saves/replays
+//! `TuningConfig` is the balance table `tick` reads from the game state;
+//! `tuning.json` lists its defaults. `params` holds the gameplay constants
+//! that haven't moved into the table yet.
 
-// TODO: Implement tuning system
-// pub mod loader;
-// pub mod params;
+pub mod config;
+pub mod params;
+
+pub use config::{
+    ArenaGrowth, BlockTable, Difficulty, FalloffKind, GravityProfile, PickupDurations, TuningConfig,
+};
@@ -0,0 +1,147 @@
+//! Gameplay tuning parameters
+//!
+//! Gameplay-affecting constants grouped by feature, until they move into
+//! the data-driven tuning file.
+
+// === Curve balls ===
+
+/// Paddle angular speed (rad/s) below which a hit imparts no curve
+pub const CURVE_MIN_PADDLE_SPEED: f32 = 3.0;
+/// Curve gained per rad/s of paddle speed above the threshold
+pub const CURVE_PER_PADDLE_SPEED: f32 = 0.15;
+/// Maximum curve magnitude (keeps curved balls catchable)
+pub const CURVE_MAX: f32 = 1.0;
+/// Sideways acceleration at full curve (pixels/s²)
+pub const CURVE_ACCEL: f32 = 180.0;
+/// Exponential curve decay rate (per second)
+pub const CURVE_DECAY_RATE: f32 = 2.5;
+
+// === Spin (cosmetic) ===
+
+/// Fraction of the paddle's surface speed a hit turns into ball spin
+pub const SPIN_TRANSFER: f32 = 0.5;
+/// Fastest visible ball spin (rad/s)
+pub const SPIN_MAX: f32 = 30.0;
+/// Exponential spin decay rate (per second)
+pub const SPIN_DECAY_RATE: f32 = 1.2;
+
+// === Combo ===
+
+/// Smallest combo whose reset sends `GameEvent::ComboBreak`
+pub const COMBO_BREAK_MIN: u32 = 5;
+/// Combos that send `GameEvent::ComboMilestone` when first reached
+pub const COMBO_MILESTONES: [u32; 3] = [5, 10, 20];
+
+// === Mercy ===
+
+/// Playing ticks after a respawn during which the black hole bounces balls
+/// back out instead of taking them (3 seconds)
+pub const MERCY_TICKS: u32 = 360;
+
+// === Chaos waves ===
+
+/// First wave that can roll a chaos modifier
+pub const CHAOS_START_WAVE: u32 = 6;
+/// Chance (percent) that an eligible wave gets a chaos modifier
+pub const CHAOS_CHANCE_PERCENT: u32 = 20;
+/// Peak tangential acceleration of the Wind modifier (pixels/s²)
+pub const WIND_ACCEL: f32 = 90.0;
+/// Wind cycle speed (rad/s); the wind reverses every half cycle (~9 s)
+pub const WIND_PHASE_RATE: f32 = 0.35;
+
+// === Endless ===
+//
+// Once the arena is at its max radius and can't fit another layer, each
+// layer the generator would have added becomes one endless level `L`
+// instead (one every 2 waves). With `L` levels:
+//
+//   max ball speed  = BALL_MAX_SPEED     * min(1 + 0.04·L, 1.6)
+//   gravity         = base_accel         * min(1 + 0.05·L, 2.0)
+//   block HP       += L / 3   (every breakable kind except Portal)
+
+/// Max ball speed gained per endless level (fraction of base)
+pub const ENDLESS_SPEED_STEP: f32 = 0.04;
+/// Cap on the endless max-speed multiplier
+pub const ENDLESS_SPEED_MAX_SCALE: f32 = 1.6;
+/// Black hole pull gained per endless level (fraction of base)
+pub const ENDLESS_GRAVITY_STEP: f32 = 0.05;
+/// Cap on the endless gravity multiplier
+pub const ENDLESS_GRAVITY_MAX_SCALE: f32 = 2.0;
+/// Endless levels per extra point of block HP
+pub const ENDLESS_LEVELS_PER_HP: u32 = 3;
+
+// === Shuffle ===
+
+/// Fraction of the remaining shuffle angle covered per second (ease-out)
+pub const SHUFFLE_EASE_RATE: f32 = 4.0;
+/// Remaining shuffle angle below which a ring snaps to its target (radians)
+pub const SHUFFLE_SNAP: f32 = 0.002;
+/// Smallest angle a ring travels in one shuffle (radians)
+pub const SHUFFLE_MIN_SHIFT: f32 = 0.4;
+/// Largest angle a ring travels in one shuffle (radians)
+pub const SHUFFLE_MAX_SHIFT: f32 = 1.2;
+/// Clearance a moving ring keeps from any ball (pixels)
+pub const SHUFFLE_BALL_CLEARANCE: f32 = 4.0;
+
+// === Explosions ===
+
+/// Blast ring growth rate (pixels/s); neighbors take damage as it reaches them
+pub const SHOCKWAVE_SPEED: f32 = 1500.0;
+/// Radius at which a blast ring has faded out
+pub const SHOCKWAVE_MAX_RADIUS: f32 = 220.0;
+
+// === Bumpers ===
+
+/// First wave that can place a bumper
+pub const BUMPER_START_WAVE: u32 = 8;
+/// Outward speed a bumper adds to a ball (pixels/s)
+pub const BUMPER_KICK: f32 = 120.0;
+/// Each kick in a chain is this fraction of the one before, so a ball
+/// caught between bumpers stops gaining speed
+pub const BUMPER_CHAIN_DECAY: f32 = 0.5;
+/// Kicks in a chain after which bumpers only bounce the ball
+pub const BUMPER_MAX_CHAIN: u8 = 4;
+/// Screen shake added per bumper kick
+pub const BUMPER_SHAKE: f32 = 0.12;
+
+// === Heavy ball ===
+
+/// Mass a Heavy Ball pickup gives each ball in play. Gravity is divided by
+/// mass, and the radius grows with its square root (area ∝ mass).
+pub const HEAVY_BALL_MASS: f32 = 2.0;
+/// Damage a heavy ball deals per block hit
+pub const HEAVY_BALL_DAMAGE: u8 = 2;
+
+// === Time Warp ===
+
+/// World speed while a Time Warp pickup is active (the paddle runs at 1.0)
+pub const TIME_WARP_SCALE: f32 = 0.5;
+
+// === Music ===
+
+/// Wave at which the wave alone brings the music to `MUSIC_WAVE_WEIGHT`
+pub const MUSIC_INTENSITY_WAVES: f32 = 20.0;
+/// Combo at which the combo alone brings the music to `MUSIC_COMBO_WEIGHT`
+pub const MUSIC_INTENSITY_COMBO: f32 = 20.0;
+/// Share of the music intensity from the wave and from the combo. They sum
+/// past 1 so either one can build up the music, and both together max it out.
+pub const MUSIC_WAVE_WEIGHT: f32 = 0.6;
+pub const MUSIC_COMBO_WEIGHT: f32 = 0.6;
+
+// === Idle AI ===
+
+/// Ticks the demo AI projects a ball ahead under gravity (0.25 s)
+pub const IDLE_AI_LOOKAHEAD_TICKS: u32 = 30;
+
+// === Wave layout ===
+
+/// Fewest block slots on a ring when blocks are sized by arc length
+pub const ARC_LENGTH_MIN_BLOCKS: usize = 3;
+
+// === Screen shake ===
+
+/// Exponential screen shake decay rate (per second). Matches the old
+/// `*= 0.9` per 120 Hz tick, but holds for any tick `dt`.
+pub const SCREEN_SHAKE_DECAY_RATE: f32 = 12.64;
+/// Shake below this snaps to zero
+pub const SCREEN_SHAKE_CUTOFF: f32 = 0.01;